@@ -0,0 +1,49 @@
+//! Compares per-color `adapt_to` against `adapt_all`, which builds the adaptation matrix once.
+//!
+//! Run with `cargo bench --bench adapt_all --features illuminant-d50`.
+
+use std::{
+  hint::black_box,
+  time::{Duration, Instant},
+};
+
+use farg::{
+  ColorimetricContext, Illuminant,
+  space::{Xyz, adapt_all},
+};
+
+const PALETTE_SIZE: usize = 10_000;
+const ROUNDS: u32 = 20;
+
+fn main() {
+  let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+  let d65 = ColorimetricContext::new();
+  let palette: Vec<Xyz> = (0..PALETTE_SIZE)
+    .map(|i| {
+      let t = i as f64 / PALETTE_SIZE as f64;
+      Xyz::new(0.1 + 0.8 * t, 0.2 + 0.6 * t, 0.9 - 0.7 * t).with_context(d50)
+    })
+    .collect();
+
+  let per_color = time(|| palette.iter().map(|color| color.adapt_to(d65)).collect());
+  let bulk = time(|| adapt_all(&palette, d65));
+
+  report("adapt_to (matrix per color)", per_color);
+  report("adapt_all (one shared matrix)", bulk);
+  println!("speedup: {:.1}x", per_color.as_secs_f64() / bulk.as_secs_f64());
+}
+
+fn report(label: &str, elapsed: Duration) {
+  let per_color = elapsed.as_nanos() as f64 / (PALETTE_SIZE as f64 * ROUNDS as f64);
+  println!("{label:<32} {per_color:>8.1} ns/color");
+}
+
+fn time(mut adapt: impl FnMut() -> Vec<Xyz>) -> Duration {
+  black_box(adapt());
+
+  let start = Instant::now();
+  for _ in 0..ROUNDS {
+    black_box(adapt());
+  }
+  start.elapsed()
+}
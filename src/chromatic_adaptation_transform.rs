@@ -1,4 +1,4 @@
-#[cfg(feature = "cat-bradford")]
+#[cfg(any(feature = "cat-bradford", all(feature = "space-lab", feature = "illuminant-d50")))]
 mod bradford;
 #[cfg(feature = "cat-cat02")]
 mod cat02;
@@ -18,9 +18,18 @@ mod sharp;
 mod von_kries;
 mod xyz_scaling;
 
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+  fmt::{Display, Formatter, Result as FmtResult},
+  str::FromStr,
+};
+
+#[cfg(all(feature = "space-lab", feature = "illuminant-d50"))]
+pub(crate) use bradford::BRADFORD_MATRIX;
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
+  error::Error,
   matrix::Matrix3,
   space::{ColorSpace, Lms, Xyz},
 };
@@ -80,16 +89,43 @@ impl ChromaticAdaptationTransform {
       lms[1] * (target_lms[1] / reference_lms[1]),
       lms[2] * (target_lms[2] / reference_lms[2]),
     ])
-    .to_xyz()
     .with_context(target_white.context().with_cat(*self))
+    .to_xyz()
     .with_alpha(color.alpha())
   }
 
+  /// Returns the XYZ-to-XYZ matrix adapting colors from `reference_white` to `target_white`.
+  ///
+  /// Equivalent to [`adapt`](Self::adapt) folded into a single matrix (M⁻¹ · D · M, where D
+  /// scales each cone channel by the ratio of target to reference white), so it can be built
+  /// once and applied to many colors.
+  pub(crate) fn adaptation_matrix(&self, reference_white: impl Into<Xyz>, target_white: impl Into<Xyz>) -> Matrix3 {
+    let reference_lms = self.matrix * reference_white.into().components();
+    let target_lms = self.matrix * target_white.into().components();
+    let scale = Matrix3::new([
+      [target_lms[0] / reference_lms[0], 0.0, 0.0],
+      [0.0, target_lms[1] / reference_lms[1], 0.0],
+      [0.0, 0.0, target_lms[2] / reference_lms[2]],
+    ]);
+
+    self.inverse * scale * self.matrix
+  }
+
+  /// Returns the XYZ-to-cone-response matrix. Alias for [`Self::matrix`].
+  pub fn cone_response_matrix(&self) -> Matrix3 {
+    self.matrix()
+  }
+
   /// Returns the inverse of the transformation matrix.
   pub fn inverse(&self) -> Matrix3 {
     self.inverse
   }
 
+  /// Returns the cone-response-to-XYZ matrix. Alias for [`Self::inverse`].
+  pub fn inverse_cone_response_matrix(&self) -> Matrix3 {
+    self.inverse()
+  }
+
   /// Returns the transformation matrix.
   pub fn matrix(&self) -> Matrix3 {
     self.matrix
@@ -119,6 +155,50 @@ impl Default for ChromaticAdaptationTransform {
   }
 }
 
+/// Parses a chromatic adaptation transform by name.
+///
+/// Matching is case-insensitive and ignores whitespace and punctuation, accepting both the
+/// display name (e.g. "Von Kries") and the constant name (e.g. "VON_KRIES"). "HPE" is an alias
+/// for Hunt-Pointer-Estevez and "default" for [`Self::DEFAULT`]. Only transforms whose features
+/// are enabled are recognized.
+impl FromStr for ChromaticAdaptationTransform {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let normalized: String = s
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric())
+      .collect::<String>()
+      .to_ascii_uppercase();
+
+    match normalized.as_str() {
+      "DEFAULT" => Ok(Self::DEFAULT),
+      #[cfg(feature = "cat-bradford")]
+      "BRADFORD" => Ok(Self::BRADFORD),
+      #[cfg(feature = "cat-cat02")]
+      "CAT02" => Ok(Self::CAT02),
+      #[cfg(feature = "cat-cat16")]
+      "CAT16" => Ok(Self::CAT16),
+      #[cfg(feature = "cat-cmc-cat2000")]
+      "CMCCAT2000" => Ok(Self::CMC_CAT2000),
+      #[cfg(feature = "cat-cmc-cat97")]
+      "CMCCAT97" => Ok(Self::CMC_CAT97),
+      #[cfg(feature = "cat-fairchild")]
+      "FAIRCHILD" => Ok(Self::FAIRCHILD),
+      #[cfg(feature = "cat-hunt-pointer-estevez")]
+      "HUNTPOINTERESTEVEZ" | "HPE" => Ok(Self::HUNT_POINTER_ESTEVEZ),
+      #[cfg(feature = "cat-sharp")]
+      "SHARP" => Ok(Self::SHARP),
+      #[cfg(feature = "cat-von-kries")]
+      "VONKRIES" => Ok(Self::VON_KRIES),
+      "XYZSCALING" => Ok(Self::XYZ_SCALING),
+      _ => Err(Error::UnknownCat {
+        name: s.to_string(),
+      }),
+    }
+  }
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -163,6 +243,27 @@ mod test {
     }
   }
 
+  #[cfg(feature = "cat-bradford")]
+  mod cone_response_matrix {
+    use super::*;
+
+    #[test]
+    fn it_matches_the_published_bradford_matrix() {
+      let published = [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296],
+      ];
+      let matrix = Cat::BRADFORD.cone_response_matrix();
+
+      for (row, expected_row) in matrix.data().iter().zip(published.iter()) {
+        for (value, expected) in row.iter().zip(expected_row.iter()) {
+          assert!((value - expected).abs() < 1e-9);
+        }
+      }
+    }
+  }
+
   mod default {
     use pretty_assertions::assert_eq;
 
@@ -197,6 +298,45 @@ mod test {
     }
   }
 
+  mod from_str {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[cfg(feature = "cat-bradford")]
+    #[test]
+    fn it_parses_display_and_constant_names() {
+      assert_eq!("Bradford".parse::<Cat>().unwrap().name(), "Bradford");
+      assert_eq!("BRADFORD".parse::<Cat>().unwrap().name(), "Bradford");
+    }
+
+    #[test]
+    fn it_parses_xyz_scaling() {
+      assert_eq!("XYZ_SCALING".parse::<Cat>().unwrap().name(), "XYZ Scaling");
+    }
+
+    #[cfg(not(feature = "cat-sharp"))]
+    #[test]
+    fn it_returns_error_for_uncompiled_transform() {
+      assert_eq!(
+        "Sharp".parse::<Cat>().unwrap_err(),
+        Error::UnknownCat {
+          name: "Sharp".to_string()
+        }
+      );
+    }
+
+    #[test]
+    fn it_returns_error_for_unknown_name() {
+      assert_eq!(
+        "CAT99".parse::<Cat>().unwrap_err(),
+        Error::UnknownCat {
+          name: "CAT99".to_string()
+        }
+      );
+    }
+  }
+
   mod inverse {
     use super::*;
 
@@ -216,6 +356,24 @@ mod test {
     }
   }
 
+  #[cfg(feature = "cat-bradford")]
+  mod inverse_cone_response_matrix {
+    use super::*;
+
+    #[test]
+    fn it_inverts_the_bradford_cone_response_matrix() {
+      let cat = Cat::BRADFORD;
+      let result = cat.cone_response_matrix() * cat.inverse_cone_response_matrix();
+      let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+      for (row, expected_row) in result.data().iter().zip(identity.iter()) {
+        for (value, expected) in row.iter().zip(expected_row.iter()) {
+          assert!((value - expected).abs() < 1e-9);
+        }
+      }
+    }
+  }
+
   mod matrix {
     use super::*;
 
@@ -1,17 +1,22 @@
+#[cfg(feature = "cat-bradford")]
 use super::ChromaticAdaptationTransform;
 
+/// The Bradford cone response matrix.
+///
+/// Compiled whenever D50 is available, even without the `cat-bradford` feature, because CSS Color
+/// Level 4 specifies Bradford for adapting its D50 `lab()` and `lch()` colors.
+pub(crate) const BRADFORD_MATRIX: [[f64; 3]; 3] = [
+  [0.8951, 0.2664, -0.1614],
+  [-0.7502, 1.7135, 0.0367],
+  [0.0389, -0.0685, 1.0296],
+];
+
+#[cfg(feature = "cat-bradford")]
 impl ChromaticAdaptationTransform {
   /// The Bradford chromatic adaptation transform.
   ///
   /// Widely considered the best general-purpose CAT. Used as the default.
-  pub const BRADFORD: Self = Self::new(
-    "Bradford",
-    [
-      [0.8951, 0.2664, -0.1614],
-      [-0.7502, 1.7135, 0.0367],
-      [0.0389, -0.0685, 1.0296],
-    ],
-  );
+  pub const BRADFORD: Self = Self::new("Bradford", BRADFORD_MATRIX);
   /// The default CAT (Bradford when the `cat-bradford` feature is enabled).
   pub const DEFAULT: Self = Self::BRADFORD;
 }
@@ -289,6 +289,15 @@ mod test {
       assert_eq!(upvp.u(), 0.0);
       assert_eq!(upvp.v(), 0.0);
     }
+
+    #[test]
+    fn it_roundtrips_d65_through_upvp() {
+      let xy = Xy::new(0.31271, 0.32902);
+      let result = xy.to_upvp().to_xy();
+
+      assert!((result.x() - xy.x()).abs() < 1e-12);
+      assert!((result.y() - xy.y()).abs() < 1e-12);
+    }
   }
 
   #[cfg(feature = "chromaticity-uv")]
@@ -315,6 +324,15 @@ mod test {
       assert_eq!(uv.u(), 0.0);
       assert_eq!(uv.v(), 0.0);
     }
+
+    #[test]
+    fn it_roundtrips_d65_through_uv() {
+      let xy = Xy::new(0.31271, 0.32902);
+      let result = xy.to_uv().to_xy();
+
+      assert!((result.x() - xy.x()).abs() < 1e-12);
+      assert!((result.y() - xy.y()).abs() < 1e-12);
+    }
   }
 
   mod to_xyz {
@@ -0,0 +1,283 @@
+//! CIE 13.3 color rendering index (CRI).
+//!
+//! Rates how faithfully a light source renders the colors of eight standard Munsell test color
+//! samples (TCS01–TCS08) compared to a reference illuminant of the same correlated color
+//! temperature: a Planckian radiator below 5000 K, CIE daylight at or above it. Each sample's
+//! color shift is measured in CIE 1964 U\*V\*W\* after a von Kries adaptation in CIE 1960 UCS,
+//! and the general index Ra is the mean of the eight special indices Ri.
+//!
+//! # Reference
+//!
+//! CIE 13.3-1995. "Method of Measuring and Specifying Colour Rendering Properties of Light
+//! Sources."
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{
+  Observer,
+  correlated_color_temperature::ohno,
+  illuminant::daylight,
+  spectral::{Spd, Table, blackbody_exitance},
+};
+
+/// CCT (K) below which the reference illuminant is a Planckian radiator rather than CIE daylight.
+const DAYLIGHT_THRESHOLD: f64 = 5000.0;
+
+/// Shortest wavelength (nm) of the integration range.
+const START_NM: u32 = 380;
+
+/// Longest wavelength (nm) of the integration range.
+const END_NM: u32 = 780;
+
+/// Reflectance factors of CIE test color samples TCS01–TCS08 at 5 nm intervals (CIE 13.3).
+#[allow(clippy::approx_constant)]
+static TEST_COLOR_SAMPLES: [(u32, [f64; 8]); 81] = [
+  (380, [0.219, 0.070, 0.065, 0.074, 0.295, 0.151, 0.378, 0.104]),
+  (385, [0.239, 0.079, 0.068, 0.083, 0.306, 0.203, 0.459, 0.129]),
+  (390, [0.252, 0.089, 0.070, 0.093, 0.310, 0.265, 0.524, 0.170]),
+  (395, [0.256, 0.101, 0.072, 0.105, 0.312, 0.339, 0.546, 0.240]),
+  (400, [0.256, 0.111, 0.073, 0.116, 0.313, 0.410, 0.551, 0.319]),
+  (405, [0.254, 0.116, 0.073, 0.121, 0.315, 0.464, 0.555, 0.416]),
+  (410, [0.252, 0.118, 0.074, 0.124, 0.319, 0.492, 0.559, 0.462]),
+  (415, [0.248, 0.120, 0.074, 0.126, 0.322, 0.508, 0.560, 0.482]),
+  (420, [0.244, 0.121, 0.074, 0.128, 0.326, 0.517, 0.561, 0.490]),
+  (425, [0.240, 0.122, 0.073, 0.131, 0.330, 0.524, 0.558, 0.488]),
+  (430, [0.237, 0.122, 0.073, 0.135, 0.334, 0.531, 0.556, 0.482]),
+  (435, [0.232, 0.122, 0.073, 0.139, 0.339, 0.538, 0.551, 0.473]),
+  (440, [0.230, 0.123, 0.073, 0.144, 0.346, 0.544, 0.544, 0.462]),
+  (445, [0.226, 0.124, 0.073, 0.151, 0.352, 0.551, 0.535, 0.450]),
+  (450, [0.225, 0.127, 0.074, 0.161, 0.360, 0.556, 0.522, 0.439]),
+  (455, [0.222, 0.128, 0.075, 0.172, 0.369, 0.556, 0.506, 0.426]),
+  (460, [0.220, 0.131, 0.077, 0.186, 0.381, 0.554, 0.488, 0.413]),
+  (465, [0.218, 0.134, 0.080, 0.205, 0.394, 0.549, 0.469, 0.397]),
+  (470, [0.216, 0.138, 0.085, 0.229, 0.403, 0.541, 0.448, 0.382]),
+  (475, [0.214, 0.143, 0.094, 0.254, 0.410, 0.531, 0.429, 0.366]),
+  (480, [0.214, 0.150, 0.109, 0.281, 0.415, 0.519, 0.408, 0.352]),
+  (485, [0.214, 0.159, 0.126, 0.308, 0.418, 0.504, 0.385, 0.337]),
+  (490, [0.216, 0.174, 0.148, 0.332, 0.419, 0.488, 0.363, 0.325]),
+  (495, [0.218, 0.190, 0.172, 0.352, 0.417, 0.469, 0.341, 0.310]),
+  (500, [0.223, 0.207, 0.198, 0.370, 0.413, 0.450, 0.324, 0.299]),
+  (505, [0.225, 0.225, 0.221, 0.383, 0.409, 0.431, 0.311, 0.289]),
+  (510, [0.226, 0.242, 0.241, 0.390, 0.403, 0.414, 0.301, 0.283]),
+  (515, [0.226, 0.253, 0.260, 0.394, 0.396, 0.395, 0.291, 0.276]),
+  (520, [0.225, 0.260, 0.278, 0.395, 0.389, 0.377, 0.283, 0.270]),
+  (525, [0.225, 0.264, 0.302, 0.392, 0.381, 0.358, 0.273, 0.262]),
+  (530, [0.227, 0.267, 0.339, 0.385, 0.372, 0.341, 0.265, 0.256]),
+  (535, [0.230, 0.269, 0.370, 0.377, 0.363, 0.325, 0.260, 0.251]),
+  (540, [0.236, 0.272, 0.392, 0.367, 0.353, 0.309, 0.257, 0.250]),
+  (545, [0.245, 0.276, 0.399, 0.354, 0.342, 0.293, 0.257, 0.251]),
+  (550, [0.253, 0.282, 0.400, 0.341, 0.331, 0.279, 0.259, 0.254]),
+  (555, [0.262, 0.289, 0.393, 0.327, 0.320, 0.265, 0.260, 0.258]),
+  (560, [0.272, 0.299, 0.380, 0.312, 0.308, 0.253, 0.260, 0.264]),
+  (565, [0.283, 0.309, 0.365, 0.296, 0.296, 0.241, 0.258, 0.269]),
+  (570, [0.298, 0.322, 0.349, 0.280, 0.284, 0.234, 0.256, 0.272]),
+  (575, [0.318, 0.329, 0.332, 0.263, 0.272, 0.227, 0.254, 0.274]),
+  (580, [0.341, 0.335, 0.315, 0.247, 0.261, 0.225, 0.254, 0.278]),
+  (585, [0.367, 0.339, 0.299, 0.229, 0.250, 0.222, 0.259, 0.284]),
+  (590, [0.390, 0.341, 0.285, 0.214, 0.239, 0.221, 0.270, 0.295]),
+  (595, [0.409, 0.341, 0.272, 0.198, 0.230, 0.220, 0.284, 0.316]),
+  (600, [0.424, 0.342, 0.264, 0.185, 0.222, 0.220, 0.302, 0.348]),
+  (605, [0.435, 0.342, 0.257, 0.175, 0.215, 0.220, 0.324, 0.384]),
+  (610, [0.442, 0.342, 0.252, 0.169, 0.209, 0.220, 0.344, 0.434]),
+  (615, [0.448, 0.341, 0.247, 0.164, 0.205, 0.220, 0.362, 0.482]),
+  (620, [0.450, 0.341, 0.241, 0.160, 0.202, 0.223, 0.377, 0.528]),
+  (625, [0.451, 0.339, 0.235, 0.156, 0.198, 0.227, 0.389, 0.568]),
+  (630, [0.451, 0.339, 0.229, 0.154, 0.195, 0.233, 0.400, 0.604]),
+  (635, [0.451, 0.338, 0.224, 0.152, 0.192, 0.239, 0.410, 0.629]),
+  (640, [0.451, 0.338, 0.220, 0.151, 0.191, 0.244, 0.420, 0.648]),
+  (645, [0.451, 0.337, 0.217, 0.149, 0.189, 0.251, 0.429, 0.663]),
+  (650, [0.450, 0.336, 0.216, 0.148, 0.188, 0.258, 0.438, 0.676]),
+  (655, [0.450, 0.335, 0.216, 0.148, 0.187, 0.263, 0.445, 0.685]),
+  (660, [0.451, 0.334, 0.219, 0.148, 0.185, 0.268, 0.452, 0.693]),
+  (665, [0.451, 0.332, 0.224, 0.149, 0.184, 0.273, 0.457, 0.700]),
+  (670, [0.453, 0.332, 0.230, 0.151, 0.183, 0.278, 0.462, 0.705]),
+  (675, [0.454, 0.331, 0.238, 0.154, 0.183, 0.281, 0.466, 0.709]),
+  (680, [0.455, 0.331, 0.251, 0.158, 0.182, 0.283, 0.468, 0.712]),
+  (685, [0.457, 0.330, 0.269, 0.162, 0.181, 0.286, 0.470, 0.715]),
+  (690, [0.458, 0.329, 0.288, 0.165, 0.181, 0.291, 0.473, 0.717]),
+  (695, [0.460, 0.328, 0.312, 0.168, 0.180, 0.296, 0.477, 0.719]),
+  (700, [0.462, 0.328, 0.340, 0.170, 0.181, 0.302, 0.483, 0.721]),
+  (705, [0.463, 0.327, 0.366, 0.171, 0.181, 0.313, 0.489, 0.720]),
+  (710, [0.464, 0.326, 0.390, 0.170, 0.181, 0.325, 0.496, 0.719]),
+  (715, [0.465, 0.325, 0.412, 0.168, 0.180, 0.338, 0.503, 0.722]),
+  (720, [0.466, 0.324, 0.431, 0.166, 0.181, 0.351, 0.511, 0.725]),
+  (725, [0.466, 0.324, 0.447, 0.164, 0.182, 0.364, 0.518, 0.727]),
+  (730, [0.466, 0.324, 0.460, 0.164, 0.183, 0.376, 0.525, 0.729]),
+  (735, [0.466, 0.323, 0.472, 0.165, 0.185, 0.389, 0.531, 0.730]),
+  (740, [0.467, 0.322, 0.481, 0.168, 0.187, 0.401, 0.536, 0.730]),
+  (745, [0.467, 0.321, 0.488, 0.172, 0.189, 0.413, 0.541, 0.730]),
+  (750, [0.467, 0.320, 0.493, 0.177, 0.192, 0.425, 0.543, 0.730]),
+  (755, [0.467, 0.318, 0.497, 0.181, 0.195, 0.436, 0.545, 0.730]),
+  (760, [0.467, 0.316, 0.500, 0.185, 0.200, 0.447, 0.546, 0.730]),
+  (765, [0.467, 0.315, 0.502, 0.189, 0.205, 0.458, 0.548, 0.730]),
+  (770, [0.467, 0.315, 0.505, 0.192, 0.209, 0.469, 0.550, 0.730]),
+  (775, [0.467, 0.314, 0.510, 0.194, 0.214, 0.477, 0.551, 0.730]),
+  (780, [0.467, 0.314, 0.516, 0.197, 0.219, 0.485, 0.555, 0.730]),
+];
+
+/// Calculates the CIE general color rendering index Ra of a light source.
+///
+/// The SPD is integrated from 380 nm to 780 nm at 1 nm intervals with the CIE 1931 2° observer,
+/// linearly interpolating the test sample reflectances and daylight components. A perfect
+/// match with the reference illuminant scores 100; typical halophosphate fluorescent lamps
+/// score in the 50s–70s.
+///
+/// ```
+/// use farg::{Illuminant, color_rendering_index};
+///
+/// let ra = color_rendering_index::calculate(&Illuminant::D65.spd());
+/// assert!((ra - 100.0).abs() < 0.1);
+/// ```
+pub fn calculate(spd: &Spd) -> f64 {
+  special_indices(spd).iter().sum::<f64>() / 8.0
+}
+
+/// Calculates the special color rendering indices R1–R8, one per test color sample.
+pub fn special_indices(spd: &Spd) -> [f64; 8] {
+  let test = |wavelength: u32| spd.power_at(wavelength as f64).unwrap_or(0.0);
+  let cct = ohno::calculate(tristimulus(test, |_| 1.0)).value();
+  let reference = |wavelength: u32| {
+    if cct < DAYLIGHT_THRESHOLD {
+      blackbody_exitance(wavelength, cct)
+    } else {
+      daylight::spectral_power(wavelength, cct)
+    }
+  };
+
+  let (u_k, v_k) = ucs(tristimulus(test, |_| 1.0));
+  let (u_r, v_r) = ucs(tristimulus(reference, |_| 1.0));
+  let (c_k, d_k) = adaptation_coordinates(u_k, v_k);
+  let (c_r, d_r) = adaptation_coordinates(u_r, v_r);
+
+  std::array::from_fn(|sample| {
+    let reflectance = |wavelength: u32| interpolate(&TEST_COLOR_SAMPLES, wavelength)[sample];
+
+    let [_, y_r, _] = tristimulus(reference, reflectance);
+    let (u, v) = ucs(tristimulus(reference, reflectance));
+    let reference_uvw = uvw(y_r, u, v, u_r, v_r);
+
+    let [_, y_k, _] = tristimulus(test, reflectance);
+    let (u, v) = ucs(tristimulus(test, reflectance));
+    let (c, d) = adaptation_coordinates(u, v);
+    let c = c_r / c_k * c;
+    let d = d_r / d_k * d;
+    let denominator = 16.518 + 1.481 * c - d;
+    let test_uvw = uvw(
+      y_k,
+      (10.872 + 0.404 * c - 4.0 * d) / denominator,
+      5.520 / denominator,
+      u_r,
+      v_r,
+    );
+
+    let delta_e = test_uvw
+      .iter()
+      .zip(reference_uvw)
+      .map(|(a, b)| (a - b).powi(2))
+      .sum::<f64>()
+      .sqrt();
+
+    100.0 - 4.6 * delta_e
+  })
+}
+
+/// Returns the von Kries (c, d) coordinates of a CIE 1960 (u, v) chromaticity.
+fn adaptation_coordinates(u: f64, v: f64) -> (f64, f64) {
+  ((4.0 - u - 10.0 * v) / v, (1.708 * v + 0.404 - 1.481 * u) / v)
+}
+
+/// Linearly interpolates a uniformly spaced table at `wavelength`.
+fn interpolate<const N: usize>(table: &[(u32, [f64; N])], wavelength: u32) -> [f64; N] {
+  let step = table[1].0 - table[0].0;
+  let index = ((wavelength - table[0].0) / step) as usize;
+  let (w1, a) = table[index];
+  let Some(&(_, b)) = table.get(index + 1) else {
+    return a;
+  };
+  let t = (wavelength - w1) as f64 / step as f64;
+
+  std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+/// Integrates a light source and reflectance with the CIE 1931 2° observer, scaled so the source
+/// itself has Y = 100.
+fn tristimulus(source: impl Fn(u32) -> f64, reflectance: impl Fn(u32) -> f64) -> [f64; 3] {
+  let cmf = Observer::CIE_1931_2D.cmf();
+  let mut xyz = [0.0; 3];
+  let mut white_y = 0.0;
+
+  for wavelength in START_NM..=END_NM {
+    let Some(response) = cmf.at(wavelength) else {
+      continue;
+    };
+    let [x, y, z] = response.components();
+    let power = source(wavelength);
+    let reflected = power * reflectance(wavelength);
+
+    xyz[0] += reflected * x;
+    xyz[1] += reflected * y;
+    xyz[2] += reflected * z;
+    white_y += power * y;
+  }
+
+  xyz.map(|c| if white_y > 0.0 { c * 100.0 / white_y } else { 0.0 })
+}
+
+/// Converts tristimulus values to CIE 1960 UCS (u, v).
+fn ucs([x, y, z]: [f64; 3]) -> (f64, f64) {
+  let denominator = x + 15.0 * y + 3.0 * z;
+
+  (4.0 * x / denominator, 6.0 * y / denominator)
+}
+
+/// Converts luminance and chromaticity to CIE 1964 U\*V\*W\* relative to the white (u0, v0).
+fn uvw(y: f64, u: f64, v: f64, u0: f64, v0: f64) -> [f64; 3] {
+  let w = 25.0 * y.cbrt() - 17.0;
+
+  [13.0 * w * (u - u0), 13.0 * w * (v - v0), w]
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod calculate {
+    use super::*;
+    use crate::Illuminant;
+
+    #[test]
+    fn it_rates_d65_at_100() {
+      assert!((calculate(&Illuminant::D65.spd()) - 100.0).abs() < 0.1);
+    }
+
+    #[cfg(feature = "illuminant-a")]
+    #[test]
+    fn it_rates_illuminant_a_at_100() {
+      assert!((calculate(&Illuminant::A.spd()) - 100.0).abs() < 0.1);
+    }
+
+    #[cfg(feature = "illuminant-fl2")]
+    #[test]
+    fn it_matches_the_published_value_for_fl2() {
+      assert!((calculate(&Illuminant::FL2.spd()) - 64.0).abs() < 1.0);
+    }
+
+    #[cfg(feature = "illuminant-fl7")]
+    #[test]
+    fn it_matches_the_published_value_for_fl7() {
+      assert!((calculate(&Illuminant::FL7.spd()) - 90.0).abs() < 1.0);
+    }
+  }
+
+  mod special_indices {
+    use super::*;
+    use crate::Illuminant;
+
+    #[test]
+    fn it_returns_one_index_per_sample() {
+      let indices = special_indices(&Illuminant::D65.spd());
+
+      assert!(indices.iter().all(|r| (r - 100.0).abs() < 0.5));
+    }
+  }
+}
@@ -12,6 +12,8 @@
 //! Model for Simulation of Color Vision Deficiency." *IEEE Transactions on Visualization
 //! and Computer Graphics*, 15(6), 1291–1298.
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
   matrix::Matrix3,
   space::{Srgb, Xyz},
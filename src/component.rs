@@ -2,8 +2,13 @@ use std::{
   cmp::Ordering,
   fmt::{Display, Formatter, Result as FmtResult},
   ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+  str::FromStr,
 };
 
+use crate::Error;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
 /// A numeric component value used throughout the library.
 ///
 /// Wraps an `f64` and provides arithmetic operations that accept any numeric type
@@ -176,6 +181,31 @@ impl From<usize> for Component {
   }
 }
 
+impl FromStr for Component {
+  type Err = Error;
+
+  /// Parses a plain number (`"0.5"`) as-is or a percentage (`"50%"`) as value / 100.
+  ///
+  /// Surrounding whitespace is ignored. Non-finite values and anything else that is not a
+  /// number return [`Error::InvalidComponent`].
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let trimmed = s.trim();
+    let (number, scale) = match trimmed.strip_suffix('%') {
+      Some(percent) => (percent, 0.01),
+      None => (trimmed, 1.0),
+    };
+
+    number
+      .parse::<f64>()
+      .ok()
+      .filter(|value| value.is_finite())
+      .map(|value| Self(value * scale))
+      .ok_or_else(|| Error::InvalidComponent {
+        input: s.to_string(),
+      })
+  }
+}
+
 impl<T> Mul<T> for Component
 where
   T: Into<Self>,
@@ -443,6 +473,50 @@ mod test {
     }
   }
 
+  mod from_str {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_a_percentage_as_a_fraction() {
+      assert_eq!("50%".parse::<Component>().unwrap(), 0.5);
+    }
+
+    #[test]
+    fn it_parses_a_plain_number_as_is() {
+      assert_eq!("0.5".parse::<Component>().unwrap(), 0.5);
+      assert_eq!("-1.25".parse::<Component>().unwrap(), -1.25);
+    }
+
+    #[test]
+    fn it_ignores_surrounding_whitespace() {
+      assert_eq!(" 25% ".parse::<Component>().unwrap(), 0.25);
+    }
+
+    #[test]
+    fn it_errors_on_garbage() {
+      assert_eq!(
+        "abc".parse::<Component>().unwrap_err(),
+        Error::InvalidComponent {
+          input: "abc".to_string()
+        }
+      );
+    }
+
+    #[test]
+    fn it_errors_on_empty_and_bare_percent() {
+      assert!("".parse::<Component>().is_err());
+      assert!("%".parse::<Component>().is_err());
+    }
+
+    #[test]
+    fn it_errors_on_non_finite_values() {
+      assert!("inf".parse::<Component>().is_err());
+      assert!("NaN%".parse::<Component>().is_err());
+    }
+  }
+
   mod mul {
     use pretty_assertions::assert_eq;
 
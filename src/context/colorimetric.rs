@@ -1,6 +1,8 @@
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
-use crate::{Cat, Illuminant, Observer, space::Xyz};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{Cat, Error, Illuminant, Observer, chromaticity::Xy, space::Xyz, spectral::Table};
 
 /// Defines the viewing conditions for colorimetric calculations.
 ///
@@ -22,13 +24,9 @@ impl ColorimetricContext {
     observer: Observer::DEFAULT,
   };
 
-  /// Creates a new context with default settings.
+  /// Creates a new context with default settings. Equivalent to [`Self::DEFAULT`].
   pub const fn new() -> Self {
-    Self {
-      cat: Cat::DEFAULT,
-      illuminant: Illuminant::DEFAULT,
-      observer: Observer::DEFAULT,
-    }
+    Self::DEFAULT
   }
 
   /// Returns a reference to the chromatic adaptation transform.
@@ -56,6 +54,63 @@ impl ColorimetricContext {
     self.observer.cmf().calculate_reference_white(&self.illuminant.spd())
   }
 
+  /// Validates that this context has the spectral data needed for colorimetric calculations.
+  ///
+  /// Returns [`Error::MissingSpectralData`] if the illuminant has no spectral power
+  /// distribution or the observer has no color matching functions, rather than failing
+  /// later when the reference white is computed.
+  ///
+  /// ```
+  /// use farg::ColorimetricContext;
+  ///
+  /// assert!(ColorimetricContext::new().try_build().is_ok());
+  /// ```
+  pub fn try_build(self) -> Result<Self, Error> {
+    if self.illuminant.spd().is_empty() || self.observer.cmf().is_empty() {
+      return Err(Error::MissingSpectralData {
+        illuminant: self.illuminant.name().to_string(),
+        observer: self.observer.name(),
+      });
+    }
+
+    Ok(self)
+  }
+
+  /// Returns the chromaticity of this context's reference white.
+  ///
+  /// Integrates the illuminant SPD through the observer CMF, as [`Self::reference_white`] does,
+  /// and reduces the result to x, y.
+  ///
+  /// ```
+  /// use farg::ColorimetricContext;
+  ///
+  /// let white = ColorimetricContext::default().white_point_xy();
+  ///
+  /// assert!((white.x() - 0.31271).abs() < 1e-4);
+  /// assert!((white.y() - 0.32902).abs() < 1e-4);
+  /// ```
+  pub fn white_point_xy(&self) -> Xy {
+    self.reference_white().chromaticity()
+  }
+
+  /// Returns the reference white of this context's illuminant as seen by a different `observer`.
+  ///
+  /// Integrates the illuminant SPD against `observer`'s color matching functions, normalized to
+  /// Y = 1.0, without changing this context. Useful for reconciling measurements taken with
+  /// different observers, e.g. 2° versus 10°.
+  ///
+  /// ```
+  /// use farg::{ColorimetricContext, Observer};
+  ///
+  /// let context = ColorimetricContext::new();
+  /// let white = context.white_point_under(Observer::CIE_1931_2D);
+  ///
+  /// assert_eq!(white.components(), context.reference_white().components());
+  /// ```
+  pub fn white_point_under(&self, observer: Observer) -> Xyz {
+    observer.cmf().calculate_reference_white(&self.illuminant.spd())
+  }
+
   /// Returns a new context with the given chromatic adaptation transform.
   pub const fn with_cat(&self, cat: Cat) -> Self {
     Self {
@@ -92,12 +147,82 @@ impl Display for ColorimetricContext {
   }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ColorimetricContext {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    use serde::de::Error as _;
+
+    #[derive(serde::Deserialize)]
+    struct ContextData {
+      illuminant: String,
+      observer: String,
+      cat: String,
+    }
+
+    let data = ContextData::deserialize(deserializer)?;
+    Ok(Self {
+      cat: data.cat.parse().map_err(D::Error::custom)?,
+      illuminant: data.illuminant.parse().map_err(D::Error::custom)?,
+      observer: data.observer.parse().map_err(D::Error::custom)?,
+    })
+  }
+}
+
 impl Default for ColorimetricContext {
   fn default() -> Self {
     Self::DEFAULT
   }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for ColorimetricContext {
+  fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+    use serde::ser::{Error as _, SerializeStruct};
+
+    let not_built_in =
+      |kind: &str, name: &str| Ser::Error::custom(format!("{kind} '{name}' is not built in and cannot be serialized"));
+    let illuminant = built_in_name(self.illuminant.name(), |illuminant: &Illuminant| {
+      illuminant.spd().table() == self.illuminant.spd().table()
+    })
+    .ok_or_else(|| not_built_in("illuminant", self.illuminant.name()))?;
+    let observer = built_in_name(&self.observer.name(), |observer: &Observer| {
+      observer.cmf().table() == self.observer.cmf().table()
+    })
+    .ok_or_else(|| not_built_in("observer", &self.observer.name()))?;
+    let cat = built_in_name(self.cat.name(), |cat: &Cat| cat.matrix() == self.cat.matrix())
+      .ok_or_else(|| not_built_in("chromatic adaptation transform", self.cat.name()))?;
+
+    let mut state = serializer.serialize_struct("ColorimetricContext", 3)?;
+    state.serialize_field("illuminant", &illuminant)?;
+    state.serialize_field("observer", &observer)?;
+    state.serialize_field("cat", &cat)?;
+    state.end()
+  }
+}
+
+/// Returns the constant name for `name` if it parses back to a built-in value that `matches`.
+///
+/// Custom illuminants, observers, and CATs have no constant to deserialize into, and one that
+/// reuses a built-in name would silently deserialize as the built-in, so both yield `None`.
+#[cfg(feature = "serde")]
+fn built_in_name<T: std::str::FromStr>(name: &str, matches: impl FnOnce(&T) -> bool) -> Option<String> {
+  let constant = constant_name(name);
+  constant.parse().ok().filter(matches).map(|_: T| constant)
+}
+
+/// Converts a display name (e.g. "CIE 1931 2°", "FL3.1") to its constant form ("CIE_1931_2D", "FL3_1").
+#[cfg(feature = "serde")]
+fn constant_name(name: &str) -> String {
+  name
+    .chars()
+    .map(|c| match c {
+      '°' => 'D',
+      c if c.is_ascii_alphanumeric() => c.to_ascii_uppercase(),
+      _ => '_',
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -113,6 +238,31 @@ mod test {
     }
   }
 
+  mod default_const {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_equals_new() {
+      let constant = ColorimetricContext::DEFAULT;
+      let built = ColorimetricContext::new();
+
+      assert_eq!(constant.name(), built.name());
+      assert_eq!(constant.cat().name(), built.cat().name());
+      assert_eq!(constant.reference_white(), built.reference_white());
+    }
+
+    #[cfg(feature = "cat-bradford")]
+    #[test]
+    fn it_uses_d65_cie_1931_2d_and_bradford() {
+      let ctx = ColorimetricContext::DEFAULT;
+
+      assert_eq!(ctx.name(), "D65 CIE 1931 2°");
+      assert_eq!(ctx.cat().name(), "Bradford");
+    }
+  }
+
   mod display {
     use pretty_assertions::assert_eq;
 
@@ -166,6 +316,124 @@ mod test {
     }
   }
 
+  mod try_build {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::IlluminantType;
+
+    #[test]
+    fn it_succeeds_for_the_default_context() {
+      let ctx = ColorimetricContext::default().try_build().unwrap();
+
+      assert_eq!(ctx.name(), "D65 CIE 1931 2°");
+    }
+
+    #[test]
+    fn it_fails_without_illuminant_spd() {
+      let illuminant = Illuminant::builder("Empty", IlluminantType::Custom)
+        .with_spd(&[])
+        .build()
+        .unwrap();
+      let result = ColorimetricContext::new().with_illuminant(illuminant).try_build();
+
+      assert_eq!(
+        result.unwrap_err(),
+        Error::MissingSpectralData {
+          illuminant: "Empty".to_string(),
+          observer: "CIE 1931 2°".to_string(),
+        }
+      );
+    }
+
+    #[test]
+    fn it_fails_without_observer_cmf() {
+      let observer = Observer::builder("Empty", 2.0).with_cmf(&[]).build().unwrap();
+      let result = ColorimetricContext::new().with_observer(observer).try_build();
+
+      assert_eq!(
+        result.unwrap_err(),
+        Error::MissingSpectralData {
+          illuminant: "D65".to_string(),
+          observer: "Empty 2°".to_string(),
+        }
+      );
+    }
+  }
+
+  mod white_point_under {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_matches_reference_white_for_the_context_observer() {
+      let ctx = ColorimetricContext::default();
+
+      assert_eq!(
+        ctx.white_point_under(Observer::CIE_1931_2D).components(),
+        ctx.reference_white().components()
+      );
+    }
+
+    #[cfg(feature = "observer-cie-1964-10d")]
+    #[test]
+    fn it_differs_slightly_between_2_and_10_degree_observers() {
+      let ctx = ColorimetricContext::default();
+      let two = ctx.white_point_under(Observer::CIE_1931_2D);
+      let ten = ctx.white_point_under(Observer::CIE_1964_10D);
+
+      assert!((two.y() - 1.0).abs() < 1e-12);
+      assert!((ten.y() - 1.0).abs() < 1e-12);
+      assert!((two.x() - ten.x()).abs() > 1e-3);
+      assert!((two.x() - ten.x()).abs() < 0.01);
+      assert!((ten.x() - 0.94811).abs() < 1e-3);
+      assert!((ten.z() - 1.07304).abs() < 1e-3);
+    }
+
+    #[cfg(feature = "observer-cie-1964-10d")]
+    #[test]
+    fn it_does_not_change_the_context() {
+      let ctx = ColorimetricContext::default();
+      ctx.white_point_under(Observer::CIE_1964_10D);
+
+      assert_eq!(ctx.observer().name(), "CIE 1931 2°");
+    }
+  }
+
+  mod white_point_xy {
+    use super::*;
+
+    #[test]
+    fn it_returns_d65_chromaticity_for_default_context() {
+      let white = ColorimetricContext::default().white_point_xy();
+
+      assert!((white.x() - 0.31271).abs() < 1e-4);
+      assert!((white.y() - 0.32902).abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_matches_reference_white_chromaticity() {
+      let ctx = ColorimetricContext::default();
+      let white = ctx.white_point_xy();
+      let expected = ctx.reference_white().chromaticity();
+
+      assert_eq!(white.x(), expected.x());
+      assert_eq!(white.y(), expected.y());
+    }
+
+    #[cfg(feature = "illuminant-d50")]
+    #[test]
+    fn it_returns_d50_chromaticity() {
+      let white = ColorimetricContext::new()
+        .with_illuminant(Illuminant::D50)
+        .white_point_xy();
+
+      assert!((white.x() - 0.34567).abs() < 1e-4);
+      assert!((white.y() - 0.35850).abs() < 1e-4);
+    }
+  }
+
   mod with_cat {
     use super::*;
 
@@ -5,7 +5,9 @@
 //! contrast algorithm designed to replace WCAG 2.x contrast ratios with perceptually uniform
 //! lightness contrast (Lc) values.
 
-use crate::space::Xyz;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::space::{Rgb, Srgb, Xyz};
 
 /// Exponent for the soft clamp applied to near-black luminance values.
 pub const BLACK_CLAMP_EXPONENT: f64 = 1.414;
@@ -62,12 +64,28 @@ pub const NORMAL_TEXT_EXPONENT: f64 = 0.57;
 
 /// Background exponent for reverse polarity (light text on dark background).
 /// Referred to as `Rbg` in the APCA specification.
-pub const REVERSE_BACKGROUND_EXPONENT: f64 = 0.57;
+pub const REVERSE_BACKGROUND_EXPONENT: f64 = 0.65;
 
 /// Text exponent for reverse polarity (light text on dark background).
 /// Referred to as `Rtx` in the APCA specification.
 pub const REVERSE_TEXT_EXPONENT: f64 = 0.62;
 
+/// Largest screen luminance accepted as input; pairs outside 0.0-1.1 produce zero contrast.
+pub const MAX_INPUT_LUMINANCE: f64 = 1.1;
+
+/// Simple exponent APCA uses in place of the piecewise sRGB transfer function.
+/// Referred to as `mainTRC` in the APCA specification.
+pub const MAIN_TRC: f64 = 2.4;
+
+/// Red coefficient for APCA screen luminance (`sRco`).
+pub const SRGB_RED_COEFFICIENT: f64 = 0.2126729;
+
+/// Green coefficient for APCA screen luminance (`sGco`).
+pub const SRGB_GREEN_COEFFICIENT: f64 = 0.7151522;
+
+/// Blue coefficient for APCA screen luminance (`sBco`).
+pub const SRGB_BLUE_COEFFICIENT: f64 = 0.0721750;
+
 /// APCA lightness contrast (Lc) value.
 ///
 /// Wraps the raw Lc value computed by the APCA algorithm. Positive values indicate
@@ -127,8 +145,40 @@ impl From<LightnessContrast> for f64 {
 /// where positive values indicate dark text on a light background (normal polarity) and negative
 /// values indicate light text on a dark background (reverse polarity).
 pub fn calculate(color: impl Into<Xyz>, background: impl Into<Xyz>) -> LightnessContrast {
-  let text_y = soft_clamp(color.into().luminance());
-  let bg_y = soft_clamp(background.into().luminance());
+  lightness_contrast(color.into().luminance(), background.into().luminance())
+}
+
+/// Calculates APCA contrast between sRGB text and background colors exactly as APCA-W3 0.1.9 does.
+///
+/// Unlike [`calculate`], which takes luminance from CIE XYZ, this estimates screen luminance with
+/// APCA's simple 2.4 exponent and its own coefficients, so results match the published APCA
+/// reference values. Channels are clamped to 0.0-1.0 and luminance outside 0.0-1.1 yields zero.
+///
+/// ```
+/// use farg::{
+///   contrast::apca,
+///   space::{Rgb, Srgb},
+/// };
+///
+/// let text = Rgb::<Srgb>::from_hexcode("#888").unwrap();
+/// let background = Rgb::<Srgb>::from_hexcode("#fff").unwrap();
+///
+/// assert!((apca::calculate_srgb(text, background).value() - 63.056).abs() < 1e-3);
+/// ```
+pub fn calculate_srgb(text: impl Into<Rgb<Srgb>>, background: impl Into<Rgb<Srgb>>) -> LightnessContrast {
+  let text_y = screen_luminance(text.into());
+  let bg_y = screen_luminance(background.into());
+
+  if text_y.is_nan() || bg_y.is_nan() || text_y.min(bg_y) < 0.0 || text_y.max(bg_y) > MAX_INPUT_LUMINANCE {
+    return LightnessContrast(0.0);
+  }
+
+  lightness_contrast(text_y, bg_y)
+}
+
+fn lightness_contrast(text_y: f64, bg_y: f64) -> LightnessContrast {
+  let text_y = soft_clamp(text_y);
+  let bg_y = soft_clamp(bg_y);
 
   if (bg_y - text_y).abs() < DELTA_Y_MIN {
     return LightnessContrast(0.0);
@@ -149,6 +199,12 @@ pub fn calculate(color: impl Into<Xyz>, background: impl Into<Xyz>) -> Lightness
   }
 }
 
+fn screen_luminance(rgb: Rgb<Srgb>) -> f64 {
+  let [r, g, b] = rgb.components().map(|c| c.clamp(0.0, 1.0).powf(MAIN_TRC));
+
+  SRGB_RED_COEFFICIENT * r + SRGB_GREEN_COEFFICIENT * g + SRGB_BLUE_COEFFICIENT * b
+}
+
 fn soft_clamp(y: f64) -> f64 {
   if y < BLACK_THRESHOLD {
     y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP_EXPONENT)
@@ -262,6 +318,49 @@ mod test {
     }
   }
 
+  mod calculate_srgb {
+    use super::*;
+
+    fn lc(text: &str, background: &str) -> f64 {
+      calculate_srgb(
+        Rgb::<Srgb>::from_hexcode(text).unwrap(),
+        Rgb::<Srgb>::from_hexcode(background).unwrap(),
+      )
+      .value()
+    }
+
+    #[test]
+    fn it_matches_apca_reference_values() {
+      let cases = [
+        ("#888", "#fff", 63.056469930209424),
+        ("#fff", "#888", -68.54146436644962),
+        ("#000", "#aaa", 58.146262578561334),
+        ("#aaa", "#000", -56.24113336839742),
+        ("#123", "#def", 91.66830811481631),
+        ("#def", "#123", -93.06770049484275),
+        ("#123", "#444", 8.32326136957393),
+        ("#444", "#123", -7.526878460278154),
+      ];
+
+      for (text, background, expected) in cases {
+        assert!((lc(text, background) - expected).abs() < 1e-9, "{text} on {background}");
+      }
+    }
+
+    #[test]
+    fn it_returns_zero_for_identical_colors() {
+      assert_eq!(lc("#777", "#777"), 0.0);
+    }
+
+    #[test]
+    fn it_clamps_out_of_range_channels() {
+      let text = Rgb::<Srgb>::from_normalized(-0.5, -0.5, -0.5);
+      let background = Rgb::<Srgb>::from_normalized(1.5, 1.5, 1.5);
+
+      assert!((calculate_srgb(text, background).value() - lc("#000", "#fff")).abs() < 1e-12);
+    }
+  }
+
   mod lightness_contrast {
     use pretty_assertions::assert_eq;
 
@@ -10,6 +10,8 @@
 //! *Applied Optics*, 38(27), 5703–5709.
 
 use super::ColorTemperature;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::Xyz;
 
 /// Chromaticity epicenter x coordinate.
@@ -10,6 +10,8 @@
 //! *LEUKOS*, 10(1), 47–55.
 
 use super::{ColorTemperature, MRD_FACTOR};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{chromaticity::Xy, space::Xyz};
 
 /// Temperature threshold (K) between the two Kim et al. approximation ranges.
@@ -75,6 +77,39 @@ const PARABOLIC_EPSILON: f64 = 1e-20;
 pub fn calculate(color: impl Into<Xyz>) -> ColorTemperature {
   let [u_test, v_test] = color.into().chromaticity().to_uv().components();
 
+  ColorTemperature(MRD_FACTOR / closest_mrd(u_test, v_test))
+}
+
+/// Calculates the correlated color temperature and signed Duv using Ohno's method.
+///
+/// Duv is the distance in CIE 1960 UCS (u, v) from the color to the closest point on the
+/// Planckian locus. It is positive when the color lies above the locus (toward green) and
+/// negative when it lies below (toward magenta/pink).
+///
+/// ```
+/// # #[cfg(feature = "cct-ohno")]
+/// # {
+/// use farg::correlated_color_temperature::ohno;
+/// use farg::space::Xyz;
+///
+/// let d65 = Xyz::new(0.95047, 1.0, 1.08883);
+/// let (cct, duv) = ohno::calculate_with_duv(d65);
+/// assert!((cct.value() - 6504.0).abs() < 50.0);
+/// assert!(duv > 0.0 && duv < 0.005);
+/// # }
+/// ```
+pub fn calculate_with_duv(color: impl Into<Xyz>) -> (ColorTemperature, f64) {
+  let [u_test, v_test] = color.into().chromaticity().to_uv().components();
+  let cct = MRD_FACTOR / closest_mrd(u_test, v_test);
+  let [u_bb, v_bb] = planckian_locus_uv(cct);
+  let duv = dist_sq(u_test, v_test, u_bb, v_bb).sqrt();
+
+  (ColorTemperature(cct), if v_test < v_bb { -duv } else { duv })
+}
+
+/// Searches the Planckian locus at 1 MRD steps for the point closest to (u, v), then refines
+/// the result with parabolic interpolation.
+fn closest_mrd(u_test: f64, v_test: f64) -> f64 {
   let mut min_dist = f64::MAX;
   let mut min_mrd = MRD_SEARCH_START;
 
@@ -102,13 +137,11 @@ pub fn calculate(color: impl Into<Xyz>) -> ColorTemperature {
   let d_hi = dist_sq(u_test, v_test, u_hi, v_hi);
 
   let denom = d_lo - 2.0 * d_mid + d_hi;
-  let mrd_refined = if denom.abs() > PARABOLIC_EPSILON {
+  if denom.abs() > PARABOLIC_EPSILON {
     mrd_mid + 0.5 * (d_lo - d_hi) / denom
   } else {
     mrd_mid
-  };
-
-  ColorTemperature(MRD_FACTOR / mrd_refined)
+  }
 }
 
 /// Squared distance between two points in uv space.
@@ -121,6 +154,13 @@ fn dist_sq(u1: f64, v1: f64, u2: f64, v2: f64) -> f64 {
 /// Uses Kim et al. (2002) approximation for CIE 1931 xy of a blackbody at temperature T,
 /// then converts to CIE 1960 uv.
 fn planckian_locus_uv(t: f64) -> [f64; 2] {
+  planckian_locus_xy(t).to_uv().components()
+}
+
+/// Calculates the CIE 1931 xy chromaticity of a blackbody at the given temperature.
+///
+/// Uses the Kim et al. (2002) cubic spline approximation, valid from roughly 1,667 K to 25,000 K.
+pub(crate) fn planckian_locus_xy(t: f64) -> Xy {
   let t2 = t * t;
   let t3 = t2 * t;
 
@@ -138,7 +178,7 @@ fn planckian_locus_uv(t: f64) -> [f64; 2] {
     (x, y)
   };
 
-  Xy::new(x, y).to_uv().components()
+  Xy::new(x, y)
 }
 
 #[cfg(test)]
@@ -215,4 +255,44 @@ mod test {
       assert!((cct.value() - 5000.0).abs() < 100.0);
     }
   }
+
+  mod calculate_with_duv {
+    use super::*;
+
+    #[test]
+    fn it_matches_calculate_for_cct() {
+      let d65 = Xyz::new(0.95047, 1.0, 1.08883);
+      let (cct, _) = calculate_with_duv(d65);
+
+      assert!((cct.value() - calculate(d65).value()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_returns_near_zero_duv_on_the_locus() {
+      let [u, v] = planckian_locus_uv(5000.0);
+      let point = crate::chromaticity::Uv::new(u, v).to_xy().to_xyz(1.0);
+      let (cct, duv) = calculate_with_duv(point);
+
+      assert!((cct.value() - 5000.0).abs() < 1.0);
+      assert!(duv.abs() < 1e-5);
+    }
+
+    #[test]
+    fn it_returns_positive_duv_above_the_locus() {
+      let [u, v] = planckian_locus_uv(5000.0);
+      let point = crate::chromaticity::Uv::new(u, v + 0.01).to_xy().to_xyz(1.0);
+      let (_, duv) = calculate_with_duv(point);
+
+      assert!((0.005..=0.01 + 1e-9).contains(&duv));
+    }
+
+    #[test]
+    fn it_returns_negative_duv_below_the_locus() {
+      let [u, v] = planckian_locus_uv(5000.0);
+      let point = crate::chromaticity::Uv::new(u, v - 0.01).to_xy().to_xyz(1.0);
+      let (_, duv) = calculate_with_duv(point);
+
+      assert!((-0.01 - 1e-9..=-0.005).contains(&duv));
+    }
+  }
 }
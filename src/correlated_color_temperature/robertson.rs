@@ -10,6 +10,8 @@
 //! Temperature." *Journal of the Optical Society of America*, 58(11), 1528–1535.
 
 use super::{ColorTemperature, MRD_FACTOR};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::Xyz;
 
 /// An isotherm entry from Robertson's Table 1: (MRD, u, v, slope).
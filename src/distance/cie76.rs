@@ -4,6 +4,8 @@
 //! formula, which is the Euclidean distance in CIELAB space. This was the first standardized
 //! color difference metric and remains widely used for its simplicity.
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::{Lab, Xyz};
 
 /// Calculates the CIE76 color difference (ΔE\*ab) between two colors.
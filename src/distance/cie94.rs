@@ -5,6 +5,8 @@
 //! formula is **not symmetric** — the first argument is treated as the reference color and the
 //! second as the sample.
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::{Lab, Xyz};
 
 /// Default lightness weight for graphic arts applications.
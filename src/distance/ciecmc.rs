@@ -5,6 +5,8 @@
 //! of Dyers and Colourists. The formula uses CIE LCh components and is **not symmetric** —
 //! the first argument is treated as the reference color.
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::{Lch, Xyz};
 
 /// Calculates the CMC l:c color difference for perceptibility (l=1, c=1).
@@ -5,6 +5,8 @@
 //! lightness, chroma, and hue, plus an interactive term for the blue region and a rotation
 //! term for chroma/hue interaction.
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::{Lab, Xyz};
 
 /// Just Noticeable Difference threshold. Two colors with ΔE\*00 < 1.0 are generally
@@ -4,6 +4,8 @@
 //! This is the simplest geometric distance metric and does not account for perceptual
 //! uniformity.
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::Xyz;
 
 /// Calculates the Euclidean distance between two colors in CIE XYZ space.
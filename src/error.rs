@@ -3,22 +3,49 @@ use std::{
   fmt::{Display, Formatter, Result as FmtResult},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
 /// Errors that can occur during color operations.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Error {
+  /// A component string was not a finite number or percentage.
+  InvalidComponent { input: String },
+  /// A CSS color string was not valid functional notation for the requested color space.
+  InvalidCssColor { input: String },
   /// A hex color code contained an invalid character.
   InvalidHexCharacter { input: String },
   /// A hex color code had an invalid length (expected 3 or 6 characters).
   InvalidHexLength { input: String, length: usize },
   /// An observer builder was missing required color matching function data.
   MissingColorMatchingFunction,
+  /// A hex color code was missing its leading `#` where one is required.
+  MissingHashPrefix { input: String },
+  /// A colorimetric context's illuminant or observer had no spectral data to integrate.
+  MissingSpectralData { illuminant: String, observer: String },
   /// An illuminant builder was missing required spectral power distribution data.
   MissingSpectralPowerDistribution,
+  /// An input contained whitespace where none is allowed.
+  UnexpectedWhitespace { input: String },
+  /// A chromatic adaptation transform name did not match any compiled-in transform.
+  UnknownCat { name: String },
+  /// A color name did not match any known named color keyword.
+  UnknownColorName { name: String },
+  /// An illuminant name did not match any compiled-in standard illuminant.
+  UnknownIlluminant { name: String },
+  /// An observer name did not match any compiled-in standard observer.
+  UnknownObserver { name: String },
 }
 
 impl Display for Error {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     match self {
+      Self::InvalidComponent {
+        input,
+      } => write!(f, "invalid component value '{input}'"),
+      Self::InvalidCssColor {
+        input,
+      } => write!(f, "invalid CSS color '{input}'"),
       Self::InvalidHexCharacter {
         input,
       } => write!(f, "invalid hex character in '{input}'"),
@@ -29,7 +56,32 @@ impl Display for Error {
         write!(f, "invalid hex length {length} for '{input}', expected 3 or 6")
       }
       Self::MissingColorMatchingFunction => write!(f, "color matching function is required"),
+      Self::MissingHashPrefix {
+        input,
+      } => write!(f, "missing '#' prefix in '{input}'"),
+      Self::MissingSpectralData {
+        illuminant,
+        observer,
+      } => write!(
+        f,
+        "missing spectral data for illuminant '{illuminant}' or observer '{observer}'"
+      ),
       Self::MissingSpectralPowerDistribution => write!(f, "spectral power distribution is required"),
+      Self::UnexpectedWhitespace {
+        input,
+      } => write!(f, "unexpected whitespace in '{input}'"),
+      Self::UnknownCat {
+        name,
+      } => write!(f, "unknown chromatic adaptation transform '{name}'"),
+      Self::UnknownColorName {
+        name,
+      } => write!(f, "unknown color name '{name}'"),
+      Self::UnknownIlluminant {
+        name,
+      } => write!(f, "unknown illuminant '{name}'"),
+      Self::UnknownObserver {
+        name,
+      } => write!(f, "unknown observer '{name}'"),
     }
   }
 }
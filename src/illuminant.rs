@@ -11,6 +11,7 @@ mod d55;
 mod d65;
 #[cfg(feature = "illuminant-d75")]
 mod d75;
+pub(crate) mod daylight;
 #[cfg(feature = "illuminant-e")]
 mod e;
 #[cfg(feature = "illuminant-fl1")]
@@ -100,8 +101,13 @@ mod led_v1;
 #[cfg(feature = "illuminant-led-v2")]
 mod led_v2;
 
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+  fmt::{Display, Formatter, Result as FmtResult},
+  str::FromStr,
+};
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{error::Error, spectral::Spd};
 
 /// Builder for constructing custom [`Illuminant`] instances.
@@ -154,7 +160,7 @@ impl<'a> Builder<'a> {
 pub struct Illuminant {
   kind: IlluminantType,
   name: &'static str,
-  spd: Spd,
+  spd: &'static [(u32, f64)],
 }
 
 impl Illuminant {
@@ -163,15 +169,57 @@ impl Illuminant {
     Builder::new(name, kind)
   }
 
+  /// Generates the CIE D-series daylight SPD for any correlated color temperature.
+  ///
+  /// The S0, S1, and S2 daylight component spectra are combined with the M1/M2 weights for the
+  /// daylight-locus chromaticity at `cct`, which is clamped to the defined 4000–25000 K range.
+  /// The result is sampled every 5 nm from 300 nm to 830 nm and normalized to 100 at 560 nm.
+  /// Note that D65 and D50 correspond to 6504 K and 5003 K on the modern temperature scale.
+  ///
+  /// ```
+  /// use farg::{Illuminant, IlluminantType, SpectralTable};
+  ///
+  /// let spd = Illuminant::daylight(5700.0);
+  /// assert_eq!(spd.at(560), Some(&100.0));
+  ///
+  /// let d57 = Illuminant::builder("D57", IlluminantType::Daylight)
+  ///   .with_spd(spd.table())
+  ///   .build()
+  ///   .unwrap();
+  /// assert_eq!(d57.name(), "D57");
+  /// ```
+  pub fn daylight(cct: f64) -> Spd {
+    Spd::from_fn(300, 830, 5, |wavelength| daylight::spectral_power(wavelength, cct))
+  }
+
   /// Creates a new illuminant from a name, type, and spectral power distribution.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `spd` owns runtime-built samples, such as one from [`Spd::from_fn`]; use
+  /// [`builder`](Self::builder) to create an illuminant from those.
   pub const fn new(name: &'static str, kind: IlluminantType, spd: Spd) -> Self {
     Self {
       kind,
       name,
-      spd,
+      spd: spd.into_static_table(),
     }
   }
 
+  /// Returns the CIE 13.3 general color rendering index (Ra) of this illuminant.
+  ///
+  /// See [`color_rendering_index::calculate`](crate::color_rendering_index::calculate).
+  ///
+  /// ```
+  /// use farg::Illuminant;
+  ///
+  /// assert!((Illuminant::D65.color_rendering_index() - 100.0).abs() < 0.1);
+  /// ```
+  #[cfg(feature = "cri")]
+  pub fn color_rendering_index(&self) -> f64 {
+    crate::color_rendering_index::calculate(&self.spd())
+  }
+
   /// Returns the illuminant type.
   pub fn kind(&self) -> IlluminantType {
     self.kind
@@ -184,7 +232,7 @@ impl Illuminant {
 
   /// Returns the spectral power distribution.
   pub fn spd(&self) -> Spd {
-    self.spd
+    Spd::new(self.spd)
   }
 
   /// Alias for [`Self::spd`].
@@ -199,6 +247,135 @@ impl Display for Illuminant {
   }
 }
 
+/// Parses a standard illuminant by name.
+///
+/// Matching is case-insensitive and ignores whitespace and punctuation, so "D65", "d65", and
+/// "FL3.1"/"fl3-1" are all accepted. An optional "CIE" or "Illuminant" prefix is allowed, and
+/// "default" and "equal energy" are recognized as aliases for D65 and E. Only illuminants whose
+/// features are enabled are recognized.
+impl FromStr for Illuminant {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let normalized: String = s
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric())
+      .collect::<String>()
+      .to_ascii_uppercase();
+    let normalized = normalized
+      .strip_prefix("CIE")
+      .or_else(|| normalized.strip_prefix("ILLUMINANT"))
+      .unwrap_or(&normalized);
+
+    match normalized {
+      #[cfg(feature = "illuminant-a")]
+      "A" => Ok(Self::A),
+      #[cfg(feature = "illuminant-b")]
+      "B" => Ok(Self::B),
+      #[cfg(feature = "illuminant-c")]
+      "C" => Ok(Self::C),
+      #[cfg(feature = "illuminant-d50")]
+      "D50" => Ok(Self::D50),
+      #[cfg(feature = "illuminant-d55")]
+      "D55" => Ok(Self::D55),
+      "D65" | "DEFAULT" => Ok(Self::D65),
+      #[cfg(feature = "illuminant-d75")]
+      "D75" => Ok(Self::D75),
+      #[cfg(feature = "illuminant-e")]
+      "E" | "EQUALENERGY" => Ok(Self::E),
+      #[cfg(feature = "illuminant-fl1")]
+      "FL1" => Ok(Self::FL1),
+      #[cfg(feature = "illuminant-fl10")]
+      "FL10" => Ok(Self::FL10),
+      #[cfg(feature = "illuminant-fl11")]
+      "FL11" => Ok(Self::FL11),
+      #[cfg(feature = "illuminant-fl12")]
+      "FL12" => Ok(Self::FL12),
+      #[cfg(feature = "illuminant-fl2")]
+      "FL2" => Ok(Self::FL2),
+      #[cfg(feature = "illuminant-fl3")]
+      "FL3" => Ok(Self::FL3),
+      #[cfg(feature = "illuminant-fl3-1")]
+      "FL31" => Ok(Self::FL3_1),
+      #[cfg(feature = "illuminant-fl3-10")]
+      "FL310" => Ok(Self::FL3_10),
+      #[cfg(feature = "illuminant-fl3-11")]
+      "FL311" => Ok(Self::FL3_11),
+      #[cfg(feature = "illuminant-fl3-12")]
+      "FL312" => Ok(Self::FL3_12),
+      #[cfg(feature = "illuminant-fl3-13")]
+      "FL313" => Ok(Self::FL3_13),
+      #[cfg(feature = "illuminant-fl3-14")]
+      "FL314" => Ok(Self::FL3_14),
+      #[cfg(feature = "illuminant-fl3-15")]
+      "FL315" => Ok(Self::FL3_15),
+      #[cfg(feature = "illuminant-fl3-2")]
+      "FL32" => Ok(Self::FL3_2),
+      #[cfg(feature = "illuminant-fl3-3")]
+      "FL33" => Ok(Self::FL3_3),
+      #[cfg(feature = "illuminant-fl3-4")]
+      "FL34" => Ok(Self::FL3_4),
+      #[cfg(feature = "illuminant-fl3-5")]
+      "FL35" => Ok(Self::FL3_5),
+      #[cfg(feature = "illuminant-fl3-6")]
+      "FL36" => Ok(Self::FL3_6),
+      #[cfg(feature = "illuminant-fl3-7")]
+      "FL37" => Ok(Self::FL3_7),
+      #[cfg(feature = "illuminant-fl3-8")]
+      "FL38" => Ok(Self::FL3_8),
+      #[cfg(feature = "illuminant-fl3-9")]
+      "FL39" => Ok(Self::FL3_9),
+      #[cfg(feature = "illuminant-fl4")]
+      "FL4" => Ok(Self::FL4),
+      #[cfg(feature = "illuminant-fl5")]
+      "FL5" => Ok(Self::FL5),
+      #[cfg(feature = "illuminant-fl6")]
+      "FL6" => Ok(Self::FL6),
+      #[cfg(feature = "illuminant-fl7")]
+      "FL7" => Ok(Self::FL7),
+      #[cfg(feature = "illuminant-fl8")]
+      "FL8" => Ok(Self::FL8),
+      #[cfg(feature = "illuminant-fl9")]
+      "FL9" => Ok(Self::FL9),
+      #[cfg(feature = "illuminant-hp1")]
+      "HP1" => Ok(Self::HP1),
+      #[cfg(feature = "illuminant-hp2")]
+      "HP2" => Ok(Self::HP2),
+      #[cfg(feature = "illuminant-hp3")]
+      "HP3" => Ok(Self::HP3),
+      #[cfg(feature = "illuminant-hp4")]
+      "HP4" => Ok(Self::HP4),
+      #[cfg(feature = "illuminant-hp5")]
+      "HP5" => Ok(Self::HP5),
+      #[cfg(feature = "illuminant-id50")]
+      "ID50" => Ok(Self::ID50),
+      #[cfg(feature = "illuminant-id65")]
+      "ID65" => Ok(Self::ID65),
+      #[cfg(feature = "illuminant-led-b1")]
+      "LEDB1" => Ok(Self::LED_B1),
+      #[cfg(feature = "illuminant-led-b2")]
+      "LEDB2" => Ok(Self::LED_B2),
+      #[cfg(feature = "illuminant-led-b3")]
+      "LEDB3" => Ok(Self::LED_B3),
+      #[cfg(feature = "illuminant-led-b4")]
+      "LEDB4" => Ok(Self::LED_B4),
+      #[cfg(feature = "illuminant-led-b5")]
+      "LEDB5" => Ok(Self::LED_B5),
+      #[cfg(feature = "illuminant-led-bh1")]
+      "LEDBH1" => Ok(Self::LED_BH1),
+      #[cfg(feature = "illuminant-led-rgb1")]
+      "LEDRGB1" => Ok(Self::LED_RGB1),
+      #[cfg(feature = "illuminant-led-v1")]
+      "LEDV1" => Ok(Self::LED_V1),
+      #[cfg(feature = "illuminant-led-v2")]
+      "LEDV2" => Ok(Self::LED_V2),
+      _ => Err(Error::UnknownIlluminant {
+        name: s.to_string(),
+      }),
+    }
+  }
+}
+
 /// The category of an illuminant.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum IlluminantType {
@@ -273,6 +450,72 @@ mod test {
   mod illuminant {
     use super::*;
 
+    mod daylight {
+      use pretty_assertions::assert_eq;
+
+      use super::*;
+      use crate::Observer;
+
+      fn chromaticity(spd: &Spd) -> (f64, f64) {
+        let xy = Observer::CIE_1931_2D
+          .cmf()
+          .calculate_reference_white(spd)
+          .chromaticity();
+        (xy.x(), xy.y())
+      }
+
+      #[test]
+      fn it_matches_d65_chromaticity_at_6504k() {
+        let (x, y) = chromaticity(&Illuminant::daylight(6504.0));
+        let (x65, y65) = chromaticity(&Illuminant::D65.spd());
+
+        assert!((x - x65).abs() < 5e-4);
+        assert!((y - y65).abs() < 5e-4);
+      }
+
+      #[test]
+      fn it_matches_d50_chromaticity_at_5003k() {
+        let (x, y) = chromaticity(&Illuminant::daylight(5003.0));
+
+        assert!((x - 0.3457).abs() < 5e-4);
+        assert!((y - 0.3585).abs() < 5e-4);
+      }
+
+      #[test]
+      fn it_reproduces_the_d65_table() {
+        let spd = Illuminant::daylight(6504.0);
+        let d65 = Illuminant::D65.spd();
+
+        for (wavelength, power) in spd.table() {
+          if let Some(expected) = d65.at(*wavelength) {
+            assert!((power - expected).abs() < 0.1, "{wavelength} nm: {power} vs {expected}");
+          }
+        }
+      }
+
+      #[test]
+      fn it_normalizes_to_100_at_560nm() {
+        assert_eq!(Illuminant::daylight(7500.0).at(560), Some(&100.0));
+      }
+
+      #[test]
+      fn it_samples_300_to_830nm_every_5nm() {
+        let spd = Illuminant::daylight(6500.0);
+
+        assert_eq!(spd.min_wavelength(), Some(300));
+        assert_eq!(spd.max_wavelength(), Some(830));
+        assert_eq!(spd.step(), 5);
+      }
+
+      #[test]
+      fn it_clamps_to_the_daylight_locus_range() {
+        let below = Illuminant::daylight(2000.0);
+        let floor = Illuminant::daylight(4000.0);
+
+        assert_eq!(below.at(450), floor.at(450));
+      }
+    }
+
     mod display {
       use pretty_assertions::assert_eq;
 
@@ -286,6 +529,73 @@ mod test {
       }
     }
 
+    mod from_str {
+      use pretty_assertions::assert_eq;
+
+      use super::*;
+
+      #[test]
+      fn it_parses_canonical_name() {
+        assert_eq!(Illuminant::from_str("D65").unwrap().name(), "D65");
+      }
+
+      #[test]
+      fn it_parses_case_insensitively() {
+        assert_eq!("d65".parse::<Illuminant>().unwrap().name(), "D65");
+      }
+
+      #[test]
+      fn it_parses_aliases() {
+        assert_eq!("default".parse::<Illuminant>().unwrap().name(), "D65");
+        assert_eq!(" CIE D65 ".parse::<Illuminant>().unwrap().name(), "D65");
+        assert_eq!("Illuminant D65".parse::<Illuminant>().unwrap().name(), "D65");
+      }
+
+      #[test]
+      fn it_round_trips_display() {
+        let illuminant = Illuminant::D65;
+
+        assert_eq!(
+          illuminant.to_string().parse::<Illuminant>().unwrap().name(),
+          illuminant.name()
+        );
+      }
+
+      #[cfg(feature = "illuminant-fl3-1")]
+      #[test]
+      fn it_parses_dotted_names() {
+        assert_eq!("fl3.1".parse::<Illuminant>().unwrap().name(), "FL3.1");
+        assert_eq!("FL3-1".parse::<Illuminant>().unwrap().name(), "FL3.1");
+      }
+
+      #[cfg(feature = "illuminant-led-b1")]
+      #[test]
+      fn it_parses_hyphenated_names() {
+        assert_eq!("led-b1".parse::<Illuminant>().unwrap().name(), "LED-B1");
+      }
+
+      #[cfg(not(feature = "illuminant-d50"))]
+      #[test]
+      fn it_returns_error_for_uncompiled_illuminant() {
+        assert_eq!(
+          "D50".parse::<Illuminant>().unwrap_err(),
+          Error::UnknownIlluminant {
+            name: "D50".to_string()
+          }
+        );
+      }
+
+      #[test]
+      fn it_returns_error_for_unknown_name() {
+        assert_eq!(
+          "Z99".parse::<Illuminant>().unwrap_err(),
+          Error::UnknownIlluminant {
+            name: "Z99".to_string()
+          }
+        );
+      }
+    }
+
     mod kind {
       use pretty_assertions::assert_eq;
 
@@ -312,6 +622,25 @@ mod test {
       }
     }
 
+    mod new {
+      use pretty_assertions::assert_eq;
+
+      use super::*;
+
+      #[test]
+      fn it_stores_a_static_spd() {
+        let illuminant = Illuminant::new("Test", IlluminantType::Custom, Spd::new(TEST_SPD));
+
+        assert_eq!(illuminant.spd().table(), TEST_SPD);
+      }
+
+      #[test]
+      #[should_panic(expected = "expected an SPD backed by static samples")]
+      fn it_panics_for_a_runtime_built_spd() {
+        Illuminant::new("Test", IlluminantType::Custom, Spd::from_fn(380, 420, 20, |_| 1.0));
+      }
+    }
+
     mod spd {
       use super::*;
 
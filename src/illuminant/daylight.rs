@@ -0,0 +1,122 @@
+//! CIE daylight (D-series) spectral power distributions.
+//!
+//! # Reference
+//!
+//! CIE 15:2018. "Colorimetry, 4th Edition." Section 4.1.2 and Table 6.
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+/// Temperature (K) splitting the two CIE daylight locus polynomials.
+const LOCUS_SPLIT: f64 = 7000.0;
+
+/// Lowest CCT (K) for which the CIE daylight locus is defined.
+const MIN_CCT: f64 = 4000.0;
+
+/// Highest CCT (K) for which the CIE daylight locus is defined.
+const MAX_CCT: f64 = 25000.0;
+
+/// CIE daylight component spectra S0, S1, S2 at 10 nm intervals (CIE 15, Table 6).
+static COMPONENTS: [(u32, [f64; 3]); 54] = [
+  (300, [0.04, 0.02, 0.0]),
+  (310, [6.0, 4.5, 2.0]),
+  (320, [29.6, 22.4, 4.0]),
+  (330, [55.3, 42.0, 8.5]),
+  (340, [57.3, 40.6, 7.8]),
+  (350, [61.8, 41.6, 6.7]),
+  (360, [61.5, 38.0, 5.3]),
+  (370, [68.8, 42.4, 6.1]),
+  (380, [63.4, 38.5, 3.0]),
+  (390, [65.8, 35.0, 1.2]),
+  (400, [94.8, 43.4, -1.1]),
+  (410, [104.8, 46.3, -0.5]),
+  (420, [105.9, 43.9, -0.7]),
+  (430, [96.8, 37.1, -1.2]),
+  (440, [113.9, 36.7, -2.6]),
+  (450, [125.6, 35.9, -2.9]),
+  (460, [125.5, 32.6, -2.8]),
+  (470, [121.3, 27.9, -2.6]),
+  (480, [121.3, 24.3, -2.6]),
+  (490, [113.5, 20.1, -1.8]),
+  (500, [113.1, 16.2, -1.5]),
+  (510, [110.8, 13.2, -1.3]),
+  (520, [106.5, 8.6, -1.2]),
+  (530, [108.8, 6.1, -1.0]),
+  (540, [105.3, 4.2, -0.5]),
+  (550, [104.4, 1.9, -0.3]),
+  (560, [100.0, 0.0, 0.0]),
+  (570, [96.0, -1.6, 0.2]),
+  (580, [95.1, -3.5, 0.5]),
+  (590, [89.1, -3.5, 2.1]),
+  (600, [90.5, -5.8, 3.2]),
+  (610, [90.3, -7.2, 4.1]),
+  (620, [88.4, -8.6, 4.7]),
+  (630, [84.0, -9.5, 5.1]),
+  (640, [85.1, -10.9, 6.7]),
+  (650, [81.9, -10.7, 7.3]),
+  (660, [82.6, -12.0, 8.6]),
+  (670, [84.9, -14.0, 9.8]),
+  (680, [81.3, -13.6, 10.2]),
+  (690, [71.9, -12.0, 8.3]),
+  (700, [74.3, -13.3, 9.6]),
+  (710, [76.4, -12.9, 8.5]),
+  (720, [63.3, -10.6, 7.0]),
+  (730, [71.7, -11.6, 7.6]),
+  (740, [77.0, -12.2, 8.0]),
+  (750, [65.2, -10.2, 6.7]),
+  (760, [47.7, -7.8, 5.2]),
+  (770, [68.6, -11.2, 7.4]),
+  (780, [65.0, -10.4, 6.8]),
+  (790, [66.0, -10.6, 7.0]),
+  (800, [61.0, -9.7, 6.4]),
+  (810, [53.3, -8.3, 5.5]),
+  (820, [58.9, -9.3, 6.1]),
+  (830, [61.9, -9.8, 6.5]),
+];
+
+/// Returns the relative spectral power of CIE daylight at `cct` for `wavelength` (nm).
+///
+/// The component spectra are linearly interpolated between their 10 nm samples, and `cct` is
+/// clamped to the 4000–25000 K range of the daylight locus. Wavelengths outside 300–830 nm
+/// have no power. The result is normalized to 100 at 560 nm.
+pub(crate) fn spectral_power(wavelength: u32, cct: f64) -> f64 {
+  let [m1, m2] = coefficients(cct);
+  let [s0, s1, s2] = components_at(wavelength);
+
+  s0 + m1 * s1 + m2 * s2
+}
+
+/// Returns the M1 and M2 weights of the S1 and S2 components for daylight at `cct`.
+fn coefficients(cct: f64) -> [f64; 2] {
+  let t = cct.clamp(MIN_CCT, MAX_CCT);
+  let x = if t <= LOCUS_SPLIT {
+    -4.6070e9 / t.powi(3) + 2.9678e6 / t.powi(2) + 0.09911e3 / t + 0.244063
+  } else {
+    -2.0064e9 / t.powi(3) + 1.9018e6 / t.powi(2) + 0.24748e3 / t + 0.237040
+  };
+  let y = -3.0 * x * x + 2.870 * x - 0.275;
+  let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+
+  [
+    (-1.3515 - 1.7703 * x + 5.9114 * y) / m,
+    (0.0300 - 31.4424 * x + 30.0717 * y) / m,
+  ]
+}
+
+/// Linearly interpolates the component spectra at `wavelength`, or zeros outside the table.
+fn components_at(wavelength: u32) -> [f64; 3] {
+  let (first, _) = COMPONENTS[0];
+  let (last, _) = COMPONENTS[COMPONENTS.len() - 1];
+  if !(first..=last).contains(&wavelength) {
+    return [0.0; 3];
+  }
+
+  let index = ((wavelength - first) / 10) as usize;
+  let (w1, a) = COMPONENTS[index];
+  let Some(&(_, b)) = COMPONENTS.get(index + 1) else {
+    return a;
+  };
+  let t = (wavelength - w1) as f64 / 10.0;
+
+  std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
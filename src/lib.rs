@@ -1,4 +1,5 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! A Rust library for colorimetry, color space conversions, and color manipulation.
 //!
 //! Farg provides context-aware color conversions with f64 precision, spectral data processing,
@@ -111,9 +112,21 @@
 //! | `all-illuminants` | All standard illuminants |
 //! | `all-observers` | All standard observers |
 //! | `all-rgb-spaces` | All RGB color spaces |
+//! | `libm` | `no_std` float math; disable the default `std` feature to build without the standard library |
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("farg requires either the `std` feature or, for `no_std` builds, the `libm` feature");
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+// Lets the `std::fmt`, `std::ops`, etc. paths used throughout the crate resolve to `core`.
+#[cfg(not(feature = "std"))]
+extern crate core as std;
 
 mod chromatic_adaptation_transform;
 pub mod chromaticity;
+#[cfg(feature = "cri")]
+pub mod color_rendering_index;
 pub mod color_vision_deficiency;
 mod component;
 mod context;
@@ -122,10 +135,16 @@ pub mod correlated_color_temperature;
 pub mod distance;
 mod error;
 mod illuminant;
+#[cfg(feature = "libm")]
+mod math;
 mod matrix;
 mod observer;
+#[cfg(not(feature = "std"))]
+mod prelude;
 pub mod space;
 mod spectral;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 
 pub use chromatic_adaptation_transform::{Cat, ChromaticAdaptationTransform};
 pub use context::ColorimetricContext;
@@ -0,0 +1,161 @@
+//! Floating-point math routed through [`libm`] for `no_std` builds.
+//!
+//! `core` does not provide the transcendental `f64` methods (`powf`, `cbrt`, `atan2`, ...) that
+//! `std` does. Without the `std` feature, [`Float`] supplies them under the same names so color
+//! math reads identically in both builds. The trait is also compiled alongside `std` when the
+//! `libm` feature is enabled, so its results can be checked against the `std` implementations.
+
+/// The `std`-only `f64` methods used by color math, implemented with [`libm`].
+///
+/// Which methods are called depends on the enabled color spaces and algorithms.
+#[allow(dead_code)]
+pub(crate) trait Float: Sized {
+  fn atan2(self, other: f64) -> f64;
+  fn cbrt(self) -> f64;
+  fn cos(self) -> f64;
+  fn exp(self) -> f64;
+  fn floor(self) -> f64;
+  fn fract(self) -> f64;
+  fn hypot(self, other: f64) -> f64;
+  fn ln(self) -> f64;
+  fn log10(self) -> f64;
+  fn powf(self, n: f64) -> f64;
+  fn powi(self, n: i32) -> f64;
+  fn rem_euclid(self, rhs: f64) -> f64;
+  fn round(self) -> f64;
+  fn sin(self) -> f64;
+  fn sqrt(self) -> f64;
+  fn tanh(self) -> f64;
+}
+
+impl Float for f64 {
+  fn atan2(self, other: f64) -> f64 {
+    libm::atan2(self, other)
+  }
+
+  fn cbrt(self) -> f64 {
+    libm::cbrt(self)
+  }
+
+  fn cos(self) -> f64 {
+    libm::cos(self)
+  }
+
+  fn exp(self) -> f64 {
+    libm::exp(self)
+  }
+
+  fn floor(self) -> f64 {
+    libm::floor(self)
+  }
+
+  fn fract(self) -> f64 {
+    self - libm::trunc(self)
+  }
+
+  fn hypot(self, other: f64) -> f64 {
+    libm::hypot(self, other)
+  }
+
+  fn ln(self) -> f64 {
+    libm::log(self)
+  }
+
+  fn log10(self) -> f64 {
+    libm::log10(self)
+  }
+
+  fn powf(self, n: f64) -> f64 {
+    libm::pow(self, n)
+  }
+
+  fn powi(self, n: i32) -> f64 {
+    libm::pow(self, n as f64)
+  }
+
+  fn rem_euclid(self, rhs: f64) -> f64 {
+    let remainder = self % rhs;
+    if remainder < 0.0 {
+      remainder + rhs.abs()
+    } else {
+      remainder
+    }
+  }
+
+  fn round(self) -> f64 {
+    libm::round(self)
+  }
+
+  fn sin(self) -> f64 {
+    libm::sin(self)
+  }
+
+  fn sqrt(self) -> f64 {
+    libm::sqrt(self)
+  }
+
+  fn tanh(self) -> f64 {
+    libm::tanh(self)
+  }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+  use super::*;
+
+  const EPSILON: f64 = 1e-9;
+  const SAMPLES: [f64; 9] = [-2.5, -1.0, -0.25, 0.0, 0.04045, 0.5, 1.0, 2.4, 95.047];
+
+  fn assert_close(libm: f64, std: f64) {
+    assert!(
+      (libm.is_nan() && std.is_nan())
+        || libm.to_bits() == std.to_bits()
+        || (libm - std).abs() <= EPSILON * std.abs().max(1.0),
+      "libm {libm} != std {std}"
+    );
+  }
+
+  mod float {
+    use super::*;
+
+    #[test]
+    fn it_matches_std_for_two_argument_functions() {
+      for x in SAMPLES {
+        for y in SAMPLES {
+          assert_close(Float::atan2(x, y), x.atan2(y));
+          assert_close(Float::hypot(x, y), x.hypot(y));
+          assert_close(Float::powf(x, y), x.powf(y));
+          if y != 0.0 {
+            assert_close(Float::rem_euclid(x, y), x.rem_euclid(y));
+          }
+        }
+      }
+    }
+
+    #[test]
+    fn it_matches_std_for_integer_powers() {
+      for x in SAMPLES {
+        for n in -3..=7 {
+          assert_close(Float::powi(x, n), x.powi(n));
+        }
+      }
+    }
+
+    #[test]
+    fn it_matches_std_for_single_argument_functions() {
+      for x in SAMPLES {
+        assert_close(Float::cbrt(x), x.cbrt());
+        assert_close(Float::cos(x), x.cos());
+        assert_close(Float::exp(x), x.exp());
+        assert_close(Float::floor(x), x.floor());
+        assert_close(Float::fract(x), x.fract());
+        assert_close(Float::ln(x), x.ln());
+        assert_close(Float::log10(x), x.log10());
+        assert_close(Float::round(x), x.round());
+        assert_close(Float::sin(x), x.sin());
+        assert_close(Float::sqrt(x), x.sqrt());
+        assert_close(Float::tanh(x), x.tanh());
+      }
+    }
+  }
+}
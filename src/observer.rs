@@ -15,10 +15,15 @@ mod stockman_sharpe_10d;
 #[cfg(feature = "observer-stockman-sharpe-2d")]
 mod stockman_sharpe_2d;
 
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+  fmt::{Display, Formatter, Result as FmtResult},
+  str::FromStr,
+};
 
 pub use fairchild_modifier::Modifier;
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
   chromaticity::Xy,
   component::Component,
@@ -221,6 +226,45 @@ impl Display for Observer {
   }
 }
 
+/// Parses a standard observer by name.
+///
+/// Matching is case-insensitive and ignores whitespace and punctuation, accepting both the
+/// display name (e.g. "CIE 1931 2°") and the constant name (e.g. "CIE_1931_2D"). "2°" and
+/// "default" are aliases for the CIE 1931 2° observer, and "10°" for the CIE 1964 10° observer.
+/// Only observers whose features are enabled are recognized.
+impl FromStr for Observer {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let normalized: String = s
+      .chars()
+      .filter(|c| c.is_ascii_alphanumeric())
+      .collect::<String>()
+      .to_ascii_uppercase();
+
+    match normalized.as_str() {
+      "2" | "CIE19312" | "CIE19312D" | "DEFAULT" => Ok(Self::CIE_1931_2D),
+      #[cfg(feature = "observer-cie-1931-judd-2d")]
+      "CIE1931JUDD2" | "CIE1931JUDD2D" => Ok(Self::CIE_1931_JUDD_2D),
+      #[cfg(feature = "observer-cie-1931-judd-vos-2d")]
+      "CIE1931JUDDVOS2" | "CIE1931JUDDVOS2D" => Ok(Self::CIE_1931_JUDD_VOS_2D),
+      #[cfg(feature = "observer-cie-1964-10d")]
+      "10" | "CIE196410" | "CIE196410D" => Ok(Self::CIE_1964_10D),
+      #[cfg(feature = "observer-cie-2006-10d")]
+      "CIE200610" | "CIE200610D" => Ok(Self::CIE_2006_10D),
+      #[cfg(feature = "observer-cie-2006-2d")]
+      "CIE20062" | "CIE20062D" => Ok(Self::CIE_2006_2D),
+      #[cfg(feature = "observer-stockman-sharpe-10d")]
+      "STOCKMANSHARPE10" | "STOCKMANSHARPE10D" => Ok(Self::STOCKMAN_SHARPE_10D),
+      #[cfg(feature = "observer-stockman-sharpe-2d")]
+      "STOCKMANSHARPE2" | "STOCKMANSHARPE2D" => Ok(Self::STOCKMAN_SHARPE_2D),
+      _ => Err(Error::UnknownObserver {
+        name: s.to_string(),
+      }),
+    }
+  }
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -329,6 +373,66 @@ mod test {
       }
     }
 
+    mod from_str {
+      use pretty_assertions::assert_eq;
+
+      use super::*;
+
+      #[test]
+      fn it_parses_display_name() {
+        assert_eq!("CIE 1931 2°".parse::<Observer>().unwrap().name(), "CIE 1931 2°");
+      }
+
+      #[test]
+      fn it_parses_constant_name_case_insensitively() {
+        assert_eq!(Observer::from_str("cie_1931_2d").unwrap().name(), "CIE 1931 2°");
+      }
+
+      #[test]
+      fn it_parses_aliases() {
+        assert_eq!("2°".parse::<Observer>().unwrap().name(), "CIE 1931 2°");
+        assert_eq!("default".parse::<Observer>().unwrap().name(), "CIE 1931 2°");
+      }
+
+      #[test]
+      fn it_round_trips_display() {
+        let observer = Observer::CIE_1931_2D;
+
+        assert_eq!(
+          observer.to_string().parse::<Observer>().unwrap().name(),
+          observer.name()
+        );
+      }
+
+      #[cfg(feature = "observer-cie-1964-10d")]
+      #[test]
+      fn it_parses_ten_degree_observer() {
+        assert_eq!("10°".parse::<Observer>().unwrap().name(), "CIE 1964 10°");
+        assert_eq!("CIE 1964 10°".parse::<Observer>().unwrap().name(), "CIE 1964 10°");
+      }
+
+      #[cfg(not(feature = "observer-cie-2006-2d"))]
+      #[test]
+      fn it_returns_error_for_uncompiled_observer() {
+        assert_eq!(
+          "CIE 2006 2°".parse::<Observer>().unwrap_err(),
+          Error::UnknownObserver {
+            name: "CIE 2006 2°".to_string()
+          }
+        );
+      }
+
+      #[test]
+      fn it_returns_error_for_unknown_name() {
+        assert_eq!(
+          "CIE 1999 4°".parse::<Observer>().unwrap_err(),
+          Error::UnknownObserver {
+            name: "CIE 1999 4°".to_string()
+          }
+        );
+      }
+    }
+
     mod modifier {
       use super::*;
 
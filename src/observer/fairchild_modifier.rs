@@ -1,4 +1,6 @@
 use super::Observer;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
   space::{Lms, Xyz},
   spectral::Table,
@@ -0,0 +1,13 @@
+//! The `alloc` items the standard prelude normally provides, plus [`Float`] math, for `no_std`
+//! builds.
+
+pub(crate) use alloc::{
+  borrow::ToOwned,
+  boxed::Box,
+  format,
+  string::{String, ToString},
+  vec,
+  vec::Vec,
+};
+
+pub(crate) use crate::math::Float;
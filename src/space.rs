@@ -1,19 +1,56 @@
+mod adaptation;
 mod cie;
+#[cfg(any(feature = "space-hwb", feature = "space-lab", feature = "space-lch"))]
+mod css;
 mod cylindrical;
+#[cfg(feature = "space-oklab")]
+mod gamut;
+#[cfg(feature = "space-oklch")]
+mod harmony;
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+mod hue_arc;
+mod image;
+mod interpolation;
+pub mod iter;
+#[cfg(feature = "space-oklab")]
+mod palette;
 mod perceptual;
 mod physiological;
+#[cfg(any(feature = "space-lab", feature = "space-luv", feature = "space-oklab"))]
+mod polar;
 mod rgb;
+#[cfg(feature = "serde")]
+pub mod serde_array;
 mod subtractive;
+#[cfg(feature = "trace")]
+mod trace;
 
+pub use adaptation::adapt_all;
 pub use cie::*;
 #[allow(unused_imports)]
 pub use cylindrical::*;
+#[cfg(feature = "space-oklab")]
+pub use gamut::gamut_volume;
+#[cfg(feature = "space-oklch")]
+pub use harmony::HarmonyScheme;
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+pub use hue_arc::HueArc;
+pub use image::{dither_row, downsample_2x2};
+pub use interpolation::{InterpolationSpace, gradient_in, mix_hue};
+#[cfg(feature = "space-oklch")]
+pub use palette::remap_lightness;
+#[cfg(feature = "space-oklab")]
+pub use palette::{average, sort_by_lightness};
 #[allow(unused_imports)]
 pub use perceptual::*;
 pub use physiological::*;
+#[cfg(any(feature = "space-lab", feature = "space-luv", feature = "space-oklab"))]
+pub use polar::PolarColor;
 pub use rgb::*;
 #[allow(unused_imports)]
 pub use subtractive::*;
+#[cfg(feature = "trace")]
+pub use trace::{HubPath, conversion_path};
 
 #[cfg(feature = "chromaticity-rg")]
 use crate::chromaticity::Rg;
@@ -21,13 +58,56 @@ use crate::chromaticity::Rg;
 use crate::chromaticity::Upvp;
 #[cfg(feature = "chromaticity-uv")]
 use crate::chromaticity::Uv;
-use crate::{chromaticity::Xy, component::Component};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorimetricContext, chromaticity::Xy, component::Component};
 
 /// Common interface for all color spaces.
 ///
 /// Provides conversions between spaces, luminance operations, and component access.
 /// All color spaces can convert to [`Xyz`], which serves as the universal hub.
 pub trait ColorSpace<const N: usize>: Copy + Clone + From<Xyz> {
+  /// Returns a contrasting accent color derived from this one.
+  ///
+  /// The color is converted to Oklch, its hue rotated by 180°, and its lightness pushed to the
+  /// opposite end of the range (0.8 for dark colors with L < 0.5, 0.25 otherwise) with a moderate
+  /// chroma of 0.12, giving a usable complementary accent for any color. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Rgb, Srgb};
+  ///
+  /// let accent = Rgb::<Srgb>::new(20, 30, 120).accent();
+  /// assert_eq!(accent.l(), 0.8);
+  /// ```
+  #[cfg(feature = "space-oklch")]
+  fn accent(&self) -> Oklch {
+    let oklch = self.to_oklch();
+    let l = if oklch.l() < 0.5 { 0.8 } else { 0.25 };
+
+    Oklch::new(l, 0.12, oklch.hue() + 180.0).with_alpha(oklch.alpha())
+  }
+
+  /// Converts this color to XYZ and chromatically adapts it to the given viewing context.
+  ///
+  /// The result is always [`Xyz`] because not every space can represent an arbitrary
+  /// reference white (e.g. RGB spaces are tied to the white point of their spec). Convert
+  /// the result into a space of your choosing if needed.
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "space-hsv", feature = "illuminant-d50"))]
+  /// # {
+  /// use farg::{ColorimetricContext, Illuminant};
+  /// use farg::space::{ColorSpace, Hsv, Srgb};
+  ///
+  /// let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+  /// let adapted = Hsv::<Srgb>::new(0.0, 0.0, 100.0).adapt_to(d50);
+  /// assert_eq!(adapted.context().illuminant().name(), "D50");
+  /// # }
+  /// ```
+  fn adapt_to(&self, context: ColorimetricContext) -> Xyz {
+    self.to_xyz().adapt_to(context)
+  }
+
   /// Returns the alpha (transparency) of the color on a 0.0 to 1.0 scale.
   fn alpha(&self) -> f64;
 
@@ -63,6 +143,32 @@ pub trait ColorSpace<const N: usize>: Copy + Clone + From<Xyz> {
     [self.with_hue_decremented_by(30), self.with_hue_incremented_by(30)]
   }
 
+  /// Returns `true` if every component and alpha of `self` and `other` differ by at most `epsilon`.
+  ///
+  /// The `PartialEq` implementations compare exact values (or rounded 8-bit channels for RGB),
+  /// which is brittle after round-trip conversions. Components are compared in their normalized
+  /// form as returned by [`components`](ColorSpace::components).
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Xyz};
+  ///
+  /// let a = Xyz::new(0.5, 0.5, 0.5);
+  /// let b = Xyz::new(0.5 + 1e-12, 0.5, 0.5);
+  ///
+  /// assert!(a.approx_eq(b, 1e-9));
+  /// assert!(!a.approx_eq(b, 1e-15));
+  /// ```
+  fn approx_eq(&self, other: impl Into<Self>, epsilon: f64) -> bool {
+    let other = other.into();
+
+    self
+      .components()
+      .iter()
+      .zip(other.components())
+      .all(|(a, b)| (a - b).abs() <= epsilon)
+      && (self.alpha() - other.alpha()).abs() <= epsilon
+  }
+
   /// Divides all components in place by the given factor.
   fn attenuate(&mut self, factor: impl Into<Component>) {
     self.set_components(self.attenuated_by(factor).components())
@@ -1112,6 +1218,10 @@ pub trait ColorSpace<const N: usize>: Copy + Clone + From<Xyz> {
   }
 
   /// Converts to the CIE L*a*b* color space.
+  ///
+  /// CIELAB and Oklab are distinct spaces: CIELAB's L* runs 0-100 and its a*/b* axes come from the
+  /// 1976 CIE formulas, while Oklab's L runs 0-1 and its a/b axes derive from an LMS cone model.
+  /// Converting between them goes through XYZ.
   #[cfg(feature = "space-lab")]
   fn to_lab(&self) -> Lab {
     Lab::from(self.to_xyz()).with_alpha(self.alpha())
@@ -1159,6 +1269,9 @@ pub trait ColorSpace<const N: usize>: Copy + Clone + From<Xyz> {
   }
 
   /// Converts to the Oklab perceptual color space.
+  ///
+  /// Oklab is not CIELAB under another name: its L runs 0-1 rather than 0-100 and its a/b axes derive
+  /// from an LMS cone model rather than the 1976 CIE formulas.
   #[cfg(feature = "space-oklab")]
   fn to_oklab(&self) -> Oklab {
     self.to_xyz().to_oklab().with_alpha(self.alpha())
@@ -1178,6 +1291,26 @@ pub trait ColorSpace<const N: usize>: Copy + Clone + From<Xyz> {
     self.to_xyz().to_rgb::<S>().with_alpha(self.alpha())
   }
 
+  /// Converts to any color space constructible from [`Xyz`].
+  ///
+  /// This is a generic alternative to the dedicated `to_*` methods, routing the conversion through
+  /// [`to_xyz`](ColorSpace::to_xyz). Alpha is carried through the intermediate [`Xyz`] value.
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Rgb, Srgb, Xyz};
+  ///
+  /// let rgb = Rgb::<Srgb>::new(255, 87, 51);
+  /// let xyz: Xyz = rgb.to_space();
+  ///
+  /// assert_eq!(xyz, rgb.to_xyz());
+  /// ```
+  fn to_space<T>(&self) -> T
+  where
+    T: From<Xyz>,
+  {
+    T::from(self.to_xyz())
+  }
+
   /// Converts to the CIE xyY color space.
   #[cfg(feature = "space-xyy")]
   fn to_xyy(&self) -> Xyy {
@@ -1187,6 +1320,26 @@ pub trait ColorSpace<const N: usize>: Copy + Clone + From<Xyz> {
   /// Converts to CIE XYZ.
   fn to_xyz(&self) -> Xyz;
 
+  /// Converts to CIE XYZ and expresses the result under the given viewing context.
+  ///
+  /// Equivalent to `self.to_xyz().adapt_to(context)`: the color is converted in its own context
+  /// and then chromatically adapted, so a color already in `context` is returned unchanged.
+  ///
+  /// ```
+  /// # #[cfg(feature = "illuminant-d50")]
+  /// # {
+  /// use farg::{ColorimetricContext, Illuminant};
+  /// use farg::space::{ColorSpace, Rgb, Srgb};
+  ///
+  /// let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+  /// let xyz = Rgb::<Srgb>::new(255, 128, 0).to_xyz_in(d50);
+  /// assert_eq!(xyz.context().illuminant().name(), "D50");
+  /// # }
+  /// ```
+  fn to_xyz_in(&self, context: ColorimetricContext) -> Xyz {
+    self.adapt_to(context)
+  }
+
   /// Returns the two triadic colors (+120° and +240° hue rotation).
   ///
   /// Triadic harmony places three colors at equal 120° intervals around the
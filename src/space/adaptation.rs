@@ -0,0 +1,110 @@
+use super::{ColorSpace, Xyz};
+use crate::ColorimetricContext;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Chromatically adapts every color in `colors` to the `destination` viewing context.
+///
+/// Produces the same results as calling [`Xyz::adapt_to`] on each color, but the adaptation
+/// matrix is computed once for the reference white of the first color and reused across the
+/// slice, which is much cheaper for large palettes. Colors whose reference white differs from
+/// the first fall back to a per-color [`Xyz::adapt_to`].
+///
+/// ```
+/// use farg::{ColorimetricContext, space::{Xyz, adapt_all}};
+///
+/// let palette = [Xyz::new(0.2, 0.3, 0.4), Xyz::new(0.5, 0.5, 0.5)];
+/// let adapted = adapt_all(&palette, ColorimetricContext::new());
+///
+/// assert_eq!(adapted.len(), 2);
+/// ```
+pub fn adapt_all(colors: &[Xyz], destination: ColorimetricContext) -> Vec<Xyz> {
+  let Some(first) = colors.first() else {
+    return Vec::new();
+  };
+
+  let reference_white = first.context().reference_white();
+  let target_white = destination.reference_white();
+  let matrix =
+    (reference_white != target_white).then(|| destination.cat().adaptation_matrix(reference_white, target_white));
+
+  colors
+    .iter()
+    .map(|color| {
+      if color.context().reference_white() != reference_white {
+        return color.adapt_to(destination);
+      }
+
+      match matrix {
+        Some(matrix) => {
+          let [x, y, z] = matrix * *color;
+          Xyz::new(x, y, z).with_context(destination).with_alpha(color.alpha())
+        }
+        None => color.with_context(destination),
+      }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod adapt_all {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_empty_for_an_empty_slice() {
+      assert!(adapt_all(&[], ColorimetricContext::new()).is_empty());
+    }
+
+    #[test]
+    fn it_keeps_values_when_the_white_point_is_unchanged() {
+      let palette = [Xyz::new(0.2, 0.3, 0.4).with_alpha(0.5)];
+      let adapted = adapt_all(&palette, ColorimetricContext::new());
+
+      assert_eq!(adapted[0].components(), palette[0].components());
+      assert_eq!(adapted[0].alpha(), 0.5);
+    }
+
+    #[cfg(feature = "illuminant-d50")]
+    #[test]
+    fn it_matches_per_color_adapt_to() {
+      use crate::Illuminant;
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let palette = [
+        Xyz::new(0.2, 0.3, 0.4),
+        Xyz::new(0.95047, 1.0, 1.08883).with_alpha(0.25),
+        Xyz::new(0.05, 0.02, 0.3),
+      ];
+      let adapted = adapt_all(&palette, d50);
+
+      for (bulk, color) in adapted.iter().zip(&palette) {
+        let single = color.adapt_to(d50);
+        for (a, b) in bulk.components().iter().zip(single.components()) {
+          assert!((a - b).abs() < 1e-12);
+        }
+        assert_eq!(bulk.alpha(), single.alpha());
+        assert_eq!(bulk.context().illuminant().name(), "D50");
+      }
+    }
+
+    #[cfg(feature = "illuminant-d50")]
+    #[test]
+    fn it_falls_back_for_colors_with_a_different_source_context() {
+      use crate::Illuminant;
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let palette = [Xyz::new(0.2, 0.3, 0.4), Xyz::new(0.4, 0.3, 0.2).with_context(d50)];
+      let adapted = adapt_all(&palette, ColorimetricContext::new());
+      let expected = palette[1].adapt_to(ColorimetricContext::new());
+
+      for (a, b) in adapted[1].components().iter().zip(expected.components()) {
+        assert!((a - b).abs() < 1e-12);
+      }
+    }
+  }
+}
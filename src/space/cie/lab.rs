@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lch")]
@@ -38,11 +42,21 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext, Error,
   component::Component,
-  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, Lms, PolarColor, Rgb, RgbSpec, Srgb, Xyz, css, polar::polar_coordinates},
 };
 
+/// The viewing context CSS Color Level 4 uses for `lab()` and `lch()` (D50, CIE 1931 2°,
+/// Bradford adaptation regardless of the default CAT).
+#[cfg(feature = "illuminant-d50")]
+pub(crate) const CSS_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT
+  .with_illuminant(crate::Illuminant::D50)
+  .with_cat(crate::Cat::new(
+    "Bradford",
+    crate::chromatic_adaptation_transform::BRADFORD_MATRIX,
+  ));
+
 /// CIE 1976 L\*a\*b\* threshold: δ³ = (6/29)³.
 const DELTA_CUBED: f64 = (6.0 / 29.0) * (6.0 / 29.0) * (6.0 / 29.0);
 
@@ -69,9 +83,72 @@ pub struct Lab {
 
 impl Lab {
   /// The default viewing context for Lab (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
+
+  /// Interpolates between two colors the way CSS `color-mix(in lab, ...)` does.
+  ///
+  /// Both colors are adapted with Bradford to the D50 reference white CSS uses for `lab()`, then
+  /// L\*, a\*, and b\* are interpolated linearly with premultiplied alpha. The result is returned
+  /// in the D50 context; `t` is the fraction of `color2` in the mix.
+  ///
+  /// ```
+  /// use farg::space::{Lab, Rgb, Srgb};
+  ///
+  /// let mixed = Lab::color_mix(Rgb::<Srgb>::new(255, 0, 0), Rgb::<Srgb>::new(0, 0, 255), 0.5);
+  /// assert!((mixed.l() - 41.9).abs() < 0.5);
+  /// ```
+  #[cfg(feature = "illuminant-d50")]
+  pub fn color_mix(color1: impl Into<Xyz>, color2: impl Into<Xyz>, t: f64) -> Self {
+    let lab1 = lab_in_context(color1.into(), CSS_CONTEXT);
+    let lab2 = lab_in_context(color2.into(), CSS_CONTEXT);
+    let [l1, a1, b1] = lab1.components();
+    let [l2, a2, b2] = lab2.components();
+    let (alpha1, alpha2) = (lab1.alpha(), lab2.alpha());
+
+    let l = lerp_premultiplied(l1, alpha1, l2, alpha2, t);
+    let a = lerp_premultiplied(a1, alpha1, a2, alpha2, t);
+    let b = lerp_premultiplied(b1, alpha1, b2, alpha2, t);
+    let alpha = Component::new(alpha1).lerp(alpha2, t);
+
+    Self::new(l, a, b).with_context(CSS_CONTEXT).with_alpha(alpha)
+  }
+
+  /// Parses a CSS Color Level 4 `lab(...)` string, e.g. `lab(56.7% 43.2 -12.1 / 0.5)`.
+  ///
+  /// L may be a number (0-100) or a percentage, where 100% is L\* 100; because this crate also
+  /// stores L\* on the 0-100 scale, the numeric and percentage forms map directly. a and b may be
+  /// numbers or percentages, where 100% is 125 per the CSS specification. L is clamped to 0-100
+  /// and `none` resolves to 0. When the `illuminant-d50` feature is enabled the result is placed
+  /// in the D50 viewing context CSS specifies, so conversions to D65 spaces adapt it from D50
+  /// (matching CSS when the default CAT is Bradford); otherwise the values are taken as-is in
+  /// the default viewing context.
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Lab};
+  ///
+  /// let color = Lab::from_css("lab(56.7% 43.2 -12.1 / 0.5)").unwrap();
+  ///
+  /// assert!((color.l() - 56.7).abs() < 1e-10);
+  /// assert!((color.alpha() - 0.5).abs() < 1e-10);
+  /// ```
+  pub fn from_css(css: impl Into<String>) -> Result<Self, Error> {
+    let css = css.into();
+    let invalid = || Error::InvalidCssColor {
+      input: css.clone(),
+    };
+    let ([l, a, b], alpha) = css::parse_function(&css, "lab").ok_or_else(invalid)?;
+
+    let l = css::parse_number(l, 100.0).ok_or_else(invalid)?.clamp(0.0, 100.0);
+    let a = css::parse_number(a, 125.0).ok_or_else(invalid)?;
+    let b = css::parse_number(b, 125.0).ok_or_else(invalid)?;
+    let alpha = css::parse_alpha(alpha).ok_or_else(invalid)?;
+
+    let color = Self::new(l, a, b).with_alpha(alpha);
+    #[cfg(feature = "illuminant-d50")]
+    let color = color.with_context(CSS_CONTEXT);
+
+    Ok(color)
+  }
 
   /// Creates a new Lab color with the default viewing context.
   pub fn new(l: impl Into<Component>, a: impl Into<Component>, b: impl Into<Component>) -> Self {
@@ -117,6 +194,13 @@ impl Lab {
     self.b.0
   }
 
+  /// Returns the colorfulness of the color: its CIE chroma C\*ab, the distance from the neutral axis.
+  ///
+  /// Alias for [`PolarColor::polar_chroma`].
+  pub fn colorfulness(&self) -> f64 {
+    self.polar_chroma()
+  }
+
   /// Returns the [L\*, a\*, b\*] components as an array.
   pub fn components(&self) -> [f64; 3] {
     [self.l.0, self.a.0, self.b.0]
@@ -251,12 +335,13 @@ impl Lab {
   /// Returns this color as a CSS Color Level 4 `lab(...)` string.
   ///
   /// L is 0-100, a and b are signed values. Alpha is appended only when less
-  /// than 1.0.
+  /// than 1.0. When the `illuminant-d50` feature is enabled the color is first adapted to the
+  /// D50 viewing context CSS specifies, mirroring [`Self::from_css`].
   ///
   /// ```
   /// use farg::space::{ColorSpace, Lab};
   ///
-  /// let color = Lab::new(62.0, 56.0, 46.0);
+  /// let color = Lab::from_css("lab(62 56 46)").unwrap();
   /// assert_eq!(color.to_css(), "lab(62 56 46)");
   /// ```
   pub fn to_css(&self) -> String {
@@ -267,11 +352,16 @@ impl Lab {
         .to_string()
     }
 
-    let a = self.alpha.0;
+    #[cfg(feature = "illuminant-d50")]
+    let color = lab_in_context(self.to_xyz(), CSS_CONTEXT);
+    #[cfg(not(feature = "illuminant-d50"))]
+    let color = *self;
+
+    let a = color.alpha.0;
     if a < 1.0 {
-      format!("lab({} {} {} / {})", f(self.l()), f(self.a()), f(self.b()), f(a))
+      format!("lab({} {} {} / {})", f(color.l()), f(color.a()), f(color.b()), f(a))
     } else {
-      format!("lab({} {} {})", f(self.l()), f(self.a()), f(self.b()))
+      format!("lab({} {} {})", f(color.l()), f(color.a()), f(color.b()))
     }
   }
 
@@ -395,6 +485,25 @@ impl Lab {
     lab.scale_l(factor);
     lab
   }
+
+  /// Returns a new color with the given lightness on the CIE L\* scale (0-100).
+  ///
+  /// L\* is stored on its native 0-100 scale (not normalized to 0-1); unlike
+  /// [`with_l`](Self::with_l), the value is clamped to 0-100 so the result is always a valid
+  /// lightness.
+  ///
+  /// ```
+  /// use farg::space::{Lab, Rgb, Srgb};
+  ///
+  /// let color = Lab::from(Rgb::<Srgb>::new(255, 87, 51).to_xyz()).with_lightness(120.0);
+  /// assert_eq!(color.l(), 100.0);
+  /// ```
+  pub fn with_lightness(&self, lightness: impl Into<Component>) -> Self {
+    Self {
+      l: lightness.into().clamp(0.0, 100.0),
+      ..*self
+    }
+  }
 }
 
 impl<T> Add<T> for Lab
@@ -653,6 +762,13 @@ impl From<Xyz> for Lab {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Lab {
+  fn hub_path() -> Vec<&'static str> {
+    vec!["Lab", "Xyz"]
+  }
+}
+
 impl<T> Mul<T> for Lab
 where
   T: Into<Self>,
@@ -674,6 +790,16 @@ where
   }
 }
 
+impl PolarColor for Lab {
+  fn hue_degrees(&self) -> f64 {
+    polar_coordinates(self.a.0, self.b.0).1
+  }
+
+  fn polar_chroma(&self) -> f64 {
+    polar_coordinates(self.a.0, self.b.0).0
+  }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Lab {
   fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -706,6 +832,14 @@ impl TryFrom<&str> for Lab {
   type Error = crate::Error;
 
   fn try_from(value: &str) -> Result<Self, Self::Error> {
+    if value
+      .trim_start()
+      .get(..4)
+      .is_some_and(|prefix| prefix.eq_ignore_ascii_case("lab("))
+    {
+      return Self::from_css(value);
+    }
+
     Ok(Self::from(Rgb::<Srgb>::try_from(value)?.to_xyz()))
   }
 }
@@ -714,7 +848,7 @@ impl TryFrom<String> for Lab {
   type Error = crate::Error;
 
   fn try_from(value: String) -> Result<Self, Self::Error> {
-    Ok(Self::from(Rgb::<Srgb>::try_from(value)?.to_xyz()))
+    Self::try_from(value.as_str())
   }
 }
 
@@ -741,6 +875,33 @@ fn lab_f_inv(t: f64) -> f64 {
   }
 }
 
+/// Converts XYZ to L\*a\*b\* relative to the given context's reference white.
+#[cfg(feature = "illuminant-d50")]
+pub(crate) fn lab_in_context(xyz: Xyz, context: ColorimetricContext) -> Lab {
+  let [xn, yn, zn] = context.reference_white().components();
+  let [x, y, z] = xyz.adapt_to(context).components();
+
+  let l = 116.0 * lab_f(y / yn) - 16.0;
+  let a = 500.0 * (lab_f(x / xn) - lab_f(y / yn));
+  let b = 200.0 * (lab_f(y / yn) - lab_f(z / zn));
+
+  Lab::new(l, a, b).with_context(context).with_alpha(xyz.alpha())
+}
+
+/// Linearly interpolates a component with premultiplied alpha, per CSS Color Level 4.
+///
+/// Falls back to plain interpolation when the interpolated alpha is zero.
+#[cfg(feature = "illuminant-d50")]
+pub(crate) fn lerp_premultiplied(v1: f64, alpha1: f64, v2: f64, alpha2: f64, t: f64) -> f64 {
+  let alpha = alpha1 + (alpha2 - alpha1) * t;
+
+  if alpha == 0.0 {
+    v1 + (v2 - v1) * t
+  } else {
+    (v1 * alpha1 + (v2 * alpha2 - v1 * alpha1) * t) / alpha
+  }
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -873,6 +1034,17 @@ mod test {
     }
   }
 
+  mod colorfulness {
+    use super::*;
+
+    #[test]
+    fn it_matches_chroma() {
+      let lab = Lab::new(60.0, -25.0, 35.0);
+
+      assert_eq!(lab.colorfulness(), lab.polar_chroma());
+    }
+  }
+
   mod components {
     use pretty_assertions::assert_eq;
 
@@ -913,6 +1085,57 @@ mod test {
     }
   }
 
+  #[cfg(feature = "illuminant-d50")]
+  mod color_mix {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_matches_css_color_mix_in_lab_for_red_and_blue() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let blue = Rgb::<Srgb>::new(0, 0, 255);
+      let mixed = Lab::color_mix(red, blue, 0.5);
+
+      assert!((mixed.l() - 41.93).abs() < 0.5);
+      assert!((mixed.a() - 74.55).abs() < 0.5);
+      assert!((mixed.b() - -21.07).abs() < 0.5);
+    }
+
+    #[test]
+    fn it_returns_endpoints_at_zero_and_one() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let blue = Rgb::<Srgb>::new(0, 0, 255);
+
+      assert!((Lab::color_mix(red, blue, 0.0).l() - 54.29).abs() < 0.5);
+      assert!((Lab::color_mix(red, blue, 1.0).l() - 29.57).abs() < 0.5);
+    }
+
+    #[test]
+    fn it_returns_result_in_d50_context() {
+      let mixed = Lab::color_mix(Rgb::<Srgb>::new(255, 0, 0), Rgb::<Srgb>::new(0, 0, 255), 0.5);
+
+      assert_eq!(mixed.context().illuminant().name(), "D50");
+    }
+
+    #[test]
+    fn it_adapts_with_bradford() {
+      let mixed = Lab::color_mix(Rgb::<Srgb>::new(255, 0, 0), Rgb::<Srgb>::new(0, 0, 255), 0.5);
+
+      assert_eq!(mixed.context().cat().name(), "Bradford");
+    }
+
+    #[test]
+    fn it_premultiplies_alpha() {
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let transparent_black = Rgb::<Srgb>::new(0, 0, 0).with_alpha(0.0);
+      let mixed = Lab::color_mix(white, transparent_black, 0.5);
+
+      assert!((mixed.l() - 100.0).abs() < 0.5);
+      assert!((mixed.alpha() - 0.5).abs() < 1e-10);
+    }
+  }
+
   mod compress_to_gamut {
     use super::*;
 
@@ -1020,6 +1243,95 @@ mod test {
     }
   }
 
+  mod from_css {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_numeric_components() {
+      let lab = Lab::from_css("lab(56.7 43.2 -12.1)").unwrap();
+
+      assert!((lab.l() - 56.7).abs() < 1e-10);
+      assert!((lab.a() - 43.2).abs() < 1e-10);
+      assert!((lab.b() + 12.1).abs() < 1e-10);
+      assert_eq!(lab.alpha(), 1.0);
+    }
+
+    #[test]
+    fn it_parses_percent_lightness_identically_to_numeric() {
+      let percent = Lab::from_css("lab(56.7% 43.2 -12.1)").unwrap();
+      let numeric = Lab::from_css("lab(56.7 43.2 -12.1)").unwrap();
+
+      assert_eq!(percent, numeric);
+    }
+
+    #[test]
+    fn it_scales_percent_a_and_b() {
+      let lab = Lab::from_css("lab(50% 100% -50%)").unwrap();
+
+      assert!((lab.a() - 125.0).abs() < 1e-10);
+      assert!((lab.b() + 62.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_parses_alpha() {
+      let lab = Lab::from_css("lab(50 20 -30 / 50%)").unwrap();
+
+      assert!((lab.alpha() - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_resolves_none_to_zero() {
+      let lab = Lab::from_css("lab(50 none 10)").unwrap();
+
+      assert_eq!(lab.a(), 0.0);
+    }
+
+    #[test]
+    fn it_clamps_lightness() {
+      let lab = Lab::from_css("lab(150 0 0)").unwrap();
+
+      assert_eq!(lab.l(), 100.0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "illuminant-d50", feature = "cat-bradford"))]
+    fn it_converts_to_srgb_like_css() {
+      let rgb = Lab::from_css("lab(50% 40 30)").unwrap().to_rgb::<Srgb>();
+
+      assert!((rgb.r() * 255.0 - 187.24).abs() < 0.05);
+      assert!((rgb.g() * 255.0 - 87.75).abs() < 0.05);
+      assert!((rgb.b() * 255.0 - 70.47).abs() < 0.05);
+    }
+
+    #[cfg(any(not(feature = "illuminant-d50"), feature = "cat-bradford"))]
+    #[test]
+    fn it_roundtrips_through_to_css() {
+      let original = Lab::new(62.5, -20.25, 46.0).with_alpha(0.75);
+      let parsed = Lab::from_css(original.to_css()).unwrap();
+
+      let restored = parsed.to_xyz().adapt_to(*original.context());
+      for (a, b) in restored.components().iter().zip(original.to_xyz().components()) {
+        assert!((a - b).abs() < 1e-6);
+      }
+      assert_eq!(parsed.alpha(), original.alpha());
+      assert_eq!(parsed.to_css(), original.to_css());
+    }
+
+    #[test]
+    fn it_returns_error_for_invalid_input() {
+      for input in ["lch(50 20 30)", "lab(50 20)", "lab(50 red 30)", "lab 50 20 30"] {
+        assert_eq!(
+          Lab::from_css(input),
+          Err(Error::InvalidCssColor {
+            input: input.to_string()
+          })
+        );
+      }
+    }
+  }
+
   mod from_rgb {
     use super::*;
 
@@ -1143,6 +1455,23 @@ mod test {
     }
   }
 
+  mod hue_degrees {
+    use super::*;
+
+    #[test]
+    fn it_wraps_negative_angles() {
+      assert!((Lab::new(50.0, 0.0, -10.0).hue_degrees() - 270.0).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "space-lch")]
+    #[test]
+    fn it_agrees_with_lch() {
+      let lab = Lab::new(60.0, -25.0, -35.0);
+
+      assert!((lab.hue_degrees() - lab.to_lch().hue()).abs() < 1e-10);
+    }
+  }
+
   mod increment_a {
     use super::*;
 
@@ -1351,6 +1680,23 @@ mod test {
     }
   }
 
+  mod polar_chroma {
+    use super::*;
+
+    #[test]
+    fn it_returns_distance_from_neutral_axis() {
+      assert!((Lab::new(50.0, 30.0, 40.0).polar_chroma() - 50.0).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "space-lch")]
+    #[test]
+    fn it_agrees_with_lch() {
+      let lab = Lab::new(60.0, -25.0, 35.0);
+
+      assert!((lab.polar_chroma() - lab.to_lch().chroma()).abs() < 1e-10);
+    }
+  }
+
   mod roundtrip {
     use super::*;
 
@@ -1450,23 +1796,68 @@ mod test {
 
     use super::*;
 
+    /// Places `color` in the viewing context `to_css` formats in, so its components print as-is.
+    fn in_css_context(color: Lab) -> Lab {
+      #[cfg(feature = "illuminant-d50")]
+      let color = color.with_context(CSS_CONTEXT);
+      color
+    }
+
     #[test]
     fn it_outputs_opaque_lab() {
-      let color = Lab::new(62.0, 56.0, 46.0);
+      let color = in_css_context(Lab::new(62.0, 56.0, 46.0));
       assert_eq!(color.to_css(), "lab(62 56 46)");
     }
 
     #[test]
     fn it_outputs_translucent_lab() {
-      let color = Lab::new(62.0, 56.0, 46.0).with_alpha(0.5);
+      let color = in_css_context(Lab::new(62.0, 56.0, 46.0).with_alpha(0.5));
       assert_eq!(color.to_css(), "lab(62 56 46 / 0.5)");
     }
 
     #[test]
     fn it_outputs_negative_components() {
-      let color = Lab::new(50.0, -20.0, -30.0);
+      let color = in_css_context(Lab::new(50.0, -20.0, -30.0));
       assert_eq!(color.to_css(), "lab(50 -20 -30)");
     }
+
+    #[cfg(all(feature = "illuminant-d50", feature = "cat-bradford"))]
+    #[test]
+    fn it_adapts_to_the_css_context() {
+      use pretty_assertions::assert_ne;
+
+      let color = Lab::new(50.0, 40.0, 30.0);
+      let parsed = Lab::from_css(color.to_css()).unwrap();
+
+      assert_ne!(color.to_css(), "lab(50 40 30)");
+      let restored = parsed.to_xyz().adapt_to(*color.context());
+      for (a, b) in restored.components().iter().zip(color.to_xyz().components()) {
+        assert!((a - b).abs() < 1e-6);
+      }
+    }
+  }
+
+  #[cfg(feature = "space-oklab")]
+  mod to_oklab {
+    use super::*;
+
+    #[test]
+    fn it_maps_white_to_white() {
+      let oklab = Lab::new(100.0, 0.0, 0.0).to_oklab();
+
+      assert!((oklab.l() - 1.0).abs() < 1e-3);
+      assert!(oklab.a().abs() < 1e-3);
+      assert!(oklab.b().abs() < 1e-3);
+    }
+
+    #[test]
+    fn it_differs_from_lab_for_a_saturated_color() {
+      let lab = Lab::new(60.0, 40.0, 50.0);
+      let oklab = lab.to_oklab();
+
+      assert!((oklab.a() - lab.a()).abs() > 1.0);
+      assert!((oklab.b() - lab.b()).abs() > 1.0);
+    }
   }
 
   mod to_rgb {
@@ -1535,6 +1926,14 @@ mod test {
 
       assert!(result.is_err());
     }
+
+    #[test]
+    fn it_parses_css_string() {
+      let lab = Lab::try_from("lab(56.7% 43.2 -12.1)").unwrap();
+
+      assert!((lab.l() - 56.7).abs() < 1e-10);
+      assert!((lab.a() - 43.2).abs() < 1e-10);
+    }
   }
 
   mod with_a {
@@ -1793,4 +2192,33 @@ mod test {
       assert!((result.l() - 100.0).abs() < 1e-10);
     }
   }
+
+  mod with_lightness {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_sets_lightness_on_the_0_to_100_scale() {
+      let color = Lab::new(40.0, 20.0, 30.0).with_lightness(100.0);
+
+      assert_eq!(color.l(), 100.0);
+      assert!((color.to_xyz().y() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_clamps_to_the_valid_range() {
+      assert_eq!(Lab::new(40.0, 20.0, 30.0).with_lightness(150.0).l(), 100.0);
+      assert_eq!(Lab::new(40.0, 20.0, 30.0).with_lightness(-10.0).l(), 0.0);
+    }
+
+    #[test]
+    fn it_preserves_the_other_components() {
+      let color = Lab::new(40.0, 20.0, 30.0).with_alpha(0.5).with_lightness(70.0);
+      let [_, second, third] = color.components();
+
+      assert_eq!([second, third], [20.0, 30.0]);
+      assert_eq!(color.alpha(), 0.5);
+    }
+  }
 }
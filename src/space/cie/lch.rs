@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lchuv")]
@@ -36,13 +40,16 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext, Error,
   component::Component,
-  space::{ColorSpace, Lab, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{
+    ColorSpace, HueArc, Lab, Lms, Rgb, RgbSpec, Srgb, Xyz, css,
+    interpolation::{ACHROMATIC_THRESHOLD, max_chroma_in_gamut, mix_hue, mix_hue_with_arc},
+  },
 };
 
-/// Chroma threshold below which a color is considered achromatic (hueless).
-const ACHROMATIC_THRESHOLD: f64 = 1e-4;
+/// Initial upper bound for the maximum in-gamut chroma search; doubled until out of gamut.
+const MAX_CHROMA_SEARCH_START: f64 = 200.0;
 
 /// CIE LCh color space (cylindrical form of CIE L*a*b*).
 ///
@@ -61,9 +68,74 @@ pub struct Lch {
 
 impl Lch {
   /// The default viewing context for Lch (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
+
+  /// Interpolates between two colors the way CSS `color-mix(in lch, ...)` does.
+  ///
+  /// Both colors are adapted with Bradford to the D50 reference white CSS uses for `lch()`.
+  /// L\* and C\* are interpolated linearly with premultiplied alpha, and hue along the shortest
+  /// arc with achromatic (powerless) hues taking the other color's hue. The result is returned in
+  /// the D50 context; `t` is the fraction of `color2` in the mix.
+  ///
+  /// ```
+  /// use farg::space::{Lch, Rgb, Srgb};
+  ///
+  /// let mixed = Lch::color_mix(Rgb::<Srgb>::new(255, 0, 0), Rgb::<Srgb>::new(0, 0, 255), 0.5);
+  /// assert!((mixed.hue() - 351.1).abs() < 0.5);
+  /// ```
+  #[cfg(feature = "illuminant-d50")]
+  pub fn color_mix(color1: impl Into<Xyz>, color2: impl Into<Xyz>, t: f64) -> Self {
+    use crate::space::cie::lab::{CSS_CONTEXT, lab_in_context, lerp_premultiplied};
+
+    let lch1 = lab_in_context(color1.into(), CSS_CONTEXT).to_lch();
+    let lch2 = lab_in_context(color2.into(), CSS_CONTEXT).to_lch();
+    let (alpha1, alpha2) = (lch1.alpha(), lch2.alpha());
+
+    let l = lerp_premultiplied(lch1.l(), alpha1, lch2.l(), alpha2, t);
+    let c = lerp_premultiplied(lch1.c(), alpha1, lch2.c(), alpha2, t);
+    let h = mix_hue(lch1.hue(), lch1.c(), lch2.hue(), lch2.c(), t);
+    let alpha = Component::new(alpha1).lerp(alpha2, t);
+
+    Self::new(l, c, h).with_context(CSS_CONTEXT).with_alpha(alpha)
+  }
+
+  /// Parses a CSS Color Level 4 `lch(...)` string, e.g. `lch(56.7% 43.2 145 / 0.5)`.
+  ///
+  /// L may be a number (0-100) or a percentage, where 100% is L\* 100; because this crate also
+  /// stores L\* on the 0-100 scale, the numeric and percentage forms map directly. C may be a
+  /// number or a percentage, where 100% is 150 per the CSS specification, and H accepts the CSS
+  /// angle units (`deg`, `grad`, `rad`, `turn`). L is clamped to 0-100, negative chroma to 0, and
+  /// `none` resolves to 0. When the `illuminant-d50` feature is enabled the result is placed in
+  /// the D50 viewing context CSS specifies, so conversions to D65 spaces adapt it from D50
+  /// (matching CSS when the default CAT is Bradford); otherwise the values are taken as-is in the
+  /// default viewing context.
+  ///
+  /// ```
+  /// use farg::space::Lch;
+  ///
+  /// let color = Lch::from_css("lch(56.7% 43.2 145)").unwrap();
+  ///
+  /// assert!((color.l() - 56.7).abs() < 1e-10);
+  /// assert!((color.hue() - 145.0).abs() < 1e-10);
+  /// ```
+  pub fn from_css(css: impl Into<String>) -> Result<Self, Error> {
+    let css = css.into();
+    let invalid = || Error::InvalidCssColor {
+      input: css.clone(),
+    };
+    let ([l, c, h], alpha) = css::parse_function(&css, "lch").ok_or_else(invalid)?;
+
+    let l = css::parse_number(l, 100.0).ok_or_else(invalid)?.clamp(0.0, 100.0);
+    let c = css::parse_number(c, 150.0).ok_or_else(invalid)?.max(0.0);
+    let h = css::parse_hue(h).ok_or_else(invalid)?;
+    let alpha = css::parse_alpha(alpha).ok_or_else(invalid)?;
+
+    let color = Self::new(l, c, h).with_alpha(alpha);
+    #[cfg(feature = "illuminant-d50")]
+    let color = color.with_context(crate::space::cie::lab::CSS_CONTEXT);
+
+    Ok(color)
+  }
 
   /// Creates a new Lch color from lightness (0–100), chroma, and hue (0–360°).
   pub fn new(l: impl Into<Component>, c: impl Into<Component>, h: impl Into<Component>) -> Self {
@@ -121,9 +193,11 @@ impl Lch {
     &self.context
   }
 
-  /// Decreases the chroma by the given amount.
+  /// Decreases the chroma by the given amount, then [normalizes](Self::normalized) the color:
+  /// chroma stops at zero and an out-of-range hue is wrapped into 0-360°.
   pub fn decrement_c(&mut self, amount: impl Into<Component>) {
     self.c -= amount.into();
+    *self = self.normalized();
   }
 
   /// Alias for [`Self::decrement_c`].
@@ -164,6 +238,44 @@ impl Lch {
     (0..steps).map(|i| self.mix(other, i as f64 / divisor)).collect()
   }
 
+  /// Generates a sequence of evenly-spaced colors between `self` and `other`, moving hue along
+  /// the given [`HueArc`].
+  ///
+  /// Behaves like [`Self::gradient`], which always takes the shorter arc, but lets the hue take
+  /// the longer way around or move in a fixed direction, as with the CSS Color Level 4
+  /// `longer`, `increasing`, and `decreasing` hue interpolation methods. Achromatic endpoints
+  /// are handled the same way.
+  ///
+  /// ```
+  /// use farg::space::{HueArc, Lch};
+  ///
+  /// let start = Lch::new(70.0, 40.0, 10.0);
+  /// let end = Lch::new(70.0, 40.0, 350.0);
+  /// let rainbow = start.gradient_with_arc(end, 3, HueArc::Longer);
+  ///
+  /// assert!((rainbow[1].hue() - 180.0).abs() < 1e-6);
+  /// ```
+  pub fn gradient_with_arc(&self, other: impl Into<Xyz>, steps: usize, arc: HueArc) -> Vec<Self> {
+    if steps == 0 {
+      return Vec::new();
+    }
+
+    let other = Self::from(other.into());
+    let divisor = steps.saturating_sub(1).max(1) as f64;
+
+    (0..steps)
+      .map(|i| {
+        let t = i as f64 / divisor;
+        let l = Component::new(self.l()).lerp(other.l(), t);
+        let c = Component::new(self.c()).lerp(other.c(), t);
+        let h = mix_hue_with_arc(self.hue(), self.c(), other.hue(), other.c(), t, arc);
+        let alpha = Component::new(self.alpha()).lerp(other.alpha(), t);
+
+        Self::new(l, c, h).with_alpha(alpha)
+      })
+      .collect()
+  }
+
   /// Returns the normalized hue component (0.0–1.0).
   pub fn h(&self) -> f64 {
     self.h.0
@@ -199,11 +311,35 @@ impl Lch {
     self.l += amount.into();
   }
 
+  /// Returns `true` if the chroma is below the achromatic threshold, making the hue powerless.
+  ///
+  /// This is the same test [`Self::mix`] and [`Self::color_mix`] use to decide whether a
+  /// color's hue takes part in interpolation.
+  pub fn is_achromatic(&self) -> bool {
+    self.c.0 < ACHROMATIC_THRESHOLD
+  }
+
   /// Returns the L\* (lightness) component.
   pub fn l(&self) -> f64 {
     self.l.0
   }
 
+  /// Returns the largest chroma at this lightness and hue that still fits the gamut of `S`.
+  ///
+  /// Bisects chroma against [`ColorSpace::is_in_gamut`], so the returned value is always in
+  /// gamut and within floating-point precision of the boundary. Lightness at or beyond black or
+  /// white (100.0) yields 0.0.
+  pub fn max_chroma_for_gamut<S>(&self) -> f64
+  where
+    S: RgbSpec,
+  {
+    if self.l.0 <= 0.0 || self.l.0 >= 100.0 {
+      return 0.0;
+    }
+
+    max_chroma_in_gamut::<3, _, S>(MAX_CHROMA_SEARCH_START, |c| self.with_c(c))
+  }
+
   /// Interpolates between `self` and `other` at parameter `t`, returning a new color.
   ///
   /// When `t` is 0.0 the result matches `self`, when 1.0 it matches `other`.
@@ -234,9 +370,51 @@ impl Lch {
     self.alpha = result.alpha;
   }
 
-  /// Scales the chroma by the given factor.
+  /// Returns a copy with chroma scaled toward gray by `factor` (clamped to 0.0-1.0).
+  ///
+  /// A factor of 1.0 leaves the color unchanged and 0.0 produces the gray of the same
+  /// lightness. Lightness, hue, and alpha are preserved.
+  ///
+  /// ```
+  /// use farg::space::Lch;
+  ///
+  /// let gray = Lch::new(60.0, 40.0, 30.0).muted(0.0);
+  /// assert_eq!(gray.c(), 0.0);
+  /// ```
+  pub fn muted(&self, factor: f64) -> Self {
+    self.with_c(self.c.0 * factor.clamp(0.0, 1.0))
+  }
+
+  /// Returns a copy with chroma clamped to be non-negative and hue wrapped into 0-360°.
+  ///
+  /// Chroma arithmetic can leave a negative value, which has no geometric meaning and produces
+  /// nonsense on conversion. [`decrement_c`](Self::decrement_c) and [`scale_c`](Self::scale_c)
+  /// apply this automatically.
+  ///
+  /// ```
+  /// use farg::space::Lch;
+  ///
+  /// let mut color = Lch::new(50.0, 20.0, 40.0);
+  /// color.set_c(-20.0);
+  /// color.set_h(400.0 / 360.0);
+  ///
+  /// let normalized = color.normalized();
+  /// assert_eq!(normalized.c(), 0.0);
+  /// assert!((normalized.hue() - 40.0).abs() < 1e-10);
+  /// ```
+  pub fn normalized(&self) -> Self {
+    Self {
+      c: Component::new(self.c.0.max(0.0)),
+      h: Component::new(self.h.0.rem_euclid(1.0)),
+      ..*self
+    }
+  }
+
+  /// Scales the chroma by the given factor, then [normalizes](Self::normalized) the color:
+  /// chroma stops at zero for negative factors and an out-of-range hue is wrapped into 0-360°.
   pub fn scale_c(&mut self, factor: impl Into<Component>) {
     self.c *= factor.into();
+    *self = self.normalized();
   }
 
   /// Alias for [`Self::scale_c`].
@@ -294,12 +472,13 @@ impl Lch {
   /// Returns this color as a CSS Color Level 4 `lch(...)` string.
   ///
   /// L is 0-100, C is chroma, H is hue in degrees. Alpha is appended only
-  /// when less than 1.0.
+  /// when less than 1.0. When the `illuminant-d50` feature is enabled the color is first adapted
+  /// to the D50 viewing context CSS specifies, mirroring [`Self::from_css`].
   ///
   /// ```
   /// use farg::space::{ColorSpace, Lch};
   ///
-  /// let color = Lch::new(62.0, 56.0, 14.0);
+  /// let color = Lch::from_css("lch(62 56 14)").unwrap();
   /// assert_eq!(color.to_css(), "lch(62 56 14)");
   /// ```
   pub fn to_css(&self) -> String {
@@ -310,11 +489,20 @@ impl Lch {
         .to_string()
     }
 
-    let a = self.alpha.0;
+    #[cfg(feature = "illuminant-d50")]
+    let color = {
+      use crate::space::cie::lab::{CSS_CONTEXT, lab_in_context};
+
+      lab_in_context(self.to_xyz(), CSS_CONTEXT).to_lch()
+    };
+    #[cfg(not(feature = "illuminant-d50"))]
+    let color = *self;
+
+    let a = color.alpha.0;
     if a < 1.0 {
-      format!("lch({} {} {} / {})", f(self.l()), f(self.c()), f(self.hue()), f(a))
+      format!("lch({} {} {} / {})", f(color.l()), f(color.c()), f(color.hue()), f(a))
     } else {
-      format!("lch({} {} {})", f(self.l()), f(self.c()), f(self.hue()))
+      format!("lch({} {} {})", f(color.l()), f(color.c()), f(color.hue()))
     }
   }
 
@@ -340,6 +528,25 @@ impl Lch {
     self.to_lab().to_xyz()
   }
 
+  /// Returns a copy with chroma raised to the maximum that fits the gamut of `S`.
+  ///
+  /// Lightness, hue, and alpha are preserved. See
+  /// [`max_chroma_for_gamut`](Self::max_chroma_for_gamut).
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Lch, Srgb};
+  ///
+  /// let vivid = Lch::new(60.0, 10.0, 145.0).vivid::<Srgb>();
+  /// assert!(vivid.c() > 10.0);
+  /// assert!(vivid.is_in_gamut::<Srgb>());
+  /// ```
+  pub fn vivid<S>(&self) -> Self
+  where
+    S: RgbSpec,
+  {
+    self.with_c(self.max_chroma_for_gamut::<S>())
+  }
+
   /// Returns a new color with the given C\* value.
   pub fn with_c(&self, c: impl Into<Component>) -> Self {
     Self {
@@ -483,6 +690,25 @@ impl Lch {
     lch.scale_l(factor);
     lch
   }
+
+  /// Returns a new color with the given lightness on the CIE L\* scale (0-100).
+  ///
+  /// L\* is stored on its native 0-100 scale (not normalized to 0-1); unlike
+  /// [`with_l`](Self::with_l), the value is clamped to 0-100 so the result is always a valid
+  /// lightness.
+  ///
+  /// ```
+  /// use farg::space::{Lch, Rgb, Srgb};
+  ///
+  /// let color = Lch::from(Rgb::<Srgb>::new(255, 87, 51).to_xyz()).with_lightness(120.0);
+  /// assert_eq!(color.l(), 100.0);
+  /// ```
+  pub fn with_lightness(&self, lightness: impl Into<Component>) -> Self {
+    Self {
+      l: lightness.into().clamp(0.0, 100.0),
+      ..*self
+    }
+  }
 }
 
 impl<T> Add<T> for Lch
@@ -742,6 +968,13 @@ impl From<Xyz> for Lch {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Lch {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Lch"], Lab::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Lch
 where
   T: Into<Self>,
@@ -795,6 +1028,14 @@ impl TryFrom<&str> for Lch {
   type Error = crate::Error;
 
   fn try_from(value: &str) -> Result<Self, Self::Error> {
+    if value
+      .trim_start()
+      .get(..4)
+      .is_some_and(|prefix| prefix.eq_ignore_ascii_case("lch("))
+    {
+      return Self::from_css(value);
+    }
+
     Ok(Self::from(Rgb::<Srgb>::try_from(value)?.to_xyz()))
   }
 }
@@ -803,37 +1044,8 @@ impl TryFrom<String> for Lch {
   type Error = crate::Error;
 
   fn try_from(value: String) -> Result<Self, Self::Error> {
-    Ok(Self::from(Rgb::<Srgb>::try_from(value)?.to_xyz()))
-  }
-}
-
-/// Interpolates hue along the shortest arc with achromatic handling.
-///
-/// When either color is achromatic (chroma below [`ACHROMATIC_THRESHOLD`]), its hue is
-/// treated as "powerless" and the other color's hue is used. When both are achromatic,
-/// hue is 0. This follows the CSS Color Level 4 specification for hue interpolation.
-fn mix_hue(h1: f64, c1: f64, h2: f64, c2: f64, t: f64) -> f64 {
-  let achromatic1 = c1 < ACHROMATIC_THRESHOLD;
-  let achromatic2 = c2 < ACHROMATIC_THRESHOLD;
-
-  if achromatic1 && achromatic2 {
-    return 0.0;
-  }
-  if achromatic1 {
-    return h2;
-  }
-  if achromatic2 {
-    return h1;
+    Self::try_from(value.as_str())
   }
-
-  let mut diff = h2 - h1;
-  if diff > 180.0 {
-    diff -= 360.0;
-  } else if diff < -180.0 {
-    diff += 360.0;
-  }
-
-  (h1 + diff * t).rem_euclid(360.0)
 }
 
 #[cfg(test)]
@@ -939,6 +1151,41 @@ mod test {
     }
   }
 
+  #[cfg(feature = "illuminant-d50")]
+  mod color_mix {
+    use super::*;
+
+    #[test]
+    fn it_matches_css_color_mix_in_lch_for_red_and_blue() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let blue = Rgb::<Srgb>::new(0, 0, 255);
+      let mixed = Lch::color_mix(red, blue, 0.5);
+
+      assert!((mixed.l() - 41.93).abs() < 0.5);
+      assert!((mixed.c() - 119.02).abs() < 0.5);
+      assert!((mixed.hue() - 351.11).abs() < 0.5);
+    }
+
+    #[test]
+    fn it_uses_chromatic_hue_when_mixing_with_achromatic() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let red_hue = Lch::color_mix(red, red, 0.0).hue();
+      let mixed = Lch::color_mix(red, white, 0.5);
+
+      assert!((mixed.hue() - red_hue).abs() < 0.5);
+    }
+
+    #[test]
+    fn it_takes_the_shortest_hue_arc() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let magenta = Rgb::<Srgb>::new(255, 0, 255);
+      let mixed = Lch::color_mix(red, magenta, 0.5);
+
+      assert!((mixed.hue() > 0.0 && mixed.hue() < 41.0) || mixed.hue() > 320.0);
+    }
+  }
+
   mod components {
     use pretty_assertions::assert_eq;
 
@@ -1050,6 +1297,88 @@ mod test {
     }
   }
 
+  mod from_css {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_numeric_components() {
+      let lch = Lch::from_css("lch(56.7 43.2 145)").unwrap();
+
+      assert!((lch.l() - 56.7).abs() < 1e-10);
+      assert!((lch.c() - 43.2).abs() < 1e-10);
+      assert!((lch.hue() - 145.0).abs() < 1e-10);
+      assert_eq!(lch.alpha(), 1.0);
+    }
+
+    #[test]
+    fn it_parses_percent_lightness_identically_to_numeric() {
+      let percent = Lch::from_css("lch(56.7% 43.2 145)").unwrap();
+      let numeric = Lch::from_css("lch(56.7 43.2 145)").unwrap();
+
+      assert_eq!(percent, numeric);
+    }
+
+    #[test]
+    fn it_scales_percent_chroma() {
+      let lch = Lch::from_css("lch(50 50% 0)").unwrap();
+
+      assert!((lch.c() - 75.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_parses_hue_units_and_alpha() {
+      let lch = Lch::from_css("lch(50 20 0.5turn / 0.25)").unwrap();
+
+      assert!((lch.hue() - 180.0).abs() < 1e-10);
+      assert!((lch.alpha() - 0.25).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_clamps_negative_chroma() {
+      let lch = Lch::from_css("lch(50 -10 30)").unwrap();
+
+      assert_eq!(lch.c(), 0.0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "illuminant-d50", feature = "cat-bradford"))]
+    fn it_converts_to_srgb_like_css() {
+      let rgb = Lch::from_css("lch(50% 50 36.869898)").unwrap().to_rgb::<Srgb>();
+
+      assert!((rgb.r() * 255.0 - 187.24).abs() < 0.05);
+      assert!((rgb.g() * 255.0 - 87.75).abs() < 0.05);
+      assert!((rgb.b() * 255.0 - 70.47).abs() < 0.05);
+    }
+
+    #[cfg(any(not(feature = "illuminant-d50"), feature = "cat-bradford"))]
+    #[test]
+    fn it_roundtrips_through_to_css() {
+      let original = Lch::new(62.5, 43.25, 145.0).with_alpha(0.75);
+      let parsed = Lch::from_css(original.to_css()).unwrap();
+
+      let restored = parsed.to_xyz().adapt_to(*original.context());
+      for (a, b) in restored.components().iter().zip(original.to_xyz().components()) {
+        assert!((a - b).abs() < 1e-6);
+      }
+      assert_eq!(parsed.alpha(), original.alpha());
+      assert_eq!(parsed.to_css(), original.to_css());
+    }
+
+    #[test]
+    fn it_returns_error_for_invalid_input() {
+      for input in ["lab(50 20 30)", "lch(50 20)", "lch(50 20 blue)", ""] {
+        assert_eq!(
+          Lch::from_css(input),
+          Err(Error::InvalidCssColor {
+            input: input.to_string()
+          })
+        );
+      }
+    }
+  }
+
   mod from_lab {
     use super::*;
 
@@ -1173,6 +1502,72 @@ mod test {
     }
   }
 
+  mod gradient_with_arc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_passes_through_180_degrees_for_longer() {
+      let start = Lch::new(70.0, 40.0, 10.0);
+      let end = Lch::new(70.0, 40.0, 350.0);
+      let gradient = start.gradient_with_arc(end, 3, HueArc::Longer);
+
+      assert!((gradient[1].hue() - 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_passes_through_0_degrees_for_shorter() {
+      let start = Lch::new(70.0, 40.0, 10.0);
+      let end = Lch::new(70.0, 40.0, 350.0);
+      let gradient = start.gradient_with_arc(end, 3, HueArc::Shorter);
+      let hue = gradient[1].hue();
+
+      assert!(!(1e-6..=360.0 - 1e-6).contains(&hue));
+    }
+
+    #[test]
+    fn it_matches_gradient_for_shorter() {
+      let start = Lch::new(70.0, 40.0, 40.0);
+      let end = Lch::new(70.0, 40.0, 300.0);
+      let expected = start.gradient(end, 5);
+      let actual = start.gradient_with_arc(end, 5, HueArc::Shorter);
+
+      for (a, b) in actual.iter().zip(&expected) {
+        assert!((a.hue() - b.hue()).abs() < 1e-6);
+      }
+    }
+
+    #[test]
+    fn it_moves_in_a_fixed_direction() {
+      let start = Lch::new(70.0, 40.0, 350.0);
+      let end = Lch::new(70.0, 40.0, 10.0);
+      let increasing = start.gradient_with_arc(end, 3, HueArc::Increasing);
+      let decreasing = start.gradient_with_arc(end, 3, HueArc::Decreasing);
+
+      assert!(increasing[1].hue() < 1e-6 || increasing[1].hue() > 360.0 - 1e-6);
+      assert!((decreasing[1].hue() - 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_includes_both_endpoints() {
+      let start = Lch::new(70.0, 40.0, 10.0);
+      let end = Lch::new(70.0, 40.0, 350.0);
+      let gradient = start.gradient_with_arc(end, 4, HueArc::Longer);
+
+      assert_eq!(gradient.len(), 4);
+      assert!((gradient[0].hue() - 10.0).abs() < 1e-6);
+      assert!((gradient[3].hue() - 350.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_returns_empty_for_zero_steps() {
+      let color = Lch::new(70.0, 40.0, 10.0);
+
+      assert!(color.gradient_with_arc(color, 0, HueArc::Longer).is_empty());
+    }
+  }
+
   mod h {
     use super::*;
 
@@ -1243,6 +1638,20 @@ mod test {
     }
   }
 
+  mod is_achromatic {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_for_grays() {
+      assert!(Lch::new(50.0, 0.0, 120.0).is_achromatic());
+    }
+
+    #[test]
+    fn it_returns_false_for_saturated_colors() {
+      assert!(!Lch::new(50.0, 40.0, 120.0).is_achromatic());
+    }
+  }
+
   mod l {
     use super::*;
 
@@ -1254,6 +1663,34 @@ mod test {
     }
   }
 
+  mod max_chroma_for_gamut {
+    use super::*;
+
+    #[test]
+    fn it_returns_an_in_gamut_chroma_on_the_boundary() {
+      let color = Lch::new(60.0, 0.0, 250.0);
+      let max = color.max_chroma_for_gamut::<Srgb>();
+
+      assert!(max > 0.0);
+      assert!(color.with_c(max).is_in_gamut::<Srgb>());
+      assert!(!color.with_c(max * 1.001).is_in_gamut::<Srgb>());
+    }
+
+    #[test]
+    fn it_returns_zero_for_black_and_white() {
+      assert_eq!(Lch::new(0.0, 0.1, 30.0).max_chroma_for_gamut::<Srgb>(), 0.0);
+      assert_eq!(Lch::new(100.0, 0.1, 30.0).max_chroma_for_gamut::<Srgb>(), 0.0);
+    }
+
+    #[cfg(feature = "rgb-rec-2020")]
+    #[test]
+    fn it_is_larger_for_wider_gamuts() {
+      let color = Lch::new(60.0, 0.0, 150.0);
+
+      assert!(color.max_chroma_for_gamut::<crate::space::Rec2020>() > color.max_chroma_for_gamut::<Srgb>());
+    }
+  }
+
   mod mix {
     use super::*;
 
@@ -1335,68 +1772,50 @@ mod test {
     }
   }
 
-  mod mix_hue_fn {
-    use super::super::mix_hue;
-
-    const EPSILON: f64 = 1e-6;
+  mod mixed_with {
+    use super::*;
 
     #[test]
-    fn shortest_arc_forward() {
-      let h = mix_hue(10.0, 0.1, 50.0, 0.1, 0.5);
-      assert!((h - 30.0).abs() < EPSILON);
+    fn it_mutates_in_place() {
+      let c1 = Lch::new(60.0, 40.0, 30.0);
+      let c2 = Lch::new(40.0, 20.0, 270.0);
+      let expected = c1.mix(c2.to_xyz(), 0.5);
+      let mut color = c1;
+      color.mixed_with(c2.to_xyz(), 0.5);
+      assert!((color.l() - expected.l()).abs() < 1e-10);
+      assert!((color.c() - expected.c()).abs() < 1e-10);
+      assert!((color.h() - expected.h()).abs() < 1e-10);
+      assert!((color.alpha() - expected.alpha()).abs() < 1e-10);
     }
+  }
 
-    #[test]
-    fn shortest_arc_crosses_zero() {
-      let h = mix_hue(350.0, 0.1, 10.0, 0.1, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
+  mod muted {
+    use super::*;
 
     #[test]
-    fn shortest_arc_backward() {
-      let h = mix_hue(10.0, 0.1, 350.0, 0.1, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
+    fn it_produces_gray_at_zero() {
+      let muted = Lch::new(60.0, 40.0, 40.0).muted(0.0);
 
-    #[test]
-    fn both_achromatic() {
-      let h = mix_hue(90.0, 0.0, 270.0, 0.0, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
+      assert_eq!(muted.c(), 0.0);
+      assert!(muted.is_achromatic());
     }
 
     #[test]
-    fn first_achromatic_uses_second() {
-      let h = mix_hue(90.0, 0.0, 200.0, 0.1, 0.5);
-      assert!((h - 200.0).abs() < EPSILON);
-    }
+    fn it_is_unchanged_at_one() {
+      let color = Lch::new(60.0, 40.0, 40.0);
 
-    #[test]
-    fn second_achromatic_uses_first() {
-      let h = mix_hue(90.0, 0.1, 200.0, 0.0, 0.5);
-      assert!((h - 90.0).abs() < EPSILON);
+      assert_eq!(color.muted(1.0), color);
     }
 
     #[test]
-    fn same_hemisphere() {
-      let h = mix_hue(100.0, 0.1, 140.0, 0.1, 0.5);
-      assert!((h - 120.0).abs() < EPSILON);
-    }
-  }
+    fn it_scales_chroma_and_preserves_lightness_and_hue() {
+      let color = Lch::new(60.0, 40.0, 40.0).with_alpha(0.5);
+      let muted = color.muted(0.5);
 
-  mod mixed_with {
-    use super::*;
-
-    #[test]
-    fn it_mutates_in_place() {
-      let c1 = Lch::new(60.0, 40.0, 30.0);
-      let c2 = Lch::new(40.0, 20.0, 270.0);
-      let expected = c1.mix(c2.to_xyz(), 0.5);
-      let mut color = c1;
-      color.mixed_with(c2.to_xyz(), 0.5);
-      assert!((color.l() - expected.l()).abs() < 1e-10);
-      assert!((color.c() - expected.c()).abs() < 1e-10);
-      assert!((color.h() - expected.h()).abs() < 1e-10);
-      assert!((color.alpha() - expected.alpha()).abs() < 1e-10);
+      assert!((muted.c() - 40.0 / 2.0).abs() < 1e-12);
+      assert_eq!(muted.l(), color.l());
+      assert_eq!(muted.hue(), color.hue());
+      assert_eq!(muted.alpha(), 0.5);
     }
   }
 
@@ -1432,6 +1851,59 @@ mod test {
     }
   }
 
+  mod normalized {
+    use super::*;
+
+    #[test]
+    fn it_clamps_negative_chroma_to_zero() {
+      let mut color = Lch::new(50.0, 20.0, 180.0);
+      color.set_c(-0.1);
+
+      assert_eq!(color.normalized().c(), 0.0);
+    }
+
+    #[test]
+    fn it_wraps_hue_into_range() {
+      let mut color = Lch::new(50.0, 20.0, 40.0);
+      color.set_h(400.0 / 360.0);
+
+      assert!(color.hue() > 360.0);
+      assert!((color.normalized().hue() - 40.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_leaves_valid_colors_unchanged() {
+      let color = Lch::new(50.0, 20.0, 120.0);
+
+      assert_eq!(color.normalized(), color);
+    }
+
+    #[test]
+    fn it_is_applied_by_decrement_c() {
+      let mut color = Lch::new(50.0, 20.0, 180.0);
+      color.decrement_c(30.0);
+
+      assert_eq!(color.c(), 0.0);
+    }
+
+    #[test]
+    fn it_is_applied_by_scale_c() {
+      let mut color = Lch::new(50.0, 20.0, 180.0);
+      color.scale_c(-1.0);
+
+      assert_eq!(color.c(), 0.0);
+    }
+
+    #[test]
+    fn it_wraps_hue_when_applied_by_decrement_c() {
+      let mut color = Lch::new(50.0, 20.0, 40.0);
+      color.set_h(400.0 / 360.0);
+      color.decrement_c(5.0);
+
+      assert!((color.hue() - 40.0).abs() < 1e-10);
+    }
+  }
+
   mod partial_eq {
     use super::*;
 
@@ -1493,17 +1965,39 @@ mod test {
 
     use super::*;
 
+    /// Places `color` in the viewing context `to_css` formats in, so its components print as-is.
+    fn in_css_context(color: Lch) -> Lch {
+      #[cfg(feature = "illuminant-d50")]
+      let color = color.with_context(crate::space::cie::lab::CSS_CONTEXT);
+      color
+    }
+
     #[test]
     fn it_outputs_opaque_lch() {
-      let color = Lch::new(62.0, 56.0, 14.0);
+      let color = in_css_context(Lch::new(62.0, 56.0, 14.0));
       assert_eq!(color.to_css(), "lch(62 56 14)");
     }
 
     #[test]
     fn it_outputs_translucent_lch() {
-      let color = Lch::new(62.0, 56.0, 14.0).with_alpha(0.5);
+      let color = in_css_context(Lch::new(62.0, 56.0, 14.0).with_alpha(0.5));
       assert_eq!(color.to_css(), "lch(62 56 14 / 0.5)");
     }
+
+    #[cfg(all(feature = "illuminant-d50", feature = "cat-bradford"))]
+    #[test]
+    fn it_adapts_to_the_css_context() {
+      use pretty_assertions::assert_ne;
+
+      let color = Lch::new(50.0, 50.0, 36.87);
+      let parsed = Lch::from_css(color.to_css()).unwrap();
+
+      assert_ne!(color.to_css(), "lch(50 50 36.87)");
+      let restored = parsed.to_xyz().adapt_to(*color.context());
+      for (a, b) in restored.components().iter().zip(color.to_xyz().components()) {
+        assert!((a - b).abs() < 1e-6);
+      }
+    }
   }
 
   mod to_lab {
@@ -1622,6 +2116,46 @@ mod test {
 
       assert!(result.is_err());
     }
+
+    #[test]
+    fn it_parses_css_string() {
+      let lch = Lch::try_from("lch(56.7% 43.2 145)").unwrap();
+
+      assert!((lch.l() - 56.7).abs() < 1e-10);
+      assert!((lch.hue() - 145.0).abs() < 1e-10);
+    }
+  }
+
+  mod vivid {
+    use super::*;
+
+    #[test]
+    fn it_lands_on_the_gamut_boundary() {
+      let vivid = Lch::new(60.0, 40.0, 140.0).vivid::<Srgb>();
+      let rgb = vivid.to_rgb::<Srgb>();
+
+      assert!(rgb.is_in_gamut());
+      assert!(!vivid.with_c(vivid.c() * 1.001).is_in_gamut::<Srgb>());
+    }
+
+    #[test]
+    fn it_round_trips_without_clamping() {
+      let vivid = Lch::new(60.0, 40.0, 300.0).vivid::<Srgb>();
+      let rgb = vivid.to_rgb::<Srgb>();
+      let back = Lch::from(rgb.to_xyz());
+
+      assert!((back.c() - vivid.c()).abs() < 1e-4);
+      assert!((back.l() - vivid.l()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_preserves_lightness_and_hue() {
+      let color = Lch::new(60.0, 40.0, 200.0);
+      let vivid = color.vivid::<Srgb>();
+
+      assert_eq!(vivid.l(), color.l());
+      assert_eq!(vivid.hue(), color.hue());
+    }
   }
 
   mod with_alpha {
@@ -1835,4 +2369,34 @@ mod test {
       assert!((result.l() - 100.0).abs() < 1e-10);
     }
   }
+
+  mod with_lightness {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_sets_lightness_on_the_0_to_100_scale() {
+      let color = Lch::new(40.0, 20.0, 30.0).with_lightness(100.0);
+
+      assert_eq!(color.l(), 100.0);
+      assert!((color.to_xyz().y() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_clamps_to_the_valid_range() {
+      assert_eq!(Lch::new(40.0, 20.0, 30.0).with_lightness(150.0).l(), 100.0);
+      assert_eq!(Lch::new(40.0, 20.0, 30.0).with_lightness(-10.0).l(), 0.0);
+    }
+
+    #[test]
+    fn it_preserves_the_other_components() {
+      let original = Lch::new(40.0, 20.0, 30.0).with_alpha(0.5);
+      let color = original.with_lightness(70.0);
+
+      assert_eq!(color.c(), original.c());
+      assert_eq!(color.hue(), original.hue());
+      assert_eq!(color.alpha(), 0.5);
+    }
+  }
 }
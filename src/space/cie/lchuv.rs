@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -36,14 +40,11 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
-  space::{ColorSpace, Lms, Luv, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, Lms, Luv, Rgb, RgbSpec, Srgb, Xyz, mix_hue},
 };
 
-/// Chroma threshold below which a color is considered achromatic (hueless).
-const ACHROMATIC_THRESHOLD: f64 = 1e-4;
-
 /// sRGB gamut boundary coefficients for the denominator (XYZ columns 0, 1, 2).
 #[cfg(any(feature = "space-hsluv", feature = "space-hpluv"))]
 const DENOM_COEFFS: [f64; 3] = [0.0, -126_452.0, 632_260.0];
@@ -96,9 +97,7 @@ pub struct Lchuv {
 
 impl Lchuv {
   /// The default viewing context for Lchuv (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new Lchuv color from lightness (0–100), chroma, and hue (0–360°).
   pub fn new(l: impl Into<Component>, c: impl Into<Component>, h: impl Into<Component>) -> Self {
@@ -800,6 +799,13 @@ impl From<Xyz> for Lchuv {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Lchuv {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Lchuv"], Luv::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Lchuv
 where
   T: Into<Self>,
@@ -865,35 +871,6 @@ impl TryFrom<String> for Lchuv {
   }
 }
 
-/// Interpolates hue along the shortest arc with achromatic handling.
-///
-/// When either color is achromatic (chroma below [`ACHROMATIC_THRESHOLD`]), its hue is
-/// treated as "powerless" and the other color's hue is used. When both are achromatic,
-/// hue is 0. This follows the CSS Color Level 4 specification for hue interpolation.
-fn mix_hue(h1: f64, c1: f64, h2: f64, c2: f64, t: f64) -> f64 {
-  let achromatic1 = c1 < ACHROMATIC_THRESHOLD;
-  let achromatic2 = c2 < ACHROMATIC_THRESHOLD;
-
-  if achromatic1 && achromatic2 {
-    return 0.0;
-  }
-  if achromatic1 {
-    return h2;
-  }
-  if achromatic2 {
-    return h1;
-  }
-
-  let mut diff = h2 - h1;
-  if diff > 180.0 {
-    diff -= 360.0;
-  } else if diff < -180.0 {
-    diff += 360.0;
-  }
-
-  (h1 + diff * t).rem_euclid(360.0)
-}
-
 /// Computes the boundary lines of the sRGB gamut at lightness `l` in the CIE LCh(uv) plane.
 ///
 /// Returns 6 `(slope, intercept)` pairs (one per RGB channel boundary × {0, 1}).
@@ -1469,54 +1446,6 @@ mod test {
     }
   }
 
-  mod mix_hue_fn {
-    use super::super::mix_hue;
-
-    const EPSILON: f64 = 1e-6;
-
-    #[test]
-    fn shortest_arc_forward() {
-      let h = mix_hue(10.0, 0.1, 50.0, 0.1, 0.5);
-      assert!((h - 30.0).abs() < EPSILON);
-    }
-
-    #[test]
-    fn shortest_arc_crosses_zero() {
-      let h = mix_hue(350.0, 0.1, 10.0, 0.1, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
-
-    #[test]
-    fn shortest_arc_backward() {
-      let h = mix_hue(10.0, 0.1, 350.0, 0.1, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
-
-    #[test]
-    fn both_achromatic() {
-      let h = mix_hue(90.0, 0.0, 270.0, 0.0, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
-
-    #[test]
-    fn first_achromatic_uses_second() {
-      let h = mix_hue(90.0, 0.0, 200.0, 0.1, 0.5);
-      assert!((h - 200.0).abs() < EPSILON);
-    }
-
-    #[test]
-    fn second_achromatic_uses_first() {
-      let h = mix_hue(90.0, 0.1, 200.0, 0.0, 0.5);
-      assert!((h - 90.0).abs() < EPSILON);
-    }
-
-    #[test]
-    fn same_hemisphere() {
-      let h = mix_hue(100.0, 0.1, 140.0, 0.1, 0.5);
-      assert!((h - 120.0).abs() < EPSILON);
-    }
-  }
-
   mod mixed_with {
     use super::*;
 
@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -38,9 +42,9 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
-  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, Lms, PolarColor, Rgb, RgbSpec, Srgb, Xyz, polar::polar_coordinates},
 };
 
 /// CIE 1976 L\*u\*v\* threshold: (6/29)³.
@@ -66,9 +70,7 @@ pub struct Luv {
 
 impl Luv {
   /// The default viewing context for Luv (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new Luv color with the default viewing context.
   pub fn new(l: impl Into<Component>, u: impl Into<Component>, v: impl Into<Component>) -> Self {
@@ -149,6 +151,31 @@ impl Luv {
     self.l.0
   }
 
+  /// Returns the CIE 1976 u\*v\* saturation, s<sub>uv</sub>.
+  ///
+  /// Computed as 13·√((u′ − u′<sub>n</sub>)² + (v′ − v′<sub>n</sub>)²), the scaled distance of the
+  /// color's UCS chromaticity from that of the context's reference white. Unlike chroma it is
+  /// independent of lightness. Black has no chromaticity and returns 0.0.
+  ///
+  /// ```
+  /// use farg::space::Luv;
+  ///
+  /// let luv = Luv::new(50.0, 30.0, 40.0);
+  /// assert!((luv.saturation() - 1.0).abs() < 1e-9);
+  /// ```
+  pub fn saturation(&self) -> f64 {
+    if self.l.0 <= 0.0 {
+      return 0.0;
+    }
+
+    let [x, y, z] = self.to_xyz().components();
+    let [xn, yn, zn] = self.context.reference_white().components();
+    let du = luv_u_prime(x, y, z) - luv_u_prime(xn, yn, zn);
+    let dv = luv_v_prime(x, y, z) - luv_v_prime(xn, yn, zn);
+
+    13.0 * (du * du + dv * dv).sqrt()
+  }
+
   /// Scales the L\* component by the given factor.
   pub fn scale_l(&mut self, factor: impl Into<Component>) {
     self.l *= factor.into();
@@ -589,6 +616,13 @@ impl From<Xyz> for Luv {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Luv {
+  fn hub_path() -> Vec<&'static str> {
+    vec!["Luv", "Xyz"]
+  }
+}
+
 impl<T> Mul<T> for Luv
 where
   T: Into<Self>,
@@ -610,6 +644,16 @@ where
   }
 }
 
+impl PolarColor for Luv {
+  fn hue_degrees(&self) -> f64 {
+    polar_coordinates(self.u.0, self.v.0).1
+  }
+
+  fn polar_chroma(&self) -> f64 {
+    polar_coordinates(self.u.0, self.v.0).0
+  }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Luv {
   fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -947,6 +991,23 @@ mod test {
     }
   }
 
+  mod hue_degrees {
+    use super::*;
+
+    #[test]
+    fn it_returns_angle_in_degrees() {
+      assert!((Luv::new(50.0, 0.0, 10.0).hue_degrees() - 90.0).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "space-lchuv")]
+    #[test]
+    fn it_agrees_with_lchuv() {
+      let luv = Luv::new(60.0, -25.0, -35.0);
+
+      assert!((luv.hue_degrees() - luv.to_lchuv().hue()).abs() < 1e-10);
+    }
+  }
+
   mod increment_l {
     use super::*;
 
@@ -1032,6 +1093,23 @@ mod test {
     }
   }
 
+  mod polar_chroma {
+    use super::*;
+
+    #[test]
+    fn it_returns_distance_from_neutral_axis() {
+      assert!((Luv::new(50.0, 30.0, 40.0).polar_chroma() - 50.0).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "space-lchuv")]
+    #[test]
+    fn it_agrees_with_lchuv() {
+      let luv = Luv::new(60.0, -25.0, 35.0);
+
+      assert!((luv.polar_chroma() - luv.to_lchuv().chroma()).abs() < 1e-10);
+    }
+  }
+
   mod roundtrip {
     use super::*;
 
@@ -1066,6 +1144,32 @@ mod test {
     }
   }
 
+  mod saturation {
+    use super::*;
+
+    #[test]
+    fn it_returns_zero_for_achromatic_colors() {
+      assert!(Luv::new(50.0, 0.0, 0.0).saturation().abs() < 1e-10);
+      assert!(Luv::new(100.0, 0.0, 0.0).saturation().abs() < 1e-10);
+      assert_eq!(Luv::new(0.0, 20.0, 10.0).saturation(), 0.0);
+    }
+
+    #[test]
+    fn it_equals_chroma_over_lightness() {
+      let luv = Luv::new(60.0, -25.0, 35.0);
+
+      assert!((luv.saturation() - luv.polar_chroma() / luv.l()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_is_independent_of_lightness_for_a_fixed_chromaticity() {
+      let light = Luv::new(80.0, 40.0, 20.0);
+      let dark = Luv::new(40.0, 20.0, 10.0);
+
+      assert!((light.saturation() - dark.saturation()).abs() < 1e-9);
+    }
+  }
+
   mod scale_l {
     use super::*;
 
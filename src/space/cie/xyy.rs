@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -38,7 +42,7 @@ use crate::space::Oklab;
 #[cfg(feature = "space-oklch")]
 use crate::space::Oklch;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
   space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz},
 };
@@ -60,9 +64,7 @@ pub struct Xyy {
 
 impl Xyy {
   /// The default viewing context for xyY (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new xyY color with the default viewing context.
   pub fn new(x: impl Into<Component>, y: impl Into<Component>, big_y: impl Into<Component>) -> Self {
@@ -183,14 +185,13 @@ impl Xyy {
   /// Converts to the CIE XYZ color space.
   pub fn to_xyz(&self) -> Xyz {
     let [x_c, y_c, big_y] = self.components();
+    let ratio = big_y / y_c;
 
-    if y_c == 0.0 {
+    if y_c == 0.0 || big_y == 0.0 || !ratio.is_finite() {
       return Xyz::new(0.0, 0.0, 0.0)
         .with_context(self.context)
         .with_alpha(self.alpha);
     }
-
-    let ratio = big_y / y_c;
     let x = ratio * x_c;
     let z = ratio * (1.0 - x_c - y_c);
 
@@ -560,6 +561,13 @@ impl From<Xyz> for Xyy {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Xyy {
+  fn hub_path() -> Vec<&'static str> {
+    vec!["Xyy", "Xyz"]
+  }
+}
+
 impl<T> Mul<T> for Xyy
 where
   T: Into<Self>,
@@ -892,6 +900,24 @@ mod test {
       assert!((xyy.y() - 0.3290).abs() < 0.001);
     }
 
+    #[test]
+    fn it_maps_black_to_the_reference_white_chromaticity() {
+      let xyy = Xyy::from(Xyz::new(0.0, 0.0, 0.0));
+      let white = ColorimetricContext::default().white_point_xy();
+
+      assert_eq!(xyy.big_y(), 0.0);
+      assert!((xyy.x() - white.x()).abs() < 1e-12);
+      assert!((xyy.y() - white.y()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_produces_finite_chromaticity_for_near_zero_colors() {
+      let xyy = Xyy::from(Xyz::new(1e-320, 1e-320, 0.0));
+
+      assert!(xyy.x().is_finite());
+      assert!(xyy.y().is_finite());
+    }
+
     #[test]
     fn it_preserves_alpha() {
       let xyz = Xyz::new(0.5, 0.5, 0.5).with_alpha(0.3);
@@ -1105,6 +1131,36 @@ mod test {
       assert!(xyz.z().abs() < 1e-10);
     }
 
+    #[test]
+    fn it_returns_black_for_zero_luminance() {
+      let xyz = Xyy::new(0.3127, 0.3290, 0.0).to_xyz();
+
+      assert_eq!(xyz.components(), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn it_returns_black_when_the_ratio_overflows() {
+      let xyz = Xyy::new(0.0, 1e-320, 1.0).to_xyz();
+
+      assert_eq!(xyz.components(), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn it_round_trips_black() {
+      let xyy = Xyz::new(0.0, 0.0, 0.0).to_xyy();
+
+      assert_eq!(xyy.to_xyz().components(), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn it_round_trips_near_zero_colors_without_nan() {
+      let original = Xyz::new(1e-300, 2e-300, 3e-300);
+      let xyz = original.to_xyy().to_xyz();
+
+      assert!(xyz.components().iter().all(|c| c.is_finite()));
+      assert!((xyz.y() - original.y()).abs() < 1e-310);
+    }
+
     #[test]
     fn it_preserves_alpha() {
       let xyy = Xyy::new(0.3127, 0.3290, 0.5).with_alpha(0.3);
@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -44,8 +48,26 @@ use crate::{
   chromaticity::Xy,
   component::Component,
   space::{ColorSpace, LinearRgb, Lms, Rgb, RgbSpec, Srgb},
+  spectral::{Spd, Table},
 };
 
+/// Lower bound (K) of the Planckian locus approximation used by [`Xyz::shift_temperature`].
+#[cfg(feature = "cct-ohno")]
+const PLANCKIAN_MIN_CCT: f64 = 1667.0;
+
+/// Upper bound (K) of the Planckian locus approximation used by [`Xyz::shift_temperature`].
+#[cfg(feature = "cct-ohno")]
+const PLANCKIAN_MAX_CCT: f64 = 25000.0;
+
+/// First wavelength (nm) of the reflectance produced by [`Xyz::to_reflectance_spd`].
+const REFLECTANCE_START_NM: u32 = 380;
+
+/// Last wavelength (nm) of the reflectance produced by [`Xyz::to_reflectance_spd`].
+const REFLECTANCE_END_NM: u32 = 780;
+
+/// Sampling interval (nm) of the reflectance produced by [`Xyz::to_reflectance_spd`].
+const REFLECTANCE_STEP_NM: u32 = 5;
+
 /// CIE 1931 XYZ tristimulus color space.
 ///
 /// The device-independent reference space through which all conversions flow.
@@ -60,6 +82,23 @@ pub struct Xyz {
 }
 
 impl Xyz {
+  /// Creates an XYZ color with the given chromaticity from an absolute luminance in cd/m².
+  ///
+  /// Y is set relative to `peak_nits`, the luminance of the context's reference white on the
+  /// target display, so this inverts [`Self::absolute_luminance`].
+  ///
+  /// ```
+  /// use farg::{chromaticity::Xy, space::Xyz};
+  ///
+  /// let color = Xyz::from_absolute_luminance(Xy::new(0.3127, 0.329), 203.0, 1000.0);
+  ///
+  /// assert!((color.y() - 0.203).abs() < 1e-12);
+  /// assert!((color.absolute_luminance(1000.0) - 203.0).abs() < 1e-9);
+  /// ```
+  pub fn from_absolute_luminance(chromaticity: Xy, nits: f64, peak_nits: f64) -> Self {
+    chromaticity.to_xyz(nits / peak_nits)
+  }
+
   /// Creates a new XYZ color with the default viewing context.
   pub fn new(x: impl Into<Component>, y: impl Into<Component>, z: impl Into<Component>) -> Self {
     Self {
@@ -82,6 +121,22 @@ impl Xyz {
     }
   }
 
+  /// Returns a copy with the given alpha in a const context.
+  pub(crate) const fn with_alpha_const(self, alpha: f64) -> Self {
+    Self {
+      alpha: Component::new_const(alpha),
+      ..self
+    }
+  }
+
+  /// Returns the absolute luminance in cd/m² when relative Y = 1.0 is displayed at `peak_nits`.
+  ///
+  /// Bridges relative colorimetry, where Y is normalized to the reference white, and display
+  /// luminance for HDR workflows.
+  pub fn absolute_luminance(&self, peak_nits: f64) -> f64 {
+    self.y.0 * peak_nits
+  }
+
   /// Adapts this color to a different viewing context using chromatic adaptation.
   pub fn adapt_to(&self, context: ColorimetricContext) -> Self {
     let reference_white = self.context.reference_white();
@@ -127,6 +182,30 @@ impl Xyz {
     xyz
   }
 
+  /// Returns the correlated color temperature in Kelvin and the signed Duv.
+  ///
+  /// Uses Ohno's method, searching the Planckian locus in CIE 1960 UCS (u, v). Duv is the
+  /// distance from the locus, positive above it (greenish) and negative below (pinkish).
+  /// Returns `None` for black, invalid (see [`is_valid`](Self::is_valid)), or otherwise
+  /// undefined chromaticities.
+  ///
+  /// ```
+  /// use farg::space::Xyz;
+  ///
+  /// let (cct, duv) = Xyz::new(0.95047, 1.0, 1.08883).cct_duv().unwrap();
+  /// assert!((cct - 6504.0).abs() < 50.0);
+  /// assert!(duv.abs() < 0.005);
+  /// ```
+  #[cfg(feature = "cct-ohno")]
+  pub fn cct_duv(&self) -> Option<(f64, f64)> {
+    if !self.is_valid() || self.y.0 == 0.0 {
+      return None;
+    }
+
+    let (cct, duv) = crate::correlated_color_temperature::ohno::calculate_with_duv(*self);
+    (cct.value().is_finite() && duv.is_finite()).then_some((cct.value(), duv))
+  }
+
   /// Returns the CIE 1931 xy chromaticity coordinates.
   pub fn chromaticity(&self) -> Xy {
     let [x, y, z] = self.components();
@@ -226,11 +305,48 @@ impl Xyz {
     }
   }
 
+  /// Returns `true` if every component is finite and the luminance (Y) is non-negative.
+  ///
+  /// Conversions of out-of-gamut or degenerate inputs can produce NaN, infinite, or negative
+  /// values; use this to guard downstream math, or [`sanitized`](Self::sanitized) to repair them.
+  pub fn is_valid(&self) -> bool {
+    self.components().iter().all(|c| c.is_finite()) && self.y.0 >= 0.0
+  }
+
   /// Returns the relative luminance (Y component).
   pub fn luminance(&self) -> f64 {
     self.y()
   }
 
+  /// Interpolates between `self` and `other` at parameter `t` linearly in XYZ.
+  ///
+  /// Because XYZ is linear in light, this models additive mixing of two light sources rather
+  /// than the perceptual mix other spaces provide. It is the same interpolation as
+  /// [`mix_linear`](ColorSpace::mix_linear); see there for details.
+  ///
+  /// Accepts any color type that can be converted to [`Xyz`].
+  pub fn mix(&self, other: impl Into<Xyz>, t: f64) -> Self {
+    self.mix_linear(other, t)
+  }
+
+  /// Interpolates `self` toward `other` at parameter `t` linearly in XYZ, mutating in place.
+  ///
+  /// See [`mix`](Self::mix) for details on the interpolation behavior.
+  pub fn mixed_with(&mut self, other: impl Into<Xyz>, t: f64) {
+    *self = self.mix(other, t);
+  }
+
+  /// Returns a copy of this color with non-finite (NaN or infinite) and negative components
+  /// replaced by 0.0.
+  ///
+  /// Finite non-negative components are preserved as-is, as are alpha and context.
+  pub fn sanitized(&self) -> Self {
+    let [x, y, z] = self
+      .components()
+      .map(|c| if !c.is_finite() || c < 0.0 { 0.0 } else { c });
+    Self::new(x, y, z).with_alpha(self.alpha).with_context(self.context)
+  }
+
   /// Scales luminance by the given factor while proportionally scaling X and Z.
   pub fn scale_luminance(&mut self, factor: impl Into<Component>) {
     self.amplify(factor)
@@ -273,6 +389,53 @@ impl Xyz {
     self.z = z.into();
   }
 
+  /// Returns this color adapted as if the scene illuminant moved along the Planckian locus.
+  ///
+  /// The correlated color temperature of the context's reference white is estimated with
+  /// Ohno's method, and the color is adapted with the context's CAT from the blackbody at that
+  /// temperature to the blackbody at `temperature - delta_kelvin`. Positive deltas warm the
+  /// color, negative deltas cool it. The target temperature is clamped to the 1,667–25,000 K
+  /// range of the locus approximation, and luminance (Y), alpha, and context are preserved.
+  ///
+  /// ```
+  /// use farg::space::Xyz;
+  ///
+  /// let color = Xyz::new(0.4, 0.4, 0.4);
+  /// let warmer = color.shift_temperature(1000.0);
+  ///
+  /// assert!(warmer.x() / warmer.z() > color.x() / color.z());
+  /// assert!((warmer.y() - color.y()).abs() < 1e-10);
+  /// ```
+  #[cfg(feature = "cct-ohno")]
+  pub fn shift_temperature(&self, delta_kelvin: f64) -> Self {
+    use crate::correlated_color_temperature::ohno;
+
+    if delta_kelvin == 0.0 || self.y.0 == 0.0 {
+      return *self;
+    }
+
+    let source_cct = ohno::calculate(self.context.reference_white())
+      .value()
+      .clamp(PLANCKIAN_MIN_CCT, PLANCKIAN_MAX_CCT);
+    let target_cct = (source_cct - delta_kelvin).clamp(PLANCKIAN_MIN_CCT, PLANCKIAN_MAX_CCT);
+    let source_white = ohno::planckian_locus_xy(source_cct).to_xyz(1.0);
+    let target_white = ohno::planckian_locus_xy(target_cct).to_xyz(1.0);
+
+    let adapted = self.context.cat().adapt(*self, source_white, target_white);
+    let scale = if adapted.y.0 == 0.0 {
+      0.0
+    } else {
+      self.y.0 / adapted.y.0
+    };
+
+    Self {
+      x: Component::new(adapted.x.0 * scale),
+      y: self.y,
+      z: Component::new(adapted.z.0 * scale),
+      ..*self
+    }
+  }
+
   /// Returns this color as a CSS Color Level 4 `color(xyz-d65 ...)` string.
   ///
   /// If the color's illuminant is not D65, it is chromatically adapted to D65
@@ -367,6 +530,64 @@ impl Xyz {
     Oklab::new(l, a, b).with_alpha(self.alpha)
   }
 
+  /// Reconstructs a smooth reflectance spectrum that reproduces this color under its context.
+  ///
+  /// Uses Scott Burns' least hyperbolic tangent slope squared (LHTSS) method: the reflectance
+  /// is expressed as `(tanh(z) + 1) / 2`, which keeps every sample within 0.0-1.0, and the
+  /// smoothest such curve matching the target tristimulus values is found by Newton iteration.
+  /// The result is sampled every 5 nm from 380 nm to 780 nm and, integrated against the
+  /// context's illuminant and observer (normalized so a perfect reflector has Y = 1), returns
+  /// the original XYZ.
+  ///
+  /// Colors outside the object-color solid (for example Y ≥ 1, or chromaticities beyond what a
+  /// reflective surface can produce) have no exact solution; the closest reachable reflectance
+  /// is returned, or a flat spectrum at the color's luminance if the iteration breaks down.
+  /// Black yields an all-zero spectrum.
+  ///
+  /// ```
+  /// use farg::{SpectralTable, space::Xyz};
+  ///
+  /// let reflectance = Xyz::new(0.2, 0.2, 0.2).to_reflectance_spd();
+  ///
+  /// assert!(reflectance.values().all(|&r| (0.0..=1.0).contains(&r)));
+  /// ```
+  pub fn to_reflectance_spd(&self) -> Spd {
+    let wavelengths: Vec<u32> = (REFLECTANCE_START_NM..=REFLECTANCE_END_NM)
+      .step_by(REFLECTANCE_STEP_NM as usize)
+      .collect();
+    let flat = |value: f64| {
+      let value = if value.is_finite() { value.clamp(0.0, 1.0) } else { 0.0 };
+      Spd::from_fn(REFLECTANCE_START_NM, REFLECTANCE_END_NM, REFLECTANCE_STEP_NM, |_| value)
+    };
+
+    let target = self.components();
+    if target.iter().any(|c| !c.is_finite()) || target[1] <= 0.0 {
+      return flat(0.0);
+    }
+
+    let illuminant = self.context.illuminant().spd();
+    let cmf = self.context.observer().cmf();
+    let mut weights: Vec<[f64; 3]> = wavelengths
+      .iter()
+      .map(|&wavelength| {
+        let power = illuminant.power_at(wavelength as f64).unwrap_or(0.0);
+        let response = cmf.at(wavelength).map(|r| r.components()).unwrap_or([0.0; 3]);
+        response.map(|c| c * power)
+      })
+      .collect();
+
+    let white_y: f64 = weights.iter().map(|w| w[1]).sum();
+    if white_y <= 0.0 {
+      return flat(target[1]);
+    }
+    weights.iter_mut().for_each(|w| *w = w.map(|c| c / white_y));
+
+    match lhtss(&weights, target) {
+      Some(reflectance) => wavelengths.into_iter().zip(reflectance).collect::<Vec<_>>().into(),
+      None => flat(target[1]),
+    }
+  }
+
   /// Converts to the specified RGB color space.
   pub fn to_rgb<S>(&self) -> Rgb<S>
   where
@@ -380,22 +601,21 @@ impl Xyz {
   }
 
   /// Converts to the CIE xyY color space.
+  ///
+  /// Chromaticity is undefined when X + Y + Z is zero (or so small the ratios overflow), so
+  /// black takes the chromaticity of the context's reference white with Y = 0, keeping the
+  /// result finite and convertible back to (0, 0, 0).
   #[cfg(feature = "space-xyy")]
   pub fn to_xyy(&self) -> Xyy {
     let [x, y, z] = self.components();
     let sum = x + y + z;
+    let (x_chrom, y_chrom) = (x / sum, y / sum);
 
-    let (x_chrom, y_chrom) = if sum == 0.0 {
-      // For black, use the reference white chromaticity
-      let [xn, yn, zn] = self.context.reference_white().components();
-      let sum_n = xn + yn + zn;
-      if sum_n == 0.0 {
-        (0.0, 0.0)
-      } else {
-        (xn / sum_n, yn / sum_n)
-      }
+    let (x_chrom, y_chrom) = if sum != 0.0 && x_chrom.is_finite() && y_chrom.is_finite() {
+      (x_chrom, y_chrom)
     } else {
-      (x / sum, y / sum)
+      let white = self.context.white_point_xy();
+      (white.x(), white.y())
     };
 
     Xyy::new(x_chrom, y_chrom, y)
@@ -556,6 +776,10 @@ impl Xyz {
   }
 }
 
+/// Adds two lights component-wise, as light mixes additively in XYZ.
+///
+/// The right-hand side is first adapted to this color's viewing context. Alpha and context
+/// are taken from the left-hand side.
 impl<T> Add<T> for Xyz
 where
   T: Into<Self>,
@@ -563,7 +787,14 @@ where
   type Output = Self;
 
   fn add(self, rhs: T) -> Self::Output {
-    Self::from(self.to_rgb::<Srgb>() + rhs.into().to_rgb::<Srgb>())
+    let rhs = rhs.into().adapt_to(self.context);
+
+    Self {
+      x: self.x + rhs.x,
+      y: self.y + rhs.y,
+      z: self.z + rhs.z,
+      ..self
+    }
   }
 }
 
@@ -576,6 +807,44 @@ impl ColorSpace<3> for Xyz {
     self.components()
   }
 
+  /// Interpolates between `self` and `other` at parameter `t` linearly in XYZ, returning a new
+  /// color.
+  ///
+  /// Because XYZ is linear in light, this models additive mixing of two light sources: the
+  /// chromaticity of the result lies on the straight line between the two chromaticities in xy,
+  /// weighted toward the brighter light. `other` is first adapted into this color's viewing
+  /// context. X, Y, Z, and alpha are interpolated linearly; values of `t` outside 0.0-1.0
+  /// extrapolate beyond the endpoints. This overrides the provided [`ColorSpace::mix_linear`], so
+  /// no round trip through linear sRGB is made, including from generic code. [`Xyz::mix`] is an
+  /// alias for this method.
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Xyz};
+  ///
+  /// let a = Xyz::new(0.2, 0.1, 0.0);
+  /// let b = Xyz::new(0.0, 0.3, 0.6);
+  ///
+  /// let mixed = a.mix_linear(b, 0.5);
+  ///
+  /// assert!((mixed.y() - 0.2).abs() < 1e-12);
+  /// ```
+  fn mix_linear(&self, other: impl Into<Xyz>, t: f64) -> Self {
+    let other = other.into().adapt_to(self.context);
+
+    let x = self.x.lerp(other.x, t);
+    let y = self.y.lerp(other.y, t);
+    let z = self.z.lerp(other.z, t);
+    let alpha = self.alpha.lerp(other.alpha, t);
+
+    Self {
+      alpha,
+      x,
+      y,
+      z,
+      ..*self
+    }
+  }
+
   fn set_alpha(&mut self, alpha: impl Into<Component>) {
     self.alpha = alpha.into().clamp(0.0, 1.0)
   }
@@ -645,6 +914,20 @@ where
   }
 }
 
+/// Divides each component by a scalar, dimming a light.
+impl Div<f64> for Xyz {
+  type Output = Self;
+
+  fn div(self, rhs: f64) -> Self::Output {
+    Self {
+      x: self.x / rhs,
+      y: self.y / rhs,
+      z: self.z / rhs,
+      ..self
+    }
+  }
+}
+
 impl<T> From<[T; 3]> for Xyz
 where
   T: Into<Component>,
@@ -813,6 +1096,13 @@ impl From<Xyy> for Xyz {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Xyz {
+  fn hub_path() -> Vec<&'static str> {
+    vec!["Xyz"]
+  }
+}
+
 impl<T> Mul<T> for Xyz
 where
   T: Into<Self>,
@@ -824,6 +1114,20 @@ where
   }
 }
 
+/// Multiplies each component by a scalar, scaling a light's intensity.
+impl Mul<f64> for Xyz {
+  type Output = Self;
+
+  fn mul(self, rhs: f64) -> Self::Output {
+    Self {
+      x: self.x * rhs,
+      y: self.y * rhs,
+      z: self.z * rhs,
+      ..self
+    }
+  }
+}
+
 impl<T> PartialEq<T> for Xyz
 where
   T: Into<Xyz> + Copy,
@@ -851,6 +1155,10 @@ impl serde::Serialize for Xyz {
   }
 }
 
+/// Subtracts one light from another component-wise, clamping each component at zero.
+///
+/// The right-hand side is first adapted to this color's viewing context. Alpha and context
+/// are taken from the left-hand side.
 impl<T> Sub<T> for Xyz
 where
   T: Into<Self>,
@@ -858,7 +1166,14 @@ where
   type Output = Self;
 
   fn sub(self, rhs: T) -> Self::Output {
-    Self::from(self.to_rgb::<Srgb>() - rhs.into().to_rgb::<Srgb>())
+    let rhs = rhs.into().adapt_to(self.context);
+
+    Self {
+      x: Component::new((self.x.0 - rhs.x.0).max(0.0)),
+      y: Component::new((self.y.0 - rhs.y.0).max(0.0)),
+      z: Component::new((self.z.0 - rhs.z.0).max(0.0)),
+      ..self
+    }
   }
 }
 
@@ -878,10 +1193,126 @@ impl TryFrom<String> for Xyz {
   }
 }
 
+/// Solves for the smoothest reflectance `(tanh(z) + 1) / 2` whose weighted sums match `target`.
+///
+/// Newton's method is applied to the Lagrangian of the LHTSS problem (minimize the squared
+/// differences between adjacent `z` subject to `weights^T · reflectance = target`). Returns `None`
+/// if the linear system becomes singular or the result is not finite.
+fn lhtss(weights: &[[f64; 3]], target: [f64; 3]) -> Option<Vec<f64>> {
+  const MAX_ITERATIONS: usize = 100;
+  const TOLERANCE: f64 = 1e-10;
+
+  let n = weights.len();
+  let size = n + 3;
+  let mut z = vec![0.0; n];
+  let mut lambda = [0.0; 3];
+
+  for _ in 0..MAX_ITERATIONS {
+    let tanh: Vec<f64> = z.iter().map(|v: &f64| v.tanh()).collect();
+    let reflectance: Vec<f64> = tanh.iter().map(|t| (t + 1.0) / 2.0).collect();
+    let first: Vec<f64> = tanh.iter().map(|t| (1.0 - t * t) / 2.0).collect();
+    let second: Vec<f64> = tanh.iter().map(|t| -(1.0 - t * t) * t).collect();
+
+    let mut jacobian = vec![vec![0.0; size]; size];
+    let mut residual = vec![0.0; size];
+
+    for i in 0..n {
+      let diagonal = if i == 0 || i == n - 1 { 1.0 } else { 2.0 };
+      let projected: f64 = (0..3).map(|k| weights[i][k] * lambda[k]).sum();
+
+      residual[i] = diagonal * z[i] + first[i] * projected;
+      jacobian[i][i] = diagonal + second[i] * projected;
+      if i > 0 {
+        residual[i] -= z[i - 1];
+        jacobian[i][i - 1] = -1.0;
+      }
+      if i < n - 1 {
+        residual[i] -= z[i + 1];
+        jacobian[i][i + 1] = -1.0;
+      }
+
+      for k in 0..3 {
+        jacobian[i][n + k] = first[i] * weights[i][k];
+        jacobian[n + k][i] = weights[i][k] * first[i];
+      }
+    }
+
+    for k in 0..3 {
+      residual[n + k] = (0..n).map(|i| weights[i][k] * reflectance[i]).sum::<f64>() - target[k];
+    }
+
+    if residual.iter().all(|r| r.abs() < TOLERANCE) {
+      return Some(reflectance);
+    }
+
+    let delta = solve_linear_system(jacobian, residual.iter().map(|r| -r).collect())?;
+    z.iter_mut().zip(&delta).for_each(|(v, d)| *v += d);
+    lambda.iter_mut().zip(&delta[n..]).for_each(|(v, d)| *v += d);
+
+    if z.iter().any(|v| !v.is_finite()) {
+      return None;
+    }
+  }
+
+  Some(z.iter().map(|v| (v.tanh() + 1.0) / 2.0).collect())
+}
+
+/// Solves `a · x = b` by Gaussian elimination with partial pivoting, or `None` if `a` is singular.
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+  let n = b.len();
+
+  for column in 0..n {
+    let pivot = (column..n).max_by(|&i, &j| a[i][column].abs().total_cmp(&a[j][column].abs()))?;
+    if a[pivot][column].abs() < 1e-300 {
+      return None;
+    }
+    a.swap(column, pivot);
+    b.swap(column, pivot);
+
+    let (upper, lower) = a.split_at_mut(column + 1);
+    let pivot_row = &upper[column];
+    for (offset, row) in lower.iter_mut().enumerate() {
+      let factor = row[column] / pivot_row[column];
+      if factor == 0.0 {
+        continue;
+      }
+      row[column..]
+        .iter_mut()
+        .zip(&pivot_row[column..])
+        .for_each(|(v, p)| *v -= factor * p);
+      b[column + 1 + offset] -= factor * b[column];
+    }
+  }
+
+  let mut x = vec![0.0; n];
+  for row in (0..n).rev() {
+    let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+    x[row] = (b[row] - sum) / a[row][row];
+  }
+
+  Some(x)
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
 
+  mod absolute_luminance {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_peak_for_unit_luminance() {
+      assert_eq!(Xyz::new(0.95047, 1.0, 1.08883).absolute_luminance(100.0), 100.0);
+    }
+
+    #[test]
+    fn it_scales_relative_luminance_by_peak() {
+      assert!((Xyz::new(0.2, 0.18, 0.3).absolute_luminance(1000.0) - 180.0).abs() < 1e-10);
+    }
+  }
+
   mod adapt_to {
     use super::*;
     use crate::{Illuminant, illuminant::IlluminantType, spectral::Spd};
@@ -969,6 +1400,52 @@ mod test {
     }
   }
 
+  mod add {
+    use super::*;
+
+    #[test]
+    fn it_sums_two_half_luminance_whites_to_full_white() {
+      let white = Xyz::new(0.95047, 1.0, 1.08883);
+      let half = white * 0.5;
+      let result = half + half;
+
+      assert!((result.x() - white.x()).abs() < 1e-12);
+      assert!((result.y() - white.y()).abs() < 1e-12);
+      assert!((result.z() - white.z()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_does_not_clamp_to_srgb() {
+      let result = Xyz::new(0.9, 0.9, 0.9) + Xyz::new(0.9, 0.9, 0.9);
+
+      assert!((result.y() - 1.8).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_preserves_lhs_alpha() {
+      let result = Xyz::new(0.1, 0.1, 0.1).with_alpha(0.4) + Xyz::new(0.2, 0.2, 0.2);
+
+      assert!((result.alpha() - 0.4).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "illuminant-d50")]
+    #[test]
+    fn it_adapts_rhs_to_lhs_context() {
+      use crate::Illuminant;
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let lhs = Xyz::new(0.0, 0.0, 0.0);
+      let rhs = Xyz::new(0.3, 0.4, 0.5).with_context(d50);
+      let expected = rhs.adapt_to(*lhs.context());
+      let result = lhs + rhs;
+
+      assert!((result.x() - expected.x()).abs() < 1e-12);
+      assert!((result.y() - expected.y()).abs() < 1e-12);
+      assert!((result.z() - expected.z()).abs() < 1e-12);
+      assert_eq!(result.context().reference_white(), lhs.context().reference_white());
+    }
+  }
+
   mod amplified_by {
     use pretty_assertions::assert_eq;
 
@@ -1011,6 +1488,34 @@ mod test {
     }
   }
 
+  mod approx_eq {
+    use super::*;
+
+    #[test]
+    fn it_is_true_within_epsilon() {
+      let a = Xyz::new(0.4, 0.5, 0.6);
+      let b = Xyz::new(0.4 + 1e-12, 0.5, 0.6 - 1e-12);
+
+      assert!(a.approx_eq(b, 1e-9));
+    }
+
+    #[test]
+    fn it_is_false_outside_epsilon() {
+      let a = Xyz::new(0.4, 0.5, 0.6);
+      let b = Xyz::new(0.4 + 1e-12, 0.5, 0.6);
+
+      assert!(!a.approx_eq(b, 1e-15));
+    }
+
+    #[test]
+    fn it_compares_alpha() {
+      let a = Xyz::new(0.4, 0.5, 0.6);
+      let b = a.with_alpha(0.5);
+
+      assert!(!a.approx_eq(b, 1e-9));
+    }
+  }
+
   mod attenuate {
     use pretty_assertions::assert_eq;
 
@@ -1054,6 +1559,45 @@ mod test {
     }
   }
 
+  #[cfg(feature = "cct-ohno")]
+  mod cct_duv {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_5000k_and_near_zero_duv_for_blackbody_point() {
+      let (cct, duv) = Xy::new(0.3451, 0.3516).to_xyz(1.0).cct_duv().unwrap();
+
+      assert!((cct - 5000.0).abs() < 10.0);
+      assert!(duv.abs() < 1e-3);
+    }
+
+    #[test]
+    fn it_returns_positive_duv_for_greenish_point() {
+      let (_, duv) = Xy::new(0.33, 0.40).to_xyz(1.0).cct_duv().unwrap();
+
+      assert!(duv > 0.0);
+    }
+
+    #[test]
+    fn it_returns_negative_duv_for_pinkish_point() {
+      let (_, duv) = Xy::new(0.33, 0.30).to_xyz(1.0).cct_duv().unwrap();
+
+      assert!(duv < 0.0);
+    }
+
+    #[test]
+    fn it_returns_none_for_black() {
+      assert_eq!(Xyz::new(0.0, 0.0, 0.0).cct_duv(), None);
+    }
+
+    #[test]
+    fn it_returns_none_for_nan() {
+      assert_eq!(Xyz::new(f64::NAN, 1.0, 1.0).cct_duv(), None);
+    }
+  }
+
   mod chromaticity {
     use pretty_assertions::assert_eq;
 
@@ -1190,6 +1734,41 @@ mod test {
     }
   }
 
+  mod div {
+    use super::*;
+
+    #[test]
+    fn it_divides_each_component_by_scalar() {
+      let result = Xyz::new(0.4, 0.8, 0.2) / 2.0;
+
+      assert!((result.x() - 0.2).abs() < 1e-12);
+      assert!((result.y() - 0.4).abs() < 1e-12);
+      assert!((result.z() - 0.1).abs() < 1e-12);
+    }
+  }
+
+  mod from_absolute_luminance {
+    use super::*;
+
+    #[test]
+    fn it_inverts_absolute_luminance() {
+      let chromaticity = Xy::new(0.3127, 0.329);
+      let color = Xyz::from_absolute_luminance(chromaticity, 250.0, 1000.0);
+
+      assert!((color.absolute_luminance(1000.0) - 250.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_preserves_chromaticity() {
+      let chromaticity = Xy::new(0.35, 0.4);
+      let color = Xyz::from_absolute_luminance(chromaticity, 80.0, 100.0);
+
+      assert!((color.chromaticity().x() - 0.35).abs() < 1e-12);
+      assert!((color.chromaticity().y() - 0.4).abs() < 1e-12);
+      assert!((color.y() - 0.8).abs() < 1e-12);
+    }
+  }
+
   mod increment_luminance {
     use pretty_assertions::assert_eq;
 
@@ -1311,6 +1890,182 @@ mod test {
     }
   }
 
+  mod is_valid {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_for_d65_white_point() {
+      let xyz = Xyz::new(0.95047, 1.0, 1.08883);
+
+      assert!(xyz.is_valid());
+    }
+
+    #[test]
+    fn it_returns_true_for_negative_x_or_z_with_non_negative_y() {
+      let xyz = Xyz::new(-0.01, 0.5, -0.02);
+
+      assert!(xyz.is_valid());
+    }
+
+    #[test]
+    fn it_returns_false_for_nan() {
+      let xyz = Xyz::new(0.5, f64::NAN, 0.5);
+
+      assert!(!xyz.is_valid());
+    }
+
+    #[test]
+    fn it_returns_false_for_infinity() {
+      let xyz = Xyz::new(f64::INFINITY, 0.5, 0.5);
+
+      assert!(!xyz.is_valid());
+    }
+
+    #[test]
+    fn it_returns_false_for_negative_luminance() {
+      let xyz = Xyz::new(0.5, -0.001, 0.5);
+
+      assert!(!xyz.is_valid());
+    }
+  }
+
+  mod mix {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_the_endpoints_at_zero_and_one() {
+      let a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1).with_alpha(0.5);
+
+      assert_eq!(a.mix(b, 0.0).components(), a.components());
+      for (actual, expected) in a.mix(b, 1.0).components().iter().zip(b.components()) {
+        assert!((actual - expected).abs() < 1e-15);
+      }
+      assert_eq!(a.mix(b, 1.0).alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_interpolates_linearly_in_xyz() {
+      let a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1);
+
+      assert_eq!(a.mix(b, 0.5).components(), a.mix_linear(b, 0.5).components());
+      assert!((a.mix(b, 0.5).y() - 0.4).abs() < 1e-15);
+    }
+  }
+
+  mod mixed_with {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_mixes_in_place() {
+      let mut a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1);
+      let expected = a.mix(b, 0.25);
+
+      a.mixed_with(b, 0.25);
+
+      assert_eq!(a.components(), expected.components());
+    }
+  }
+
+  mod mix_linear {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Observer;
+
+    #[test]
+    fn it_returns_the_endpoints_at_zero_and_one() {
+      let a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1).with_alpha(0.5);
+
+      assert_eq!(a.mix_linear(b, 0.0).components(), a.components());
+      for (actual, expected) in a.mix_linear(b, 1.0).components().iter().zip(b.components()) {
+        assert!((actual - expected).abs() < 1e-15);
+      }
+      assert_eq!(a.mix_linear(b, 1.0).alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_interpolates_alpha() {
+      let a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1).with_alpha(0.0);
+
+      assert_eq!(a.mix_linear(b, 0.5).alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_mixes_monochromatic_lights_along_the_line_between_them() {
+      let cmf = Observer::CIE_1931_2D.cmf();
+      let blue = cmf.at(450).unwrap().components();
+      let red = cmf.at(600).unwrap().components();
+      let blue = Xyz::new(blue[0], blue[1], blue[2]);
+      let red = Xyz::new(red[0], red[1], red[2]);
+
+      let mixed = blue.mix_linear(red, 0.5).chromaticity();
+      let (b, r) = (blue.chromaticity(), red.chromaticity());
+      let cross = (r.x() - b.x()) * (mixed.y() - b.y()) - (r.y() - b.y()) * (mixed.x() - b.x());
+
+      assert!(cross.abs() < 1e-12);
+
+      let blue_sum: f64 = blue.components().iter().sum();
+      let red_sum: f64 = red.components().iter().sum();
+      let weight = red_sum / (blue_sum + red_sum);
+      let expected_x = b.x() + (r.x() - b.x()) * weight;
+
+      assert!((mixed.x() - expected_x).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "illuminant-d50")]
+    #[test]
+    fn it_adapts_other_into_this_context() {
+      use crate::{ColorimetricContext, Illuminant};
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1).with_context(d50);
+      let expected = b.adapt_to(ColorimetricContext::new());
+
+      let mixed = a.mix_linear(b, 1.0);
+      for (actual, expected) in mixed.components().iter().zip(expected.components()) {
+        assert!((actual - expected).abs() < 1e-12);
+      }
+      assert_eq!(mixed.context().illuminant().name(), "D65");
+    }
+
+    #[test]
+    fn it_matches_when_called_through_a_generic_color_space() {
+      fn mix<C: ColorSpace<3>>(a: C, b: C, t: f64) -> C {
+        a.mix_linear(b.to_xyz(), t)
+      }
+
+      let a = Xyz::new(0.2, 0.3, 0.4);
+      let b = Xyz::new(0.6, 0.5, 0.1);
+
+      assert_eq!(mix(a, b, 0.5).components(), a.mix_linear(b, 0.5).components());
+      assert!((mix(a, b, 0.5).y() - 0.4).abs() < 1e-15);
+    }
+  }
+
+  mod mul {
+    use super::*;
+
+    #[test]
+    fn it_halves_each_component_when_scaled_by_half() {
+      let result = Xyz::new(0.4, 0.8, 0.2).with_alpha(0.5) * 0.5;
+
+      assert!((result.x() - 0.2).abs() < 1e-12);
+      assert!((result.y() - 0.4).abs() < 1e-12);
+      assert!((result.z() - 0.1).abs() < 1e-12);
+      assert!((result.alpha() - 0.5).abs() < 1e-12);
+    }
+  }
+
   mod partial_eq {
     use pretty_assertions::{assert_eq, assert_ne};
 
@@ -1348,6 +2103,49 @@ mod test {
     }
   }
 
+  mod sanitized {
+    use super::*;
+
+    #[test]
+    fn it_makes_nan_color_valid() {
+      let xyz = Xyz::new(f64::NAN, 0.5, 0.25);
+      let result = xyz.sanitized();
+
+      assert!(result.is_valid());
+      assert_eq!(result.x(), 0.0);
+      assert_eq!(result.y(), 0.5);
+      assert_eq!(result.z(), 0.25);
+    }
+
+    #[test]
+    fn it_clamps_negative_components_to_zero() {
+      let result = Xyz::new(-0.1, -0.2, 0.3).sanitized();
+
+      assert_eq!(result.x(), 0.0);
+      assert_eq!(result.y(), 0.0);
+      assert_eq!(result.z(), 0.3);
+    }
+
+    #[test]
+    fn it_makes_infinite_color_valid() {
+      let result = Xyz::new(f64::INFINITY, 0.5, f64::NEG_INFINITY).sanitized();
+
+      assert!(result.is_valid());
+      assert_eq!(result.x(), 0.0);
+      assert_eq!(result.y(), 0.5);
+      assert_eq!(result.z(), 0.0);
+    }
+
+    #[test]
+    fn it_preserves_valid_values_and_alpha() {
+      let xyz = Xyz::new(0.95047, 1.0, 1.08883).with_alpha(0.5);
+      let result = xyz.sanitized();
+
+      assert_eq!(result.components(), xyz.components());
+      assert_eq!(result.alpha(), 0.5);
+    }
+  }
+
   mod scale_x {
     use pretty_assertions::assert_eq;
 
@@ -1390,6 +2188,159 @@ mod test {
     }
   }
 
+  #[cfg(feature = "cct-ohno")]
+  mod shift_temperature {
+    use super::*;
+
+    #[test]
+    fn it_is_a_no_op_for_zero_delta() {
+      let color = Xyz::new(0.3, 0.4, 0.5).with_alpha(0.5);
+      let shifted = color.shift_temperature(0.0);
+
+      assert_eq!(shifted.components(), color.components());
+      assert_eq!(shifted.alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_increases_x_to_z_ratio_for_positive_delta() {
+      let color = Xyz::new(0.3, 0.4, 0.5);
+      let shifted = color.shift_temperature(1500.0);
+
+      assert!(shifted.x() / shifted.z() > color.x() / color.z());
+    }
+
+    #[test]
+    fn it_decreases_x_to_z_ratio_for_negative_delta() {
+      let color = Xyz::new(0.3, 0.4, 0.5);
+      let shifted = color.shift_temperature(-1500.0);
+
+      assert!(shifted.x() / shifted.z() < color.x() / color.z());
+    }
+
+    #[test]
+    fn it_preserves_luminance() {
+      let color = Xyz::new(0.3, 0.4, 0.5);
+
+      for delta in [-3000.0, -500.0, 500.0, 3000.0] {
+        assert!((color.shift_temperature(delta).y() - 0.4).abs() < 1e-12);
+      }
+    }
+
+    #[test]
+    fn it_is_monotonic_in_delta() {
+      let color = Xyz::new(0.3, 0.4, 0.5);
+      let small = color.shift_temperature(500.0);
+      let large = color.shift_temperature(2000.0);
+
+      assert!(large.x() / large.z() > small.x() / small.z());
+    }
+
+    #[test]
+    fn it_preserves_alpha_and_context() {
+      let color = Xyz::new(0.3, 0.4, 0.5).with_alpha(0.25);
+      let shifted = color.shift_temperature(1000.0);
+
+      assert_eq!(shifted.alpha(), 0.25);
+      assert_eq!(shifted.context().reference_white(), color.context().reference_white());
+    }
+
+    #[test]
+    fn it_leaves_black_unchanged() {
+      let black = Xyz::new(0.0, 0.0, 0.0);
+
+      assert_eq!(black.shift_temperature(1000.0).components(), [0.0, 0.0, 0.0]);
+    }
+  }
+
+  mod sub {
+    use super::*;
+
+    #[test]
+    fn it_subtracts_component_wise() {
+      let result = Xyz::new(0.5, 0.6, 0.7) - Xyz::new(0.1, 0.2, 0.3);
+
+      assert!((result.x() - 0.4).abs() < 1e-12);
+      assert!((result.y() - 0.4).abs() < 1e-12);
+      assert!((result.z() - 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_clamps_components_at_zero() {
+      let result = Xyz::new(0.1, 0.5, 0.1) - Xyz::new(0.3, 0.2, 0.1);
+
+      assert_eq!(result.x(), 0.0);
+      assert!((result.y() - 0.3).abs() < 1e-12);
+      assert_eq!(result.z(), 0.0);
+    }
+  }
+
+  mod to_reflectance_spd {
+    use super::*;
+
+    fn integrate(xyz: &Xyz, reflectance: &Spd) -> Xyz {
+      let illuminant = xyz.context().illuminant().spd();
+      let cmf = xyz.context().observer().cmf();
+      let mut sums = [0.0; 3];
+      let mut white_y = 0.0;
+
+      for (wavelength, r) in reflectance.table() {
+        let power = illuminant.power_at(*wavelength as f64).unwrap_or(0.0);
+        let [x, y, z] = cmf.at(*wavelength).unwrap().components();
+        sums[0] += x * power * r;
+        sums[1] += y * power * r;
+        sums[2] += z * power * r;
+        white_y += y * power;
+      }
+
+      Xyz::new(sums[0] / white_y, sums[1] / white_y, sums[2] / white_y)
+    }
+
+    fn max_difference(a: Xyz, b: Xyz) -> f64 {
+      let [x1, y1, z1] = a.components();
+      let [x2, y2, z2] = b.components();
+      (x1 - x2).abs().max((y1 - y2).abs()).max((z1 - z2).abs())
+    }
+
+    #[test]
+    fn it_round_trips_mid_gray() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128).to_xyz();
+      let reflectance = gray.to_reflectance_spd();
+
+      assert!(max_difference(gray, integrate(&gray, &reflectance)) < 1e-4);
+    }
+
+    #[test]
+    fn it_round_trips_a_chromatic_color() {
+      let orange = Rgb::<Srgb>::new(200, 120, 60).to_xyz();
+      let reflectance = orange.to_reflectance_spd();
+
+      assert!(max_difference(orange, integrate(&orange, &reflectance)) < 1e-4);
+    }
+
+    #[test]
+    fn it_keeps_values_within_unit_range() {
+      let reflectance = Rgb::<Srgb>::new(20, 180, 90).to_xyz().to_reflectance_spd();
+
+      assert!(reflectance.values().all(|&r| (0.0..=1.0).contains(&r)));
+    }
+
+    #[test]
+    fn it_samples_every_five_nanometers() {
+      let reflectance = Xyz::new(0.3, 0.3, 0.3).to_reflectance_spd();
+
+      assert_eq!(reflectance.min_wavelength(), Some(380));
+      assert_eq!(reflectance.max_wavelength(), Some(780));
+      assert_eq!(reflectance.step(), 5);
+    }
+
+    #[test]
+    fn it_returns_zero_reflectance_for_black() {
+      let reflectance = Xyz::new(0.0, 0.0, 0.0).to_reflectance_spd();
+
+      assert!(reflectance.values().all(|&r| r == 0.0));
+    }
+  }
+
   mod with_luminance {
     use pretty_assertions::assert_eq;
 
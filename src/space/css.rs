@@ -0,0 +1,219 @@
+//! Parsing helpers for CSS Color Level 4 functional notation (e.g. `lab(50% 20 -30 / 0.5)`).
+
+#[cfg(any(feature = "space-hwb", feature = "space-lch"))]
+use std::f64::consts::PI;
+
+use crate::component::Component;
+
+/// Splits a CSS color function such as `name(c1 c2 c3 / alpha)` into its three component tokens and
+/// optional alpha token.
+///
+/// The function name is matched case-insensitively and surrounding whitespace is ignored. Returns
+/// `None` if the name does not match or the argument list is malformed.
+pub(crate) fn parse_function<'a>(input: &'a str, name: &str) -> Option<([&'a str; 3], Option<&'a str>)> {
+  let input = input.trim();
+  let open = input.find('(')?;
+
+  if !input[..open].trim_end().eq_ignore_ascii_case(name) {
+    return None;
+  }
+
+  let arguments = input[open + 1..].strip_suffix(')')?;
+  let (components, alpha) = match arguments.split_once('/') {
+    Some((components, alpha)) => {
+      let alpha = alpha.trim();
+      if alpha.is_empty() || alpha.contains(char::is_whitespace) {
+        return None;
+      }
+      (components, Some(alpha))
+    }
+    None => (arguments, None),
+  };
+
+  let mut tokens = components.split_whitespace();
+  let components = [tokens.next()?, tokens.next()?, tokens.next()?];
+
+  if tokens.next().is_some() {
+    return None;
+  }
+
+  Some((components, alpha))
+}
+
+/// Parses an optional CSS alpha token (a number or percentage), defaulting to 1.0 and clamping
+/// to 0.0-1.0.
+pub(crate) fn parse_alpha(token: Option<&str>) -> Option<f64> {
+  match token {
+    Some(token) => parse_number(token, 1.0).map(|alpha| alpha.clamp(0.0, 1.0)),
+    None => Some(1.0),
+  }
+}
+
+/// Parses a CSS `<hue>` token in degrees, accepting the `deg`, `grad`, `rad`, and `turn` units.
+///
+/// The `none` keyword resolves to 0.
+#[cfg(any(feature = "space-hwb", feature = "space-lch"))]
+pub(crate) fn parse_hue(token: &str) -> Option<f64> {
+  if token.eq_ignore_ascii_case("none") {
+    return Some(0.0);
+  }
+
+  let lower = token.to_ascii_lowercase();
+  let (value, scale) = if let Some(value) = lower.strip_suffix("deg") {
+    (value, 1.0)
+  } else if let Some(value) = lower.strip_suffix("grad") {
+    (value, 0.9)
+  } else if let Some(value) = lower.strip_suffix("rad") {
+    (value, 180.0 / PI)
+  } else if let Some(value) = lower.strip_suffix("turn") {
+    (value, 360.0)
+  } else {
+    (lower.as_str(), 1.0)
+  };
+
+  parse_finite(value).map(|value| value * scale)
+}
+
+/// Parses a CSS `<number>` or `<percentage>` token, where 100% maps to `percent_reference`.
+///
+/// The `none` keyword resolves to 0.
+pub(crate) fn parse_number(token: &str, percent_reference: f64) -> Option<f64> {
+  if token.eq_ignore_ascii_case("none") {
+    return Some(0.0);
+  }
+
+  let value = token.parse::<Component>().ok()?.0;
+  Some(if token.ends_with('%') {
+    value * percent_reference
+  } else {
+    value
+  })
+}
+
+#[cfg(any(feature = "space-hwb", feature = "space-lch"))]
+fn parse_finite(token: &str) -> Option<f64> {
+  token.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod parse_alpha {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_defaults_to_opaque() {
+      assert_eq!(parse_alpha(None), Some(1.0));
+    }
+
+    #[test]
+    fn it_parses_numbers_and_percentages() {
+      assert_eq!(parse_alpha(Some("0.25")), Some(0.25));
+      assert_eq!(parse_alpha(Some("50%")), Some(0.5));
+    }
+
+    #[test]
+    fn it_clamps_out_of_range_values() {
+      assert_eq!(parse_alpha(Some("1.5")), Some(1.0));
+      assert_eq!(parse_alpha(Some("-1")), Some(0.0));
+    }
+  }
+
+  mod parse_function {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_splits_components() {
+      assert_eq!(
+        parse_function("lab(50% 20 -30)", "lab"),
+        Some((["50%", "20", "-30"], None))
+      );
+    }
+
+    #[test]
+    fn it_splits_alpha() {
+      assert_eq!(
+        parse_function("  LAB( 50 20 -30 / 0.5 ) ", "lab"),
+        Some((["50", "20", "-30"], Some("0.5")))
+      );
+    }
+
+    #[test]
+    fn it_rejects_other_functions() {
+      assert_eq!(parse_function("lch(50 20 30)", "lab"), None);
+    }
+
+    #[test]
+    fn it_rejects_wrong_component_counts() {
+      assert_eq!(parse_function("lab(50 20)", "lab"), None);
+      assert_eq!(parse_function("lab(50 20 30 40)", "lab"), None);
+      assert_eq!(parse_function("lab(50 20 30 /)", "lab"), None);
+    }
+
+    #[test]
+    fn it_rejects_missing_parenthesis() {
+      assert_eq!(parse_function("lab(50 20 30", "lab"), None);
+    }
+  }
+
+  #[cfg(any(feature = "space-hwb", feature = "space-lch"))]
+  mod parse_hue {
+    use super::*;
+
+    #[test]
+    fn it_parses_bare_degrees() {
+      assert_eq!(parse_hue("145"), Some(145.0));
+    }
+
+    #[test]
+    fn it_parses_units() {
+      assert!((parse_hue("90deg").unwrap() - 90.0).abs() < 1e-10);
+      assert!((parse_hue("100grad").unwrap() - 90.0).abs() < 1e-10);
+      assert!((parse_hue("0.25turn").unwrap() - 90.0).abs() < 1e-10);
+      assert!((parse_hue("3.141592653589793rad").unwrap() - 180.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_resolves_none_to_zero() {
+      assert_eq!(parse_hue("none"), Some(0.0));
+    }
+
+    #[test]
+    fn it_rejects_invalid_tokens() {
+      assert_eq!(parse_hue("red"), None);
+    }
+  }
+
+  mod parse_number {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_numbers() {
+      assert_eq!(parse_number("-12.5", 100.0), Some(-12.5));
+    }
+
+    #[test]
+    fn it_scales_percentages_by_reference() {
+      assert_eq!(parse_number("50%", 125.0), Some(62.5));
+    }
+
+    #[test]
+    fn it_resolves_none_to_zero() {
+      assert_eq!(parse_number("NONE", 100.0), Some(0.0));
+    }
+
+    #[test]
+    fn it_rejects_non_finite_values() {
+      assert_eq!(parse_number("inf", 100.0), None);
+      assert_eq!(parse_number("NaN", 100.0), None);
+      assert_eq!(parse_number("abc", 100.0), None);
+    }
+  }
+}
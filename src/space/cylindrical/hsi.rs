@@ -4,6 +4,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -16,6 +18,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -802,6 +806,16 @@ where
   }
 }
 
+#[cfg(feature = "trace")]
+impl<S> HubPath for Hsi<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Hsi"], Rgb::<S>::hub_path()].concat()
+  }
+}
+
 impl<S, T> Mul<T> for Hsi<S>
 where
   S: RgbSpec,
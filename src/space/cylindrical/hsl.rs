@@ -4,6 +4,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -12,6 +14,8 @@ use crate::space::Cmyk;
 use crate::space::Hpluv;
 #[cfg(feature = "space-hsluv")]
 use crate::space::Hsluv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -39,7 +43,7 @@ use crate::space::{Hsb, Hsv};
 use crate::{
   ColorimetricContext,
   component::Component,
-  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz, interpolation::ACHROMATIC_THRESHOLD},
 };
 
 /// HSL (Hue, Saturation, Lightness) color space.
@@ -171,6 +175,17 @@ where
     self.increment_s(amount.into() / 100.0)
   }
 
+  /// Returns `true` if the hue is powerless: saturation is near 0%, or lightness is near 0% or
+  /// 100%.
+  ///
+  /// Such colors are grays (including black and white) and hue-based operations have no visible
+  /// effect on them.
+  pub fn is_achromatic(&self) -> bool {
+    self.s.0.abs() < ACHROMATIC_THRESHOLD
+      || self.l.0.abs() < ACHROMATIC_THRESHOLD
+      || (1.0 - self.l.0).abs() < ACHROMATIC_THRESHOLD
+  }
+
   /// Returns the normalized lightness component (0.0-1.0).
   pub fn l(&self) -> f64 {
     self.l.0
@@ -829,6 +844,16 @@ where
   }
 }
 
+#[cfg(feature = "trace")]
+impl<S> HubPath for Hsl<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Hsl"], Rgb::<S>::hub_path()].concat()
+  }
+}
+
 impl<S, T> Mul<T> for Hsl<S>
 where
   S: RgbSpec,
@@ -1280,6 +1305,22 @@ mod test {
     }
   }
 
+  mod is_achromatic {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_for_grays() {
+      assert!(Hsl::<Srgb>::new(210.0, 0.0, 50.0).is_achromatic());
+      assert!(Hsl::<Srgb>::new(210.0, 80.0, 0.0).is_achromatic());
+      assert!(Hsl::<Srgb>::new(210.0, 80.0, 100.0).is_achromatic());
+    }
+
+    #[test]
+    fn it_returns_false_for_saturated_colors() {
+      assert!(!Hsl::<Srgb>::new(210.0, 80.0, 50.0).is_achromatic());
+    }
+  }
+
   mod mul {
     use super::*;
 
@@ -4,6 +4,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -14,6 +16,8 @@ use crate::space::Hpluv;
 use crate::space::Hsl;
 #[cfg(feature = "space-hsluv")]
 use crate::space::Hsluv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -39,7 +43,7 @@ use crate::space::Xyy;
 use crate::{
   ColorimetricContext,
   component::Component,
-  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz, interpolation::ACHROMATIC_THRESHOLD},
 };
 
 /// Type alias for [`Hsv`] using the HSB (Hue, Saturation, Brightness) naming convention.
@@ -204,6 +208,14 @@ where
     self.increment_value(amount)
   }
 
+  /// Returns `true` if the hue is powerless: saturation or value is near 0%.
+  ///
+  /// Such colors are grays (including black) and hue-based operations have no visible effect on
+  /// them.
+  pub fn is_achromatic(&self) -> bool {
+    self.s.0.abs() < ACHROMATIC_THRESHOLD || self.v.0.abs() < ACHROMATIC_THRESHOLD
+  }
+
   /// Returns the normalized saturation component (0.0-1.0).
   pub fn s(&self) -> f64 {
     self.s.0
@@ -908,6 +920,16 @@ where
   }
 }
 
+#[cfg(feature = "trace")]
+impl<S> HubPath for Hsv<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Hsv"], Rgb::<S>::hub_path()].concat()
+  }
+}
+
 impl<S, T> Mul<T> for Hsv<S>
 where
   S: RgbSpec,
@@ -967,6 +989,37 @@ where
 mod test {
   use super::*;
 
+  mod adapt_to {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "illuminant-d50")]
+    fn it_matches_adapting_xyz_directly() {
+      use crate::Illuminant;
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let white = Hsv::<Srgb>::new(0.0, 0.0, 100.0);
+      let expected = white.to_xyz().adapt_to(d50);
+      let result = ColorSpace::adapt_to(&white, d50);
+
+      assert!((result.x() - expected.x()).abs() < 1e-10);
+      assert!((result.y() - expected.y()).abs() < 1e-10);
+      assert!((result.z() - expected.z()).abs() < 1e-10);
+      assert_eq!(result.context().illuminant().name(), "D50");
+    }
+
+    #[test]
+    fn it_returns_xyz_in_target_context() {
+      let white = Hsv::<Srgb>::new(0.0, 0.0, 100.0);
+      let result = white.adapt_to(ColorimetricContext::default());
+      let expected = white.to_xyz();
+
+      assert!((result.x() - expected.x()).abs() < 1e-10);
+      assert!((result.y() - expected.y()).abs() < 1e-10);
+      assert!((result.z() - expected.z()).abs() < 1e-10);
+    }
+  }
+
   mod add {
     use super::*;
 
@@ -1319,6 +1372,21 @@ mod test {
     }
   }
 
+  mod is_achromatic {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_for_grays() {
+      assert!(Hsv::<Srgb>::new(120.0, 0.0, 60.0).is_achromatic());
+      assert!(Hsv::<Srgb>::new(120.0, 90.0, 0.0).is_achromatic());
+    }
+
+    #[test]
+    fn it_returns_false_for_saturated_colors() {
+      assert!(!Hsv::<Srgb>::new(120.0, 90.0, 60.0).is_achromatic());
+    }
+  }
+
   mod mul {
     use super::*;
 
@@ -1589,6 +1657,22 @@ mod test {
     }
   }
 
+  #[cfg(feature = "space-lab")]
+  mod to_space {
+    use super::*;
+    use crate::space::Lab;
+
+    #[test]
+    fn it_matches_the_explicit_lab_conversion() {
+      let hsv = Hsv::<Srgb>::new(210.0, 65.0, 80.0).with_alpha(0.5);
+      let generic: Lab = hsv.to_space();
+      let explicit = hsv.to_lab();
+
+      assert_eq!(generic.components(), explicit.components());
+      assert_eq!(generic.alpha(), 0.5);
+    }
+  }
+
   mod to_xyz {
     use super::*;
 
@@ -1614,6 +1698,37 @@ mod test {
     }
   }
 
+  mod to_xyz_in {
+    use super::*;
+
+    #[test]
+    fn it_equals_to_xyz_in_the_same_context() {
+      let color = Hsv::<Srgb>::new(30.0, 60.0, 80.0);
+      let result = color.to_xyz_in(ColorimetricContext::default());
+      let expected = color.to_xyz();
+
+      assert!((result.x() - expected.x()).abs() < 1e-10);
+      assert!((result.y() - expected.y()).abs() < 1e-10);
+      assert!((result.z() - expected.z()).abs() < 1e-10);
+    }
+
+    #[test]
+    #[cfg(feature = "illuminant-d50")]
+    fn it_matches_adapting_to_xyz_for_a_different_context() {
+      use crate::Illuminant;
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let color = Hsv::<Srgb>::new(30.0, 60.0, 80.0);
+      let result = color.to_xyz_in(d50);
+      let expected = color.to_xyz().adapt_to(d50);
+
+      assert!((result.x() - expected.x()).abs() < 1e-10);
+      assert!((result.y() - expected.y()).abs() < 1e-10);
+      assert!((result.z() - expected.z()).abs() < 1e-10);
+      assert!((result.x() - color.to_xyz().x()).abs() > 1e-4);
+    }
+  }
+
   mod with_h {
     use pretty_assertions::assert_eq;
 
@@ -4,6 +4,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -14,6 +16,8 @@ use crate::space::Hpluv;
 use crate::space::Hsl;
 #[cfg(feature = "space-hsluv")]
 use crate::space::Hsluv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-lab")]
 use crate::space::Lab;
 #[cfg(feature = "space-lch")]
@@ -37,9 +41,9 @@ use crate::space::Xyy;
 #[cfg(feature = "space-hsv")]
 use crate::space::{Hsb, Hsv};
 use crate::{
-  ColorimetricContext,
+  ColorimetricContext, Error,
   component::Component,
-  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, Lms, Rgb, RgbSpec, Srgb, Xyz, css, interpolation::ACHROMATIC_THRESHOLD},
 };
 
 /// HWB (Hue, Whiteness, Blackness) color space.
@@ -181,6 +185,13 @@ where
     self.increment_w(amount.into() / 100.0)
   }
 
+  /// Returns `true` if the hue is powerless: whiteness plus blackness is at or near 100%.
+  ///
+  /// Such colors are grays and hue-based operations have no visible effect on them.
+  pub fn is_achromatic(&self) -> bool {
+    self.w.0 + self.b.0 > 1.0 - ACHROMATIC_THRESHOLD
+  }
+
   /// Scales the normalized blackness by the given factor.
   pub fn scale_b(&mut self, factor: impl Into<Component>) {
     self.b *= factor.into();
@@ -255,16 +266,13 @@ where
   }
 
   /// Converts this HWB color to an [`Hsl`] color in the specified RGB color space.
+  ///
+  /// Converts analytically through the HSV relations (see [`to_hsv`](Self::to_hsv)) without an
+  /// RGB round-trip, so W + B > 1 is normalized to the same gray as [`to_rgb`](Self::to_rgb).
   #[cfg(feature = "space-hsl")]
   pub fn to_hsl(&self) -> Hsl<S> {
-    let [h, w, b] = self.components();
-
-    let (s, v) = if w + b >= 1.0 {
-      (0.0, w / (w + b))
-    } else {
-      let v = 1.0 - b;
-      (1.0 - (w / v), v)
-    };
+    let h = self.h.0;
+    let (s, v) = self.saturation_and_value();
 
     let l = v * (1.0 - (s / 2.0));
     let sl = if l == 0.0 || l == 1.0 {
@@ -277,16 +285,32 @@ where
   }
 
   /// Converts this HWB color to an [`Hsv`] color in the specified RGB color space.
+  ///
+  /// Uses the analytic relations V = 1 − B and S = 1 − W / V, avoiding an RGB round-trip. When
+  /// W + B ≥ 1 the color is the gray W / (W + B) with zero saturation, and pure black (V = 0) is
+  /// treated as achromatic.
   #[cfg(feature = "space-hsv")]
   pub fn to_hsv(&self) -> Hsv<S> {
-    let [h, w, b] = self.components();
-
-    let v = 1.0 - b;
-    let s = if v == 0.0 { 0.0 } else { 1.0 - (w / v) };
+    let h = self.h.0;
+    let (s, v) = self.saturation_and_value();
 
     Hsv::<S>::new(h * 360.0, s * 100.0, v * 100.0).with_alpha(self.alpha)
   }
 
+  /// Returns the normalized HSV (saturation, value) pair for this color.
+  #[cfg(any(feature = "space-hsl", feature = "space-hsv"))]
+  fn saturation_and_value(&self) -> (f64, f64) {
+    let w = self.w.0;
+    let b = self.b.0;
+
+    if w + b >= 1.0 {
+      (0.0, w / (w + b))
+    } else {
+      let v = 1.0 - b;
+      (1.0 - (w / v), v)
+    }
+  }
+
   /// Converts this HWB color to an [`Rgb`] color in the specified output space.
   pub fn to_rgb<OS>(&self) -> Rgb<OS>
   where
@@ -829,6 +853,16 @@ where
   }
 }
 
+#[cfg(feature = "trace")]
+impl<S> HubPath for Hwb<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Hwb"], Rgb::<S>::hub_path()].concat()
+  }
+}
+
 impl<S, T> Mul<T> for Hwb<S>
 where
   S: RgbSpec,
@@ -884,7 +918,67 @@ where
   }
 }
 
+impl TryFrom<&str> for Hwb<Srgb> {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    if value
+      .trim_start()
+      .get(..4)
+      .is_some_and(|prefix| prefix.eq_ignore_ascii_case("hwb("))
+    {
+      return Self::from_css(value);
+    }
+
+    Ok(Self::from(Rgb::<Srgb>::try_from(value)?))
+  }
+}
+
+impl TryFrom<String> for Hwb<Srgb> {
+  type Error = Error;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    Self::try_from(value.as_str())
+  }
+}
+
 impl Hwb<crate::space::Srgb> {
+  /// Parses a CSS Color Level 4 `hwb(...)` string, e.g. `hwb(120 30% 20% / 0.5)`.
+  ///
+  /// H accepts the CSS angle units (`deg`, `grad`, `rad`, `turn`), and `none` is treated as a
+  /// powerless hue of 0°. Whiteness and blackness may be percentages or numbers on the same
+  /// 0-100 scale; negative values are clamped to 0, and when their sum exceeds 100% both are
+  /// scaled down proportionally so they sum to 100%, as the specification requires.
+  ///
+  /// ```
+  /// use farg::space::{Hwb, Srgb};
+  ///
+  /// let color = Hwb::<Srgb>::from_css("hwb(120 30% 20%)").unwrap();
+  ///
+  /// assert!((color.hue() - 120.0).abs() < 1e-10);
+  /// assert!((color.whiteness() - 30.0).abs() < 1e-10);
+  /// ```
+  pub fn from_css(css: impl Into<String>) -> Result<Self, Error> {
+    let css = css.into();
+    let invalid = || Error::InvalidCssColor {
+      input: css.clone(),
+    };
+    let ([h, w, b], alpha) = css::parse_function(&css, "hwb").ok_or_else(invalid)?;
+
+    let h = css::parse_hue(h).ok_or_else(invalid)?;
+    let mut w = css::parse_number(w, 100.0).ok_or_else(invalid)?.max(0.0);
+    let mut b = css::parse_number(b, 100.0).ok_or_else(invalid)?.max(0.0);
+    let alpha = css::parse_alpha(alpha).ok_or_else(invalid)?;
+
+    let sum = w + b;
+    if sum > 100.0 {
+      w = w / sum * 100.0;
+      b = b / sum * 100.0;
+    }
+
+    Ok(Self::new(h, w, b).with_alpha(alpha))
+  }
+
   /// Returns this color as a CSS Color Level 4 `hwb(...)` string.
   ///
   /// Uses space-separated modern syntax: hue in degrees, whiteness and blackness
@@ -1082,6 +1176,63 @@ mod test {
     }
   }
 
+  mod from_css {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_hue_whiteness_and_blackness() {
+      let color = Hwb::<Srgb>::from_css("hwb(120 30% 20%)").unwrap();
+
+      assert!((color.hue() - 120.0).abs() < 1e-10);
+      assert!((color.whiteness() - 30.0).abs() < 1e-10);
+      assert!((color.blackness() - 20.0).abs() < 1e-10);
+      assert_eq!(color.alpha(), 1.0);
+    }
+
+    #[test]
+    fn it_parses_alpha() {
+      let color = Hwb::<Srgb>::from_css("hwb(120 30% 20% / 50%)").unwrap();
+
+      assert_eq!(color.alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_treats_none_hue_as_powerless() {
+      let color = Hwb::<Srgb>::from_css("hwb(none 30% 20%)").unwrap();
+
+      assert_eq!(color.hue(), 0.0);
+    }
+
+    #[test]
+    fn it_normalizes_whiteness_and_blackness_above_100_percent() {
+      let color = Hwb::<Srgb>::from_css("hwb(0 60% 60%)").unwrap();
+
+      assert!((color.whiteness() - 50.0).abs() < 1e-10);
+      assert!((color.blackness() - 50.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_round_trips_through_to_css() {
+      let color = Hwb::<Srgb>::from_css("hwb(194 0% 0%)").unwrap();
+
+      assert_eq!(color.to_css(), "hwb(194 0% 0%)");
+    }
+
+    #[test]
+    fn it_rejects_invalid_input() {
+      for input in [
+        "hsl(120 30% 20%)",
+        "hwb(120 30%)",
+        "hwb(120 30% 20%",
+        "hwb(abc 30% 20%)",
+      ] {
+        assert!(Hwb::<Srgb>::from_css(input).is_err());
+      }
+    }
+  }
+
   #[cfg(feature = "space-hsl")]
   mod from_hsl {
     use super::*;
@@ -1310,6 +1461,21 @@ mod test {
     }
   }
 
+  mod is_achromatic {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_for_grays() {
+      assert!(Hwb::<Srgb>::new(40.0, 30.0, 70.0).is_achromatic());
+      assert!(Hwb::<Srgb>::new(40.0, 80.0, 60.0).is_achromatic());
+    }
+
+    #[test]
+    fn it_returns_false_for_saturated_colors() {
+      assert!(!Hwb::<Srgb>::new(40.0, 10.0, 20.0).is_achromatic());
+    }
+  }
+
   mod mul {
     use super::*;
 
@@ -1578,6 +1744,14 @@ mod test {
       assert!((hsl.lightness() - 50.0).abs() < 1.0);
     }
 
+    #[test]
+    fn it_normalizes_whiteness_and_blackness_above_one() {
+      let hsl = Hwb::<Srgb>::new(120.0, 60.0, 60.0).to_hsl();
+
+      assert!(hsl.saturation().abs() < 1e-10);
+      assert!((hsl.lightness() - 50.0).abs() < 1e-10);
+    }
+
     #[test]
     fn it_roundtrips_with_from_hsl() {
       let original = Hwb::<Srgb>::new(210.0, 20.0, 40.0);
@@ -1604,6 +1778,48 @@ mod test {
       assert!((hsv.value() - 100.0).abs() < 1.0);
     }
 
+    #[test]
+    fn it_returns_full_saturation_and_value_for_zero_whiteness_and_blackness() {
+      let hsv = Hwb::<Srgb>::new(30.0, 0.0, 0.0).to_hsv();
+
+      assert!((hsv.saturation() - 100.0).abs() < 1e-10);
+      assert!((hsv.value() - 100.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_returns_zero_saturation_when_whiteness_and_blackness_sum_to_one() {
+      let hsv = Hwb::<Srgb>::new(30.0, 40.0, 60.0).to_hsv();
+
+      assert!(hsv.saturation().abs() < 1e-10);
+      assert!((hsv.value() - 40.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_normalizes_whiteness_and_blackness_above_one() {
+      let hsv = Hwb::<Srgb>::new(30.0, 75.0, 75.0).to_hsv();
+
+      assert!(hsv.saturation().abs() < 1e-10);
+      assert!((hsv.value() - 50.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_treats_black_as_achromatic() {
+      let hsv = Hwb::<Srgb>::new(30.0, 0.0, 100.0).to_hsv();
+
+      assert_eq!(hsv.saturation(), 0.0);
+      assert_eq!(hsv.value(), 0.0);
+    }
+
+    #[test]
+    fn it_matches_the_rgb_round_trip() {
+      let hwb = Hwb::<Srgb>::new(210.0, 70.0, 50.0);
+      let direct = hwb.to_hsv();
+      let via_rgb = hwb.to_rgb::<Srgb>().to_hsv();
+
+      assert!((direct.value() - via_rgb.value()).abs() < 0.5);
+      assert!((direct.saturation() - via_rgb.saturation()).abs() < 0.5);
+    }
+
     #[test]
     fn it_roundtrips_with_from_hsv() {
       let original = Hwb::<Srgb>::new(210.0, 20.0, 40.0);
@@ -1641,6 +1857,30 @@ mod test {
     }
   }
 
+  mod try_from_str {
+    use super::*;
+
+    #[test]
+    fn it_parses_hex_string() {
+      let hwb = Hwb::<Srgb>::try_from("#ff0000").unwrap();
+
+      assert!(hwb.whiteness().abs() < 1e-10);
+      assert!(hwb.blackness().abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_parses_css_string() {
+      let hwb = Hwb::<Srgb>::try_from("HWB(194 0% 0%)").unwrap();
+
+      assert!((hwb.hue() - 194.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_returns_error_for_invalid_input() {
+      assert!(Hwb::<Srgb>::try_from("not_a_color").is_err());
+    }
+  }
+
   mod with_b {
     use pretty_assertions::assert_eq;
 
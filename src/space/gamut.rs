@@ -0,0 +1,112 @@
+use super::{Rgb, RgbSpec};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Estimates the volume of an RGB space's gamut in Oklab.
+///
+/// Each face of the RGB cube is divided into a `samples_per_axis` × `samples_per_axis` grid,
+/// mapped to Oklab through XYZ, and triangulated; the enclosed volume follows from the
+/// divergence theorem as the sum of signed tetrahedra spanned by each triangle and the origin.
+/// More samples follow the curved gamut surface more closely. A value of 0 is treated as 1.
+///
+/// ```
+/// use farg::space::{Srgb, gamut_volume};
+///
+/// let volume = gamut_volume::<Srgb>(8);
+/// assert!(volume > 0.0);
+/// ```
+pub fn gamut_volume<S>(samples_per_axis: usize) -> f64
+where
+  S: RgbSpec,
+{
+  let n = samples_per_axis.max(1);
+  let to_oklab = |rgb: [f64; 3]| {
+    Rgb::<S>::from_normalized(rgb[0], rgb[1], rgb[2])
+      .to_xyz()
+      .to_oklab()
+      .components()
+  };
+  let cross = |a: [f64; 3], b: [f64; 3]| {
+    [
+      a[1] * b[2] - a[2] * b[1],
+      a[2] * b[0] - a[0] * b[2],
+      a[0] * b[1] - a[1] * b[0],
+    ]
+  };
+  let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+  let mut volume = 0.0;
+
+  // Each face is the fixed axis, its fixed value, and the two free axes ordered so (u × v) points outward.
+  for (axis, value, u_axis, v_axis) in [
+    (0, 1.0, 1, 2),
+    (0, 0.0, 2, 1),
+    (1, 1.0, 2, 0),
+    (1, 0.0, 0, 2),
+    (2, 1.0, 0, 1),
+    (2, 0.0, 1, 0),
+  ] {
+    let grid: Vec<Vec<[f64; 3]>> = (0..=n)
+      .map(|i| {
+        (0..=n)
+          .map(|j| {
+            let mut rgb = [0.0; 3];
+            rgb[axis] = value;
+            rgb[u_axis] = i as f64 / n as f64;
+            rgb[v_axis] = j as f64 / n as f64;
+            to_oklab(rgb)
+          })
+          .collect()
+      })
+      .collect();
+
+    for i in 0..n {
+      for j in 0..n {
+        let [p00, p10, p01, p11] = [grid[i][j], grid[i + 1][j], grid[i][j + 1], grid[i + 1][j + 1]];
+        volume += dot(p00, cross(p10, p11)) + dot(p00, cross(p11, p01));
+      }
+    }
+  }
+
+  (volume / 6.0).abs()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::space::Srgb;
+
+  mod gamut_volume {
+    use super::*;
+
+    #[test]
+    fn it_returns_a_positive_volume_for_srgb() {
+      assert!(gamut_volume::<Srgb>(8) > 0.0);
+    }
+
+    #[test]
+    fn it_converges_as_samples_increase() {
+      let coarse = gamut_volume::<Srgb>(8);
+      let fine = gamut_volume::<Srgb>(24);
+
+      assert!((coarse - fine).abs() / fine < 0.05);
+    }
+
+    #[test]
+    fn it_treats_zero_samples_as_one() {
+      assert_eq!(gamut_volume::<Srgb>(0), gamut_volume::<Srgb>(1));
+    }
+
+    #[cfg(all(feature = "rgb-display-p3", feature = "rgb-rec-2020"))]
+    #[test]
+    fn it_ranks_rec2020_above_display_p3_above_srgb() {
+      use crate::space::{DisplayP3, Rec2020};
+
+      let srgb = gamut_volume::<Srgb>(8);
+      let p3 = gamut_volume::<DisplayP3>(8);
+      let rec2020 = gamut_volume::<Rec2020>(8);
+
+      assert!(rec2020 > p3);
+      assert!(p3 > srgb);
+    }
+  }
+}
@@ -0,0 +1,162 @@
+use std::iter;
+
+use super::{ColorSpace, Oklch, Rgb, Srgb};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Lightness that [`HarmonyScheme::Monochromatic`] steps toward for its darker shades.
+const MONOCHROMATIC_DARK: f64 = 0.15;
+
+/// Lightness that [`HarmonyScheme::Monochromatic`] steps toward for its lighter tints.
+const MONOCHROMATIC_LIGHT: f64 = 0.95;
+
+/// A named color harmony computed by [`Rgb::harmony`].
+///
+/// Hue-based schemes use the [`ColorSpace`] harmony methods (such as
+/// [`triadic`](ColorSpace::triadic)) on the Oklch form, keeping lightness and chroma;
+/// [`Monochromatic`](Self::Monochromatic) keeps hue and chroma while varying lightness.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HarmonyScheme {
+  /// The color and its neighbors at -30° and +30°.
+  Analogous,
+  /// The color and its opposite at +180°.
+  Complementary,
+  /// Five shades of the color's hue and chroma, from dark to light.
+  Monochromatic,
+  /// The color and the two neighbors of its complement, at +150° and +210°.
+  SplitComplementary,
+  /// The color and two others at +90°, +180°, and +270°.
+  Tetradic,
+  /// The color and two others at +120° and +240°.
+  Triadic,
+}
+
+impl Rgb<Srgb> {
+  /// Returns the swatches of a color harmony built around this color.
+  ///
+  /// Each swatch is computed in Oklch and converted back to sRGB with gamut clipping. The result
+  /// includes this color: first for hue-rotation schemes, in the middle for
+  /// [`HarmonyScheme::Analogous`] and [`HarmonyScheme::Monochromatic`], which are ordered by hue
+  /// and lightness respectively. Alpha is preserved on every swatch.
+  ///
+  /// ```
+  /// use farg::space::{HarmonyScheme, Rgb, Srgb};
+  ///
+  /// let color = Rgb::<Srgb>::new(200, 80, 60);
+  ///
+  /// assert_eq!(color.harmony(HarmonyScheme::Triadic).len(), 3);
+  /// assert_eq!(color.harmony(HarmonyScheme::Monochromatic).len(), 5);
+  /// ```
+  pub fn harmony(&self, scheme: HarmonyScheme) -> Vec<Self> {
+    let oklch = self.to_oklch();
+
+    let swatches: Vec<Oklch> = match scheme {
+      HarmonyScheme::Analogous => {
+        let [below, above] = oklch.analogous();
+        vec![below, oklch, above]
+      }
+      HarmonyScheme::Complementary => vec![oklch, oklch.complementary()],
+      HarmonyScheme::Monochromatic => {
+        let l = oklch.l();
+        let toward = |target: f64, t: f64| oklch.with_l(l + (target - l) * t);
+
+        vec![
+          toward(MONOCHROMATIC_DARK, 2.0 / 3.0),
+          toward(MONOCHROMATIC_DARK, 1.0 / 3.0),
+          oklch,
+          toward(MONOCHROMATIC_LIGHT, 1.0 / 3.0),
+          toward(MONOCHROMATIC_LIGHT, 2.0 / 3.0),
+        ]
+      }
+      HarmonyScheme::SplitComplementary => iter::once(oklch).chain(oklch.split_complementary()).collect(),
+      HarmonyScheme::Tetradic => iter::once(oklch).chain(oklch.tetradic()).collect(),
+      HarmonyScheme::Triadic => iter::once(oklch).chain(oklch.triadic()).collect(),
+    };
+
+    swatches
+      .into_iter()
+      .map(|swatch| {
+        let mut rgb = swatch.to_rgb::<Srgb>().with_alpha(self.alpha());
+        rgb.clip_to_gamut();
+        rgb
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod harmony {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn hue_distance(a: f64, b: f64) -> f64 {
+      let diff = (a - b).rem_euclid(360.0);
+      diff.min(360.0 - diff)
+    }
+
+    #[test]
+    fn it_spaces_triadic_hues_120_degrees_apart() {
+      let swatches = Rgb::<Srgb>::new(150, 110, 100).harmony(HarmonyScheme::Triadic);
+      let hues: Vec<f64> = swatches.iter().map(|c| c.to_oklch().hue()).collect();
+
+      assert_eq!(swatches.len(), 3);
+      assert!((hue_distance(hues[0], hues[1]) - 120.0).abs() < 1.0);
+      assert!((hue_distance(hues[1], hues[2]) - 120.0).abs() < 1.0);
+      assert!((hue_distance(hues[2], hues[0]) - 120.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn it_keeps_hue_and_varies_lightness_for_monochromatic() {
+      let color = Rgb::<Srgb>::new(140, 100, 90);
+      let swatches = color.harmony(HarmonyScheme::Monochromatic);
+      let hue = color.to_oklch().hue();
+
+      assert_eq!(swatches.len(), 5);
+      for swatch in &swatches {
+        assert!(hue_distance(swatch.to_oklch().hue(), hue) < 2.0);
+      }
+      for pair in swatches.windows(2) {
+        assert!(pair[0].to_oklch().l() < pair[1].to_oklch().l());
+      }
+    }
+
+    #[test]
+    fn it_includes_the_original_color() {
+      let color = Rgb::<Srgb>::new(200, 80, 60);
+
+      assert_eq!(color.harmony(HarmonyScheme::Complementary)[0].to_hex(), "#c8503c");
+      assert_eq!(color.harmony(HarmonyScheme::Analogous)[1].to_hex(), "#c8503c");
+      assert_eq!(color.harmony(HarmonyScheme::Monochromatic)[2].to_hex(), "#c8503c");
+    }
+
+    #[test]
+    fn it_returns_the_scheme_sizes() {
+      let color = Rgb::<Srgb>::new(200, 80, 60);
+
+      assert_eq!(color.harmony(HarmonyScheme::Analogous).len(), 3);
+      assert_eq!(color.harmony(HarmonyScheme::Complementary).len(), 2);
+      assert_eq!(color.harmony(HarmonyScheme::SplitComplementary).len(), 3);
+      assert_eq!(color.harmony(HarmonyScheme::Tetradic).len(), 4);
+    }
+
+    #[test]
+    fn it_clips_swatches_to_gamut() {
+      let swatches = Rgb::<Srgb>::new(0, 255, 0).harmony(HarmonyScheme::Tetradic);
+
+      assert!(swatches.iter().all(|swatch| swatch.is_in_gamut()));
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let swatches = Rgb::<Srgb>::new(200, 80, 60)
+        .with_alpha(0.5)
+        .harmony(HarmonyScheme::Triadic);
+
+      assert!(swatches.iter().all(|swatch| swatch.alpha() == 0.5));
+    }
+  }
+}
@@ -0,0 +1,71 @@
+/// The direction hue takes around the color wheel when interpolating between two hues.
+///
+/// Mirrors the CSS Color Level 4 `<hue-interpolation-method>` keywords. [`Shorter`](Self::Shorter)
+/// is the default used by `mix` and `gradient`; the others are selected through
+/// `gradient_with_arc` on the cylindrical spaces that support it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HueArc {
+  /// Always decrease the hue, wrapping through 0° as needed.
+  Decreasing,
+  /// Always increase the hue, wrapping through 360° as needed.
+  Increasing,
+  /// Take the arc longer than or equal to 180°.
+  Longer,
+  /// Take the arc shorter than or equal to 180°.
+  #[default]
+  Shorter,
+}
+
+impl HueArc {
+  /// Returns the signed hue change in degrees from `from` to `to` along this arc.
+  pub(crate) fn delta(&self, from: f64, to: f64) -> f64 {
+    let diff = to - from;
+
+    match self {
+      Self::Decreasing if diff > 0.0 => diff - 360.0,
+      Self::Increasing if diff < 0.0 => diff + 360.0,
+      Self::Longer if diff > 0.0 && diff < 180.0 => diff - 360.0,
+      Self::Longer if diff > -180.0 && diff <= 0.0 => diff + 360.0,
+      Self::Shorter if diff > 180.0 => diff - 360.0,
+      Self::Shorter if diff < -180.0 => diff + 360.0,
+      _ => diff,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod delta {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_takes_the_short_way_for_shorter() {
+      assert_eq!(HueArc::Shorter.delta(10.0, 350.0), -20.0);
+      assert_eq!(HueArc::Shorter.delta(350.0, 10.0), 20.0);
+      assert_eq!(HueArc::Shorter.delta(10.0, 50.0), 40.0);
+    }
+
+    #[test]
+    fn it_takes_the_long_way_for_longer() {
+      assert_eq!(HueArc::Longer.delta(10.0, 350.0), 340.0);
+      assert_eq!(HueArc::Longer.delta(350.0, 10.0), -340.0);
+      assert_eq!(HueArc::Longer.delta(10.0, 50.0), -320.0);
+    }
+
+    #[test]
+    fn it_always_increases_for_increasing() {
+      assert_eq!(HueArc::Increasing.delta(350.0, 10.0), 20.0);
+      assert_eq!(HueArc::Increasing.delta(10.0, 350.0), 340.0);
+    }
+
+    #[test]
+    fn it_always_decreases_for_decreasing() {
+      assert_eq!(HueArc::Decreasing.delta(10.0, 350.0), -20.0);
+      assert_eq!(HueArc::Decreasing.delta(350.0, 10.0), -340.0);
+    }
+  }
+}
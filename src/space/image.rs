@@ -0,0 +1,134 @@
+use super::{ColorSpace, LinearRgb, Rgb, Srgb};
+
+/// Quantizes a row of pixels to `bits_per_channel` with 1-D Floyd–Steinberg error diffusion.
+///
+/// Each pixel is quantized with [`Rgb::quantize`] and the residual error is carried into the
+/// next pixel, so smooth gradients dither into a pattern whose average matches the original
+/// rather than banding. The error is measured and propagated in linear light. With a single row
+/// the full error diffuses to the right-hand neighbor. Alpha is left unchanged.
+///
+/// ```
+/// use farg::space::{Rgb, Srgb, dither_row};
+///
+/// let mut row = vec![Rgb::<Srgb>::new(128, 128, 128); 8];
+/// dither_row(&mut row, 1);
+///
+/// assert!(row.iter().any(|p| p.red() == 0));
+/// assert!(row.iter().any(|p| p.red() == 255));
+/// ```
+pub fn dither_row(pixels: &mut [Rgb<Srgb>], bits_per_channel: u8) {
+  let mut error = [0.0; 3];
+
+  for pixel in pixels.iter_mut() {
+    let [r, g, b] = pixel.to_linear().components();
+    let desired = [r + error[0], g + error[1], b + error[2]];
+    let quantized = LinearRgb::<Srgb>::from_normalized(
+      desired[0].clamp(0.0, 1.0),
+      desired[1].clamp(0.0, 1.0),
+      desired[2].clamp(0.0, 1.0),
+    )
+    .to_encoded()
+    .quantize(bits_per_channel)
+    .with_alpha(pixel.alpha());
+    let [qr, qg, qb] = quantized.to_linear().components();
+
+    error = [desired[0] - qr, desired[1] - qg, desired[2] - qb];
+    *pixel = quantized;
+  }
+}
+
+/// Averages a 2×2 block of sRGB pixels into one, as when building a mipmap level.
+///
+/// The channels are averaged in linear light and re-encoded, avoiding the darkening that naive
+/// averaging of gamma-encoded values produces at high-contrast edges. Alpha is averaged
+/// arithmetically.
+///
+/// ```
+/// use farg::space::{Rgb, Srgb, downsample_2x2};
+///
+/// let black = Rgb::<Srgb>::new(0, 0, 0);
+/// let white = Rgb::<Srgb>::new(255, 255, 255);
+///
+/// assert_eq!(downsample_2x2([black, white, white, black]).red(), 188);
+/// ```
+pub fn downsample_2x2(block: [Rgb<Srgb>; 4]) -> Rgb<Srgb> {
+  let [r, g, b, alpha] = block.iter().fold([0.0; 4], |acc, pixel| {
+    let [r, g, b] = pixel.to_linear().components();
+    [acc[0] + r, acc[1] + g, acc[2] + b, acc[3] + pixel.alpha()]
+  });
+
+  LinearRgb::<Srgb>::from_normalized(r / 4.0, g / 4.0, b / 4.0)
+    .to_encoded()
+    .with_alpha(alpha / 4.0)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod dither_row {
+    use super::*;
+
+    #[test]
+    fn it_tracks_a_ramp_in_linear_light_at_1_bit() {
+      let original: Vec<Rgb<Srgb>> = (0..=255).map(|v| Rgb::<Srgb>::new(v, v, v)).collect();
+      let mut dithered = original.clone();
+      dither_row(&mut dithered, 1);
+
+      assert!(dithered.iter().all(|p| p.red() == 0 || p.red() == 255));
+      assert!(dithered.iter().any(|p| p.red() == 0));
+      assert!(dithered.iter().any(|p| p.red() == 255));
+
+      for (source, result) in original.chunks(64).zip(dithered.chunks(64)) {
+        let mean = |row: &[Rgb<Srgb>]| row.iter().map(|p| p.to_linear().r()).sum::<f64>() / row.len() as f64;
+        assert!((mean(source) - mean(result)).abs() < 0.05);
+      }
+    }
+
+    #[test]
+    fn it_leaves_already_quantized_pixels_unchanged() {
+      let mut row = vec![Rgb::<Srgb>::new(0, 255, 0), Rgb::<Srgb>::new(255, 0, 255)];
+      let expected = row.clone();
+      dither_row(&mut row, 1);
+
+      assert_eq!(row, expected);
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let mut row = vec![Rgb::<Srgb>::new(100, 150, 200).with_alpha(0.5); 4];
+      dither_row(&mut row, 2);
+
+      assert!(row.iter().all(|p| p.alpha() == 0.5));
+    }
+  }
+
+  mod downsample_2x2 {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_preserves_uniform_mid_gray() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128);
+
+      assert_eq!(downsample_2x2([gray; 4]).to_hex(), "#808080");
+    }
+
+    #[test]
+    fn it_averages_black_and_white_in_linear_light() {
+      let black = Rgb::<Srgb>::new(0, 0, 0);
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+
+      assert_eq!(downsample_2x2([black, white, black, white]).to_hex(), "#bcbcbc");
+    }
+
+    #[test]
+    fn it_averages_alpha() {
+      let opaque = Rgb::<Srgb>::new(10, 20, 30);
+      let clear = opaque.with_alpha(0.0);
+
+      assert_eq!(downsample_2x2([opaque, clear, opaque, clear]).alpha(), 0.5);
+    }
+  }
+}
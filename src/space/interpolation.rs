@@ -0,0 +1,385 @@
+#[cfg(feature = "space-hsl")]
+use super::Hsl;
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+use super::HueArc;
+#[cfg(feature = "space-lab")]
+use super::Lab;
+#[cfg(feature = "space-oklab")]
+use super::Oklab;
+#[cfg(feature = "space-oklch")]
+use super::Oklch;
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+use super::RgbSpec;
+use super::{ColorSpace, Rgb, Srgb, Xyz};
+use crate::component::Component;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Chroma (or saturation) below which a color is achromatic and its hue is powerless.
+pub(crate) const ACHROMATIC_THRESHOLD: f64 = 1e-4;
+
+/// The color space in which [`gradient_in`] interpolates between its endpoints.
+///
+/// Different gradients look best in different spaces: perceptual spaces keep lightness steps
+/// even, linear light matches physical mixing, and gamma-encoded sRGB matches naive CSS and
+/// image-editor blends.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum InterpolationSpace {
+  /// Cylindrical HSL with shortest-arc hue interpolation.
+  #[cfg(feature = "space-hsl")]
+  Hsl,
+  /// Rectangular CIE L\*a\*b\*, interpolating the opponent a\*/b\* channels.
+  #[cfg(feature = "space-lab")]
+  Lab,
+  /// Linear-light sRGB, matching additive mixing of light.
+  LinearSrgb,
+  /// Rectangular Oklab, interpolating the opponent a/b channels.
+  #[cfg(feature = "space-oklab")]
+  Oklab,
+  /// Cylindrical Oklch with shortest-arc hue interpolation.
+  #[cfg(feature = "space-oklch")]
+  Oklch,
+  /// Gamma-encoded sRGB, interpolating the stored channel values directly.
+  #[default]
+  Srgb,
+}
+
+/// Generates `steps` evenly-spaced sRGB colors from `a` to `b`, interpolated in `space`.
+///
+/// The result includes both endpoints. When `steps` is 0 the result is empty, and when `steps`
+/// is 1 it contains only `a`. Rectangular spaces interpolate their opponent channels directly,
+/// while cylindrical spaces take the shortest arc around the hue circle, ignoring the hue of
+/// achromatic endpoints. Alpha is interpolated linearly. Results are not gamut mapped.
+///
+/// ```
+/// use farg::space::{InterpolationSpace, Rgb, Srgb, gradient_in};
+///
+/// let black = Rgb::<Srgb>::new(0, 0, 0);
+/// let white = Rgb::<Srgb>::new(255, 255, 255);
+///
+/// let ramp = gradient_in(black, white, 3, InterpolationSpace::Srgb);
+/// assert!((ramp[1].r() - 0.5).abs() < 1e-6);
+///
+/// let ramp = gradient_in(black, white, 3, InterpolationSpace::LinearSrgb);
+/// assert_eq!(ramp[1].to_hex(), "#bcbcbc");
+/// ```
+pub fn gradient_in(a: impl Into<Xyz>, b: impl Into<Xyz>, steps: usize, space: InterpolationSpace) -> Vec<Rgb<Srgb>> {
+  if steps == 0 {
+    return Vec::new();
+  }
+
+  let a = a.into();
+  let b = b.into();
+  let divisor = steps.saturating_sub(1).max(1) as f64;
+
+  (0..steps)
+    .map(|i| interpolate(a, b, i as f64 / divisor, space))
+    .collect()
+}
+
+/// Interpolates a single color between `a` and `b` at parameter `t` in `space`.
+fn interpolate(a: Xyz, b: Xyz, t: f64, space: InterpolationSpace) -> Rgb<Srgb> {
+  match space {
+    #[cfg(feature = "space-hsl")]
+    InterpolationSpace::Hsl => {
+      let from = Hsl::<Srgb>::from(a);
+      let to = Hsl::<Srgb>::from(b);
+      let h = mix_hue(from.hue(), from.s(), to.hue(), to.s(), t);
+      let s = Component::new(from.saturation()).lerp(to.saturation(), t);
+      let l = Component::new(from.lightness()).lerp(to.lightness(), t);
+      let alpha = Component::new(from.alpha()).lerp(to.alpha(), t);
+
+      Hsl::<Srgb>::new(h, s, l).with_alpha(alpha).to_rgb::<Srgb>()
+    }
+    #[cfg(feature = "space-lab")]
+    InterpolationSpace::Lab => Lab::from(a).mix(b, t).to_rgb::<Srgb>(),
+    InterpolationSpace::LinearSrgb => Rgb::<Srgb>::from(a).mix_linear(b, t),
+    #[cfg(feature = "space-oklab")]
+    InterpolationSpace::Oklab => Oklab::from(a).mix(b, t).to_rgb::<Srgb>(),
+    #[cfg(feature = "space-oklch")]
+    InterpolationSpace::Oklch => Oklch::from(a).mix(b, t).to_rgb::<Srgb>(),
+    InterpolationSpace::Srgb => {
+      let from = Rgb::<Srgb>::from(a);
+      let to = Rgb::<Srgb>::from(b);
+      let [r, g, b] = [
+        Component::new(from.r()).lerp(to.r(), t),
+        Component::new(from.g()).lerp(to.g(), t),
+        Component::new(from.b()).lerp(to.b(), t),
+      ];
+      let alpha = Component::new(from.alpha()).lerp(to.alpha(), t);
+
+      Rgb::<Srgb>::from_normalized(r, g, b).with_alpha(alpha)
+    }
+  }
+}
+
+/// Interpolates a hue in degrees from `h1` toward `h2` along the shortest arc at parameter `t`.
+///
+/// `c1` and `c2` are the chroma (or saturation) of each endpoint. An achromatic endpoint's hue is
+/// powerless, so the other endpoint's hue is returned unchanged; when both are achromatic the
+/// result is 0. This follows the CSS Color Level 4 rules for hue interpolation. `t` is not
+/// clamped, so values outside 0.0-1.0 extrapolate along the same arc. The result is normalized to
+/// 0-360°.
+///
+/// ```
+/// use farg::space::mix_hue;
+///
+/// assert_eq!(mix_hue(350.0, 0.1, 30.0, 0.1, 0.5), 10.0);
+/// assert_eq!(mix_hue(90.0, 0.0, 200.0, 0.1, 0.5), 200.0);
+/// ```
+pub fn mix_hue(h1: f64, c1: f64, h2: f64, c2: f64, t: f64) -> f64 {
+  if let Some(hue) = powerless_hue(h1, c1, h2, c2) {
+    return hue;
+  }
+
+  let mut diff = h2 - h1;
+  if diff > 180.0 {
+    diff -= 360.0;
+  } else if diff < -180.0 {
+    diff += 360.0;
+  }
+
+  (h1 + diff * t).rem_euclid(360.0)
+}
+
+/// Interpolates hue along the given arc, handling achromatic endpoints as in [`mix_hue`].
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+pub(crate) fn mix_hue_with_arc(h1: f64, c1: f64, h2: f64, c2: f64, t: f64, arc: HueArc) -> f64 {
+  if let Some(hue) = powerless_hue(h1, c1, h2, c2) {
+    return hue;
+  }
+
+  (h1 + arc.delta(h1, h2) * t).rem_euclid(360.0)
+}
+
+/// Number of bisection steps used when searching for the maximum in-gamut chroma.
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+const MAX_CHROMA_SEARCH_ITERATIONS: usize = 48;
+
+/// Returns the largest chroma for which `candidate` builds a color inside the gamut of `S`.
+///
+/// The upper bound starts at `start` and is doubled until the candidate leaves the gamut, then
+/// bisected, so the result is always in gamut. Returns 0.0 when even the achromatic candidate
+/// is out of gamut.
+#[cfg(any(feature = "space-lch", feature = "space-oklch"))]
+pub(crate) fn max_chroma_in_gamut<const N: usize, C, S>(start: f64, candidate: impl Fn(f64) -> C) -> f64
+where
+  C: ColorSpace<N>,
+  S: RgbSpec,
+{
+  let fits = |c: f64| candidate(c).is_in_gamut::<S>();
+  if !fits(0.0) {
+    return 0.0;
+  }
+
+  let mut low = 0.0;
+  let mut high = start;
+  while fits(high) {
+    low = high;
+    high *= 2.0;
+  }
+
+  for _ in 0..MAX_CHROMA_SEARCH_ITERATIONS {
+    let mid = (low + high) / 2.0;
+    if fits(mid) {
+      low = mid;
+    } else {
+      high = mid;
+    }
+  }
+
+  low
+}
+
+/// Returns the resolved hue when either endpoint is achromatic, or `None` when both carry hue.
+fn powerless_hue(h1: f64, c1: f64, h2: f64, c2: f64) -> Option<f64> {
+  match (c1 < ACHROMATIC_THRESHOLD, c2 < ACHROMATIC_THRESHOLD) {
+    (true, true) => Some(0.0),
+    (true, false) => Some(h2),
+    (false, true) => Some(h1),
+    (false, false) => None,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod gradient_in {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_empty_for_zero_steps() {
+      let black = Rgb::<Srgb>::new(0, 0, 0);
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+
+      assert!(gradient_in(black, white, 0, InterpolationSpace::Srgb).is_empty());
+    }
+
+    #[test]
+    fn it_returns_only_the_start_for_one_step() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let blue = Rgb::<Srgb>::new(0, 0, 255);
+      let ramp = gradient_in(red, blue, 1, InterpolationSpace::Srgb);
+
+      assert_eq!(ramp.len(), 1);
+      assert_eq!(ramp[0].to_hex(), "#ff0000");
+    }
+
+    #[test]
+    fn it_includes_both_endpoints() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let blue = Rgb::<Srgb>::new(0, 0, 255);
+      let ramp = gradient_in(red, blue, 5, InterpolationSpace::LinearSrgb);
+
+      assert_eq!(ramp.len(), 5);
+      assert_eq!(ramp[0].to_hex(), "#ff0000");
+      assert_eq!(ramp[4].to_hex(), "#0000ff");
+    }
+
+    #[test]
+    fn it_interpolates_encoded_channels_in_srgb() {
+      let black = Rgb::<Srgb>::new(0, 0, 0);
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let ramp = gradient_in(black, white, 3, InterpolationSpace::Srgb);
+
+      for channel in ramp[1].components() {
+        assert!((channel - 0.5).abs() < 1e-6);
+      }
+    }
+
+    #[cfg(feature = "space-oklab")]
+    #[test]
+    fn it_differs_between_srgb_and_oklab_at_the_midpoint() {
+      let black = Rgb::<Srgb>::new(0, 0, 0);
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let srgb = gradient_in(black, white, 3, InterpolationSpace::Srgb);
+      let oklab = gradient_in(black, white, 3, InterpolationSpace::Oklab);
+
+      assert!((srgb[1].r() - oklab[1].r()).abs() > 0.05);
+    }
+
+    #[cfg(feature = "space-lab")]
+    #[test]
+    fn it_interpolates_opponent_channels_in_lab() {
+      let black = Rgb::<Srgb>::new(0, 0, 0);
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let ramp = gradient_in(black, white, 3, InterpolationSpace::Lab);
+
+      assert!((Lab::from(ramp[1].to_xyz()).l() - 50.0).abs() < 0.5);
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_takes_the_shortest_hue_arc_in_oklch() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let magenta = Rgb::<Srgb>::new(255, 0, 255);
+      let start = red.to_oklch().hue();
+      let end = magenta.to_oklch().hue();
+      let mid = gradient_in(red, magenta, 3, InterpolationSpace::Oklch)[1]
+        .to_oklch()
+        .hue();
+
+      assert!(mid < start || mid > end);
+    }
+
+    #[cfg(feature = "space-hsl")]
+    #[test]
+    fn it_takes_the_shortest_hue_arc_in_hsl() {
+      let red = Hsl::<Srgb>::new(350.0, 100.0, 50.0);
+      let orange = Hsl::<Srgb>::new(30.0, 100.0, 50.0);
+      let mid = gradient_in(red, orange, 3, InterpolationSpace::Hsl)[1];
+
+      assert!((Hsl::<Srgb>::from(mid.to_xyz()).hue() - 10.0).abs() < 1.0);
+    }
+
+    #[cfg(feature = "space-hsl")]
+    #[test]
+    fn it_ignores_the_hue_of_gray_endpoints_in_hsl() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128);
+      let blue = Hsl::<Srgb>::new(240.0, 100.0, 50.0);
+      let mid = gradient_in(gray, blue, 3, InterpolationSpace::Hsl)[1];
+
+      assert!((Hsl::<Srgb>::from(mid.to_xyz()).hue() - 240.0).abs() < 1.0);
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod max_chroma_in_gamut {
+    use super::*;
+
+    #[test]
+    fn it_finds_the_srgb_boundary() {
+      let base = Oklch::new(0.6, 0.0, 30.0);
+      let max = max_chroma_in_gamut::<3, _, Srgb>(0.5, |c| base.with_c(c));
+
+      assert!(base.with_c(max).is_in_gamut::<Srgb>());
+      assert!(!base.with_c(max + 1e-6).is_in_gamut::<Srgb>());
+    }
+
+    #[test]
+    fn it_grows_past_a_small_start() {
+      let base = Oklch::new(0.6, 0.0, 30.0);
+      let expected = max_chroma_in_gamut::<3, _, Srgb>(0.5, |c| base.with_c(c));
+      let max = max_chroma_in_gamut::<3, _, Srgb>(0.01, |c| base.with_c(c));
+
+      assert!((max - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_returns_zero_when_the_achromatic_candidate_is_out_of_gamut() {
+      let max = max_chroma_in_gamut::<3, _, Srgb>(0.5, |c| Oklch::new(1.5, c, 30.0));
+
+      assert_eq!(max, 0.0);
+    }
+  }
+
+  mod mix_hue {
+    use super::*;
+
+    const EPSILON: f64 = 1e-6;
+
+    #[test]
+    fn it_takes_the_shortest_arc_forward() {
+      assert!((mix_hue(10.0, 0.1, 50.0, 0.1, 0.5) - 30.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_takes_the_shortest_arc_across_zero() {
+      assert!(mix_hue(350.0, 0.1, 10.0, 0.1, 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_takes_the_shortest_arc_backward() {
+      assert!(mix_hue(10.0, 0.1, 350.0, 0.1, 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_returns_zero_when_both_are_achromatic() {
+      assert!(mix_hue(90.0, 0.0, 270.0, 0.0, 0.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_uses_the_second_hue_when_the_first_is_achromatic() {
+      assert!((mix_hue(90.0, 0.0, 200.0, 0.1, 0.5) - 200.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_uses_the_first_hue_when_the_second_is_achromatic() {
+      assert!((mix_hue(90.0, 0.1, 200.0, 0.0, 0.5) - 90.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_interpolates_within_the_same_hemisphere() {
+      assert!((mix_hue(100.0, 0.1, 140.0, 0.1, 0.5) - 120.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn it_extrapolates_beyond_the_endpoints() {
+      assert!((mix_hue(10.0, 0.1, 50.0, 0.1, 1.5) - 70.0).abs() < EPSILON);
+      assert!((mix_hue(10.0, 0.1, 50.0, 0.1, -0.5) - 350.0).abs() < EPSILON);
+    }
+  }
+}
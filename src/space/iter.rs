@@ -0,0 +1,85 @@
+//! Iterator adaptors for streams of colors.
+//!
+//! Not re-exported from [`space`](super), so importing `farg::space::*` does not bring these
+//! methods into scope alongside other iterator extension traits.
+
+use std::iter::Map;
+
+use super::Xyz;
+
+/// Iterator adaptor for converting a stream of colors from one space into another.
+///
+/// Implemented for every [`Iterator`] whose items are colors (convert into [`Xyz`]), so they can
+/// be converted with [`into_colors`](Self::into_colors) instead of `.map(Into::into)`.
+///
+/// ```
+/// use farg::space::{Rgb, Srgb, Xyz, iter::ColorIterator};
+///
+/// let pixels = vec![Rgb::<Srgb>::new(255, 0, 0), Rgb::<Srgb>::new(0, 0, 255)];
+/// let xyz: Vec<Xyz> = pixels.iter().copied().into_colors::<Xyz>().collect();
+///
+/// assert_eq!(xyz.len(), 2);
+/// ```
+pub trait ColorIterator: Iterator + Sized {
+  /// Converts each item into `B` using its [`Into`] implementation.
+  fn into_colors<B>(self) -> Map<Self, fn(Self::Item) -> B>
+  where
+    Self::Item: Into<B>,
+  {
+    self.map(Into::into)
+  }
+}
+
+impl<I> ColorIterator for I
+where
+  I: Iterator,
+  I::Item: Into<Xyz>,
+{
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::space::{Rgb, Srgb, Xyz};
+
+  mod into_colors {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_converts_each_item() {
+      let pixels = [Rgb::<Srgb>::new(255, 0, 0), Rgb::<Srgb>::new(0, 255, 0)];
+      let xyz: Vec<Xyz> = pixels.iter().copied().into_colors::<Xyz>().collect();
+
+      assert_eq!(xyz, pixels.iter().map(|p| p.to_xyz()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_yields_nothing_for_an_empty_iterator() {
+      let pixels: Vec<Rgb<Srgb>> = Vec::new();
+
+      assert_eq!(pixels.into_iter().into_colors::<Xyz>().count(), 0);
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_round_trips_rgb_through_oklch() {
+      use crate::space::Oklch;
+
+      let pixels: Vec<Rgb<Srgb>> = (0..=255)
+        .step_by(15)
+        .map(|v| Rgb::<Srgb>::new(v, 255 - v, v / 2))
+        .collect();
+      let oklch: Vec<Oklch> = pixels.iter().copied().into_colors::<Oklch>().collect();
+      let back: Vec<Rgb<Srgb>> = oklch.into_iter().into_colors::<Rgb<Srgb>>().collect();
+
+      assert_eq!(back.len(), pixels.len());
+      for (original, converted) in pixels.iter().zip(&back) {
+        assert!(converted.red().abs_diff(original.red()) <= 1);
+        assert!(converted.green().abs_diff(original.green()) <= 1);
+        assert!(converted.blue().abs_diff(original.blue()) <= 1);
+      }
+    }
+  }
+}
@@ -0,0 +1,222 @@
+use super::{ColorSpace, Oklab, Rgb, Srgb};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Returns the weighted average of the given colors, computed in Oklab.
+///
+/// Each color is converted to Oklab and its L, a, and b (and alpha) are averaged using the
+/// weights normalized by their sum. Averaging in a perceptual space avoids the dark, muddy
+/// results of averaging encoded RGB values.
+///
+/// Returns `None` if `colors` is empty or the weights sum to zero.
+///
+/// ```
+/// use farg::space::{Rgb, Srgb, average};
+///
+/// let red = Rgb::<Srgb>::new(255, 0, 0);
+/// let blue = Rgb::<Srgb>::new(0, 0, 255);
+///
+/// let mean = average(&[(red, 1.0), (blue, 1.0)]).unwrap();
+/// assert!(mean.l() > 0.0);
+/// assert!(average::<Rgb<Srgb>>(&[]).is_none());
+/// ```
+pub fn average<C>(colors: &[(C, f64)]) -> Option<Oklab>
+where
+  C: ColorSpace<3>,
+{
+  let total_weight: f64 = colors.iter().map(|(_, weight)| weight).sum();
+
+  if colors.is_empty() || total_weight == 0.0 {
+    return None;
+  }
+
+  let [l, a, b, alpha] = colors.iter().fold([0.0; 4], |acc, (color, weight)| {
+    let oklab = color.to_oklab();
+    [
+      acc[0] + oklab.l() * weight,
+      acc[1] + oklab.a() * weight,
+      acc[2] + oklab.b() * weight,
+      acc[3] + oklab.alpha() * weight,
+    ]
+  });
+
+  Some(Oklab::new(l / total_weight, a / total_weight, b / total_weight).with_alpha(alpha / total_weight))
+}
+
+/// Remaps the lightness of every color in a palette through `curve`, preserving hue relationships.
+///
+/// Each color is converted to Oklch, `curve` is applied to its lightness (0.0-1.0), and the result
+/// is converted back with chroma and hue unchanged, clipping to the sRGB gamut. Useful for
+/// deriving a consistent dark theme from a light one (e.g. `|l| 1.0 - l`).
+///
+/// ```
+/// use farg::space::{Rgb, Srgb, remap_lightness};
+///
+/// let palette = [Rgb::<Srgb>::new(240, 240, 240), Rgb::<Srgb>::new(30, 30, 30)];
+/// let dark = remap_lightness(&palette, |l| 1.0 - l);
+///
+/// assert!(dark[0].red() < dark[1].red());
+/// ```
+#[cfg(feature = "space-oklch")]
+pub fn remap_lightness(palette: &[Rgb<Srgb>], curve: impl Fn(f64) -> f64) -> Vec<Rgb<Srgb>> {
+  palette
+    .iter()
+    .map(|color| {
+      let oklch = color.to_oklab().to_oklch();
+      let mut remapped = oklch.with_l(curve(oklch.l())).to_rgb::<Srgb>();
+      remapped.clip_to_gamut();
+      remapped
+    })
+    .collect()
+}
+
+/// Sorts a palette in place from darkest to lightest by perceived (Oklab) lightness.
+///
+/// Uses [`Rgb::perceived_lightness`], which orders colors the way they look rather than by
+/// HSL lightness. The sort is stable, so colors of equal lightness keep their relative order.
+///
+/// ```
+/// use farg::space::{Rgb, Srgb, sort_by_lightness};
+///
+/// let mut palette = [Rgb::<Srgb>::new(255, 255, 0), Rgb::<Srgb>::new(0, 0, 255)];
+/// sort_by_lightness(&mut palette);
+///
+/// assert_eq!(palette[0], Rgb::<Srgb>::new(0, 0, 255));
+/// ```
+pub fn sort_by_lightness(palette: &mut [Rgb<Srgb>]) {
+  palette.sort_by(|a, b| a.perceived_lightness().total_cmp(&b.perceived_lightness()));
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod average {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_none_for_empty_input() {
+      assert_eq!(average::<Rgb<Srgb>>(&[]), None);
+    }
+
+    #[test]
+    fn it_returns_none_for_zero_total_weight() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let green = Rgb::<Srgb>::new(0, 255, 0);
+
+      assert_eq!(average(&[(red, 0.0), (green, 0.0)]), None);
+    }
+
+    #[test]
+    fn it_lands_between_equally_weighted_colors() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let green = Rgb::<Srgb>::new(0, 255, 0);
+      let red_oklab = ColorSpace::to_oklab(&red);
+      let green_oklab = ColorSpace::to_oklab(&green);
+      let mean = average(&[(red, 1.0), (green, 1.0)]).unwrap();
+
+      assert!((mean.l() - (red_oklab.l() + green_oklab.l()) / 2.0).abs() < 1e-10);
+      assert!((mean.a() - (red_oklab.a() + green_oklab.a()) / 2.0).abs() < 1e-10);
+      assert!((mean.b() - (red_oklab.b() + green_oklab.b()) / 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_returns_single_color_regardless_of_weight() {
+      let color = Rgb::<Srgb>::new(64, 128, 192);
+      let expected = ColorSpace::to_oklab(&color);
+
+      for weight in [0.25, 1.0, 42.0] {
+        let mean = average(&[(color, weight)]).unwrap();
+
+        assert!((mean.l() - expected.l()).abs() < 1e-10);
+        assert!((mean.a() - expected.a()).abs() < 1e-10);
+        assert!((mean.b() - expected.b()).abs() < 1e-10);
+      }
+    }
+
+    #[test]
+    fn it_respects_weights() {
+      let red = Rgb::<Srgb>::new(255, 0, 0);
+      let green = Rgb::<Srgb>::new(0, 255, 0);
+      let red_oklab = ColorSpace::to_oklab(&red);
+      let green_oklab = ColorSpace::to_oklab(&green);
+      let mean = average(&[(red, 3.0), (green, 1.0)]).unwrap();
+
+      assert!((mean.a() - (3.0 * red_oklab.a() + green_oklab.a()) / 4.0).abs() < 1e-10);
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod remap_lightness {
+    use super::*;
+
+    #[test]
+    fn it_returns_colors_within_one_unit_for_identity_curve() {
+      let palette = [
+        Rgb::<Srgb>::new(255, 0, 0),
+        Rgb::<Srgb>::new(12, 150, 220),
+        Rgb::<Srgb>::new(240, 200, 40),
+        Rgb::<Srgb>::new(128, 128, 128),
+        Rgb::<Srgb>::new(0, 0, 0),
+        Rgb::<Srgb>::new(255, 255, 255),
+      ];
+      let remapped = remap_lightness(&palette, |l| l);
+
+      assert_eq!(remapped.len(), palette.len());
+
+      for (original, result) in palette.iter().zip(&remapped) {
+        assert!((original.red() as i16 - result.red() as i16).abs() <= 1);
+        assert!((original.green() as i16 - result.green() as i16).abs() <= 1);
+        assert!((original.blue() as i16 - result.blue() as i16).abs() <= 1);
+      }
+    }
+
+    #[test]
+    fn it_inverts_lightness_order() {
+      let palette = [Rgb::<Srgb>::new(230, 230, 250), Rgb::<Srgb>::new(20, 20, 60)];
+      let remapped = remap_lightness(&palette, |l| 1.0 - l);
+
+      assert!(remapped[0].to_oklab().l() < remapped[1].to_oklab().l());
+    }
+
+    #[test]
+    fn it_clips_results_to_gamut() {
+      let palette = [Rgb::<Srgb>::new(0, 0, 255)];
+      let remapped = remap_lightness(&palette, |_| 0.95);
+
+      assert!(remapped[0].is_in_gamut());
+    }
+
+    #[test]
+    fn it_returns_empty_for_empty_palette() {
+      assert!(remap_lightness(&[], |l| l).is_empty());
+    }
+  }
+
+  mod sort_by_lightness {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_restores_a_shuffled_grayscale_ramp() {
+      let ramp: Vec<Rgb<Srgb>> = (0..=255).step_by(17).map(|v| Rgb::<Srgb>::new(v, v, v)).collect();
+      let mut shuffled: Vec<Rgb<Srgb>> = (0..ramp.len()).map(|i| ramp[(i * 7) % ramp.len()]).collect();
+      assert_ne!(shuffled, ramp);
+
+      sort_by_lightness(&mut shuffled);
+
+      assert_eq!(shuffled, ramp);
+    }
+
+    #[test]
+    fn it_handles_an_empty_palette() {
+      let mut palette: [Rgb<Srgb>; 0] = [];
+      sort_by_lightness(&mut palette);
+
+      assert!(palette.is_empty());
+    }
+  }
+}
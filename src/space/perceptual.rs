@@ -9,7 +9,7 @@ mod okhsv;
 #[cfg(feature = "space-okhwb")]
 mod okhwb;
 #[cfg(feature = "space-oklab")]
-mod oklab;
+pub mod oklab;
 #[cfg(feature = "space-oklch")]
 mod oklch;
 
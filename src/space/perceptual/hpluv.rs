@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -15,6 +17,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -34,7 +38,7 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
   space::{ColorSpace, Lchuv, Lms, Luv, Rgb, RgbSpec, Srgb, Xyz, cie::lchuv::max_safe_chroma_for_l},
 };
@@ -63,9 +67,7 @@ pub struct Hpluv {
 
 impl Hpluv {
   /// The default viewing context for Hpluv (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new HPLuv color from hue (0-360°), saturation (0-100%), and lightness (0-100%).
   pub fn new(h: impl Into<Component>, s: impl Into<Component>, l: impl Into<Component>) -> Self {
@@ -91,6 +93,15 @@ impl Hpluv {
     }
   }
 
+  /// Returns the maximum CIE LCh(uv) chroma that stays inside the sRGB gamut at lightness `l` (0-100)
+  /// for every hue.
+  ///
+  /// This is the radius HPLuv saturation is scaled against. Unlike HSLuv it does not depend on hue,
+  /// so 100% saturation is in gamut but generally falls short of the sRGB boundary.
+  pub fn max_chroma_for_l(l: f64) -> f64 {
+    max_safe_chroma_for_l(l)
+  }
+
   /// Returns the [H, S, L] components as normalized values (all 0.0-1.0).
   pub fn components(&self) -> [f64; 3] {
     [self.h.0, self.s.0, self.l.0]
@@ -728,6 +739,13 @@ impl From<Xyz> for Hpluv {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Hpluv {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Hpluv"], Lchuv::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Hpluv
 where
   T: Into<Self>,
@@ -1130,6 +1148,30 @@ mod test {
     }
   }
 
+  mod max_chroma_for_l {
+    use super::*;
+
+    #[test]
+    fn it_matches_the_chroma_of_full_saturation_for_every_hue() {
+      for h in [0.0, 90.0, 180.0, 270.0] {
+        let lchuv = Hpluv::new(h, 100.0, 60.0).to_lchuv();
+
+        assert!((lchuv.chroma() - Hpluv::max_chroma_for_l(60.0)).abs() < 1e-9);
+      }
+    }
+
+    #[test]
+    fn it_stays_inside_srgb_for_every_hue() {
+      let max = Hpluv::max_chroma_for_l(50.0);
+
+      for h in (0..360).step_by(15) {
+        let [r, g, b] = Lchuv::new(50.0, max, h as f64).to_xyz().to_rgb::<Srgb>().components();
+
+        assert!([r, g, b].iter().all(|c| (-1e-9..=1.0 + 1e-9).contains(c)));
+      }
+    }
+  }
+
   mod mul {
     use super::*;
 
@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -15,6 +17,8 @@ use crate::space::Hsi;
 use crate::space::Hsl;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -34,7 +38,7 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
   space::{ColorSpace, Lchuv, Lms, Luv, Rgb, RgbSpec, Srgb, Xyz, cie::lchuv::max_safe_chroma_for_lh},
 };
@@ -62,9 +66,7 @@ pub struct Hsluv {
 
 impl Hsluv {
   /// The default viewing context for Hsluv (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new HSLuv color from hue (0-360°), saturation (0-100%), and lightness (0-100%).
   pub fn new(h: impl Into<Component>, s: impl Into<Component>, l: impl Into<Component>) -> Self {
@@ -90,6 +92,16 @@ impl Hsluv {
     }
   }
 
+  /// Returns the maximum CIE LCh(uv) chroma inside the sRGB gamut at lightness `l` (0-100) and hue
+  /// `h` (degrees).
+  ///
+  /// This is the gamut-boundary chroma that HSLuv saturation is scaled against: a color with 100%
+  /// saturation has exactly this chroma, so every HSLuv color lies inside sRGB and S=100 sits on
+  /// its edge.
+  pub fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+    max_safe_chroma_for_lh(l, h)
+  }
+
   /// Returns the [H, S, L] components as normalized values (all 0.0-1.0).
   pub fn components(&self) -> [f64; 3] {
     [self.h.0, self.s.0, self.l.0]
@@ -727,6 +739,13 @@ impl From<Xyz> for Hsluv {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Hsluv {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Hsluv"], Lchuv::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Hsluv
 where
   T: Into<Self>,
@@ -1149,6 +1168,27 @@ mod test {
     }
   }
 
+  mod max_chroma_for_lh {
+    use super::*;
+
+    #[test]
+    fn it_matches_the_chroma_of_full_saturation() {
+      for h in [0.0, 45.0, 120.0, 200.0, 280.0] {
+        let lchuv = Hsluv::new(h, 100.0, 60.0).to_lchuv();
+
+        assert!((lchuv.chroma() - Hsluv::max_chroma_for_lh(60.0, h)).abs() < 1e-9);
+      }
+    }
+
+    #[test]
+    fn it_leaves_srgb_just_beyond_the_boundary() {
+      let max = Hsluv::max_chroma_for_lh(50.0, 150.0);
+      let rgb = Lchuv::new(50.0, max * 1.05, 150.0).to_xyz().to_rgb::<Srgb>();
+
+      assert!(!rgb.is_in_gamut());
+    }
+  }
+
   mod mul {
     use super::*;
 
@@ -1351,6 +1391,16 @@ mod test {
       let _rgb: Rgb<Srgb> = hsluv.to_rgb();
     }
 
+    #[test]
+    fn it_keeps_full_saturation_on_the_srgb_boundary_without_clamping() {
+      for h in [0.0, 60.0, 120.0, 180.0, 240.0, 300.0] {
+        let [r, g, b] = Hsluv::new(h, 100.0, 50.0).to_xyz().to_rgb::<Srgb>().components();
+
+        assert!([r, g, b].iter().all(|c| (-1e-3..=1.0 + 1e-3).contains(c)));
+        assert!([r, g, b].iter().any(|c| c.abs() < 1e-3 || (c - 1.0).abs() < 1e-3));
+      }
+    }
+
     #[test]
     fn it_converts_white() {
       let hsluv = Hsluv::new(0.0, 0.0, 100.0);
@@ -4,6 +4,8 @@ use std::{
 };
 
 use super::oklab::{cusp_for_hue, max_chroma_at_lightness, toe_inv};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -18,6 +20,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -37,7 +41,7 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
   space::{ColorSpace, Lms, Oklab, Rgb, RgbSpec, Srgb, Xyz},
 };
@@ -60,9 +64,7 @@ pub struct Okhsl {
 
 impl Okhsl {
   /// The default viewing context for Okhsl (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new Okhsl color from hue (0-360°), saturation (0-100%), and lightness (0-100%).
   pub fn new(h: impl Into<Component>, s: impl Into<Component>, l: impl Into<Component>) -> Self {
@@ -717,6 +719,13 @@ impl From<Xyz> for Okhsl {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Okhsl {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Okhsl"], Oklab::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Okhsl
 where
   T: Into<Self>,
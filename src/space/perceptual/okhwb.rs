@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -34,7 +38,7 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
   space::{ColorSpace, Lms, Okhsv, Oklab, Rgb, RgbSpec, Srgb, Xyz},
 };
@@ -56,9 +60,7 @@ pub struct Okhwb {
 
 impl Okhwb {
   /// The default viewing context for Okhwb (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new Okhwb color from hue (0-360°), whiteness (0-100%), and blackness (0-100%).
   pub fn new(h: impl Into<Component>, w: impl Into<Component>, b: impl Into<Component>) -> Self {
@@ -244,12 +246,18 @@ impl Okhwb {
   /// Converts to the Okhsv perceptual color space.
   ///
   /// Uses the standard HWB-to-HSV reparameterization:
-  /// V = 1 - B, S = 1 - W/V (or 0 when V is zero).
+  /// V = 1 - B, S = 1 - W/V (or 0 when V is zero). When W + B >= 1, whiteness and blackness
+  /// are scaled down proportionally per the CSS HWB rules, producing an achromatic gray with
+  /// V = W / (W + B).
   pub fn to_okhsv(&self) -> Okhsv {
     let [h, w, b] = self.components();
 
-    let v = 1.0 - b;
-    let s = if v == 0.0 { 0.0 } else { 1.0 - (w / v) };
+    let (s, v) = if w + b >= 1.0 {
+      (0.0, w / (w + b))
+    } else {
+      let v = 1.0 - b;
+      (1.0 - (w / v), v)
+    };
 
     Okhsv::new(h * 360.0, s * 100.0, v * 100.0).with_alpha(self.alpha)
   }
@@ -260,6 +268,11 @@ impl Okhwb {
   }
 
   /// Converts to the specified RGB color space.
+  ///
+  /// Whiteness and blackness summing to 1.0 or more are normalized (see [`Self::to_okhsv`]), so
+  /// the result is a gray whose lightness depends only on W / (W + B) and not on hue. With
+  /// W = B = 0 the result is the most saturated color of the hue within the sRGB-derived Okhsv
+  /// gamut; in narrower target spaces it may fall outside the gamut.
   pub fn to_rgb<S>(&self) -> Rgb<S>
   where
     S: RgbSpec,
@@ -715,6 +728,13 @@ impl From<Xyz> for Okhwb {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Okhwb {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Okhwb"], Okhsv::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Okhwb
 where
   T: Into<Self>,
@@ -1328,6 +1348,15 @@ mod test {
       assert!(okhsv.s().abs() < 1e-10);
     }
 
+    #[test]
+    fn it_normalizes_whiteness_and_blackness_exceeding_one() {
+      let okhwb = Okhwb::new(90.0, 60.0, 60.0);
+      let okhsv = okhwb.to_okhsv();
+
+      assert!(okhsv.s().abs() < 1e-10);
+      assert!((okhsv.v() - 0.5).abs() < 1e-10);
+    }
+
     #[test]
     fn it_roundtrips_through_okhsv() {
       let original = Okhwb::new(210.0, 20.0, 40.0);
@@ -1400,6 +1429,39 @@ mod test {
       assert_eq!(rgb.blue(), 255);
     }
 
+    #[test]
+    fn it_converts_whiteness_plus_blackness_of_one_to_the_same_gray_for_any_hue() {
+      let expected = Okhwb::new(0.0, 40.0, 60.0).to_rgb::<Srgb>();
+
+      assert_eq!(expected.red(), expected.green());
+      assert_eq!(expected.green(), expected.blue());
+
+      for hue in [45.0, 120.0, 210.0, 300.0] {
+        let rgb = Okhwb::new(hue, 40.0, 60.0).to_rgb::<Srgb>();
+
+        assert_eq!(rgb, expected);
+      }
+    }
+
+    #[test]
+    fn it_scales_down_whiteness_and_blackness_exceeding_one() {
+      let scaled = Okhwb::new(150.0, 80.0, 120.0).to_rgb::<Srgb>();
+      let normalized = Okhwb::new(150.0, 40.0, 60.0).to_rgb::<Srgb>();
+
+      assert_eq!(scaled, normalized);
+    }
+
+    #[test]
+    fn it_converts_zero_whiteness_and_blackness_to_a_fully_saturated_color() {
+      for hue in [0.0, 60.0, 120.0, 180.0, 240.0, 300.0] {
+        let rgb = Okhwb::new(hue, 0.0, 0.0).to_rgb::<Srgb>();
+        let channels = [rgb.red(), rgb.green(), rgb.blue()];
+
+        assert!(*channels.iter().max().unwrap() >= 254);
+        assert!(*channels.iter().min().unwrap() <= 1);
+      }
+    }
+
     #[test]
     fn it_preserves_alpha() {
       let okhwb = Okhwb::new(120.0, 25.0, 25.0).with_alpha(0.7);
@@ -1,8 +1,15 @@
+//! The Oklab perceptual color space and its gamut helpers.
+//!
+//! Alongside [`Oklab`], this module exposes the toe function pair and the sRGB gamut cusp
+//! lookup that underpin [`Okhsl`](crate::space::Okhsl) and [`Okhsv`](crate::space::Okhsv),
+//! for building custom gamut-aware tools such as sliders.
 use std::{
   fmt::{Display, Formatter, Result as FmtResult},
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +24,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -38,10 +47,10 @@ use crate::space::Oklch;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
   matrix::Matrix3,
-  space::{ColorSpace, LinearRgb, Lms, Rgb, RgbSpec, Srgb, Xyz},
+  space::{ColorSpace, LinearRgb, Lms, PolarColor, Rgb, RgbSpec, Srgb, Xyz, polar::polar_coordinates},
 };
 
 /// Ok* toe-function constants.
@@ -65,9 +74,7 @@ pub struct Oklab {
 
 impl Oklab {
   /// The default viewing context for Oklab (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
   /// Matrix for converting cube-root LMS to Oklab L, a, b.
   pub const LINEAR_LMS_MATRIX: Matrix3 = Matrix3::new([
     [0.2104542553, 0.7936177850, -0.0040720468],
@@ -144,6 +151,33 @@ impl Oklab {
     self.l -= amount.into();
   }
 
+  /// Returns the Euclidean color difference ΔE_OK between this color and `other`.
+  ///
+  /// Oklab is designed so straight-line distance tracks perceived difference, making this a
+  /// cheap alternative to CIEDE2000. `other` is converted into Oklab and, when its reference
+  /// white differs, adapted into this color's viewing context first. A difference of roughly
+  /// 0.02 is about one just-noticeable difference.
+  ///
+  /// ```
+  /// use farg::space::Oklab;
+  ///
+  /// let a = Oklab::new(0.5, 0.0, 0.0);
+  /// let b = Oklab::new(0.5, 0.03, 0.04);
+  ///
+  /// assert!((a.delta_e_ok(b) - 0.05).abs() < 1e-12);
+  /// ```
+  pub fn delta_e_ok(&self, other: impl Into<Oklab>) -> f64 {
+    let mut other = other.into();
+    if other.context.reference_white() != self.context.reference_white() {
+      other = oklab_in_context(other.to_xyz(), self.context);
+    }
+    let dl = self.l.0 - other.l.0;
+    let da = self.a.0 - other.a.0;
+    let db = self.b.0 - other.b.0;
+
+    (dl * dl + da * da + db * db).sqrt()
+  }
+
   /// Generates a sequence of evenly-spaced colors between `self` and `other` in rectangular Oklab.
   ///
   /// Returns `steps` colors including both endpoints, interpolated directly in L/a/b
@@ -250,6 +284,23 @@ impl Oklab {
     self.l = l.into();
   }
 
+  /// Converts to CIE L\*a\*b\* (CIELAB); a named alias for [`ColorSpace::to_lab`].
+  ///
+  /// Oklab and CIELAB are distinct spaces, not two names for the same thing: Oklab's L runs
+  /// 0-1 and its a/b axes are derived from an LMS cone model, while CIELAB's L\* runs 0-100 and
+  /// its a\*/b\* axes come from the 1976 CIE formulas. The conversion goes through XYZ.
+  ///
+  /// ```
+  /// use farg::space::Oklab;
+  ///
+  /// let lab = Oklab::new(1.0, 0.0, 0.0).to_cielab();
+  /// assert!((lab.l() - 100.0).abs() < 0.01);
+  /// ```
+  #[cfg(feature = "space-lab")]
+  pub fn to_cielab(&self) -> Lab {
+    self.to_lab()
+  }
+
   /// Returns this color as a CSS Color Level 4 `oklab(...)` string.
   ///
   /// L is 0-1, a and b are signed values. Alpha is appended only when less
@@ -710,6 +761,21 @@ impl From<Okhwb> for Oklab {
   }
 }
 
+#[cfg(feature = "palette-interop")]
+impl From<palette::Oklab<f32>> for Oklab {
+  fn from(oklab: palette::Oklab<f32>) -> Self {
+    Self::new(oklab.l as f64, oklab.a as f64, oklab.b as f64)
+  }
+}
+
+#[cfg(feature = "palette-interop")]
+impl From<Oklab> for palette::Oklab<f32> {
+  fn from(oklab: Oklab) -> Self {
+    let [l, a, b] = oklab.components();
+    Self::new(l as f32, a as f32, b as f32)
+  }
+}
+
 #[cfg(feature = "space-oklch")]
 impl From<Oklch> for Oklab {
   fn from(oklch: Oklch) -> Self {
@@ -739,6 +805,13 @@ impl From<Xyz> for Oklab {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Oklab {
+  fn hub_path() -> Vec<&'static str> {
+    vec!["Oklab", "Xyz"]
+  }
+}
+
 impl<T> Mul<T> for Oklab
 where
   T: Into<Self>,
@@ -760,6 +833,16 @@ where
   }
 }
 
+impl PolarColor for Oklab {
+  fn hue_degrees(&self) -> f64 {
+    polar_coordinates(self.a.0, self.b.0).1
+  }
+
+  fn polar_chroma(&self) -> f64 {
+    polar_coordinates(self.a.0, self.b.0).0
+  }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Oklab {
   fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -804,12 +887,25 @@ impl TryFrom<String> for Oklab {
   }
 }
 
-/// Finds the cusp (L, C) for a given normalized hue (0.0-1.0).
+/// Finds the cusp (L, C) for a given normalized hue (0.0-1.0, i.e. degrees / 360).
 ///
-/// The cusp is the point of maximum chroma on the sRGB gamut boundary
-/// for the given hue.
-pub(crate) fn cusp_for_hue(h: f64) -> (f64, f64) {
-  let h_rad = h * 2.0 * std::f64::consts::PI;
+/// The cusp is the point of maximum chroma on the sRGB gamut boundary for the given hue. The
+/// returned `L` is the Oklab lightness (0.0-1.0) at which that maximum occurs, and `C` is the
+/// Oklab chroma there. Together with black (0, 0) and white (1, 0) it forms the triangle that
+/// approximates the gamut slice for the hue.
+///
+/// ```
+/// use farg::space::oklab::cusp_for_hue;
+///
+/// for step in 0..360 {
+///   let (l, c) = cusp_for_hue(step as f64 / 360.0);
+///
+///   assert!(c > 0.0);
+///   assert!(l > 0.0 && l < 1.0);
+/// }
+/// ```
+pub fn cusp_for_hue(hue_fraction: f64) -> (f64, f64) {
+  let h_rad = hue_fraction * 2.0 * std::f64::consts::PI;
   let a = h_rad.cos();
   let b = h_rad.sin();
 
@@ -839,13 +935,24 @@ pub(crate) fn max_chroma_at_lightness(cusp: (f64, f64), l: f64) -> f64 {
 
 /// Maps Oklab lightness to Ok* perceived lightness via the toe function.
 ///
-/// Improves perceptual uniformity at the dark end of the lightness range.
-pub(crate) fn toe(x: f64) -> f64 {
+/// Improves perceptual uniformity at the dark end of the lightness range. This is the
+/// lightness used by [`Okhsl`](crate::space::Okhsl) and [`Okhsv`](crate::space::Okhsv).
+///
+/// ```
+/// use farg::space::oklab::{toe, toe_inv};
+///
+/// for step in 0..=100 {
+///   let x = step as f64 / 100.0;
+///
+///   assert!((toe(toe_inv(x)) - x).abs() < 1e-10);
+/// }
+/// ```
+pub fn toe(x: f64) -> f64 {
   0.5 * ((K3 * x) - K1 + ((K3 * x - K1).powi(2) + 4.0 * K2 * K3 * x).sqrt())
 }
 
 /// Inverse toe function: maps Ok* perceived lightness to Oklab lightness.
-pub(crate) fn toe_inv(x: f64) -> f64 {
+pub fn toe_inv(x: f64) -> f64 {
   (x * x + K1 * x) / (K3 * (x + K2))
 }
 
@@ -914,6 +1021,15 @@ fn compute_max_saturation(a: f64, b: f64) -> f64 {
   sat - f * f1 / (f1 * f1 - 0.5 * f * f2)
 }
 
+/// Converts XYZ to Oklab relative to the given context's reference white.
+fn oklab_in_context(xyz: Xyz, context: ColorimetricContext) -> Oklab {
+  let linear_lms = Oklab::LINEAR_XYZ_MATRIX * xyz.adapt_to(context);
+  let cube_root_lms = [linear_lms[0].cbrt(), linear_lms[1].cbrt(), linear_lms[2].cbrt()];
+  let [l, a, b] = Oklab::LINEAR_LMS_MATRIX * cube_root_lms;
+
+  Oklab::new(l, a, b).with_context(context).with_alpha(xyz.alpha())
+}
+
 /// Converts Oklab L, a, b to linear sRGB components.
 fn oklab_to_linear_srgb(l: f64, a: f64, b: f64) -> [f64; 3] {
   let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
@@ -1022,6 +1138,66 @@ mod test {
     }
   }
 
+  mod delta_e_ok {
+    use super::*;
+
+    #[test]
+    fn it_returns_zero_for_identical_colors() {
+      let color = Oklab::new(0.6, 0.1, -0.05);
+
+      assert_eq!(color.delta_e_ok(color), 0.0);
+    }
+
+    #[test]
+    fn it_measures_the_distance_between_srgb_primaries() {
+      let red = Rgb::<Srgb>::new(255, 0, 0).to_oklab();
+      let green = Rgb::<Srgb>::new(0, 255, 0).to_oklab();
+
+      // Reference Oklab values: red (0.62796, 0.22486, 0.12585), green (0.86644, -0.23389, 0.17950).
+      assert!((red.delta_e_ok(green) - 0.51981).abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_puts_one_srgb_gray_step_below_the_jnd() {
+      let a = Rgb::<Srgb>::new(128, 128, 128).to_oklab();
+      let b = Rgb::<Srgb>::new(129, 129, 129).to_oklab();
+      let distance = a.delta_e_ok(b);
+
+      assert!(distance > 0.0);
+      assert!(distance < 0.02);
+    }
+
+    #[cfg(feature = "illuminant-d50")]
+    #[test]
+    fn it_adapts_other_into_this_context() {
+      use crate::Illuminant;
+
+      let color = Rgb::<Srgb>::new(200, 100, 50).to_oklab();
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let in_d50 = oklab_in_context(color.to_xyz(), d50);
+
+      assert!((in_d50.b() - color.b()).abs() > 1e-3);
+      assert!(in_d50.delta_e_ok(color) < 1e-9);
+      assert!(color.delta_e_ok(in_d50) < 1e-9);
+    }
+
+    #[test]
+    fn it_is_symmetric() {
+      let a = Oklab::new(0.3, 0.1, -0.2);
+      let b = Oklab::new(0.7, -0.05, 0.1);
+
+      assert!((a.delta_e_ok(b) - b.delta_e_ok(a)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_accepts_other_color_spaces() {
+      let white = Oklab::new(1.0, 0.0, 0.0);
+      let distance = white.delta_e_ok(Rgb::<Srgb>::new(255, 255, 255));
+
+      assert!(distance < 1e-3);
+    }
+  }
+
   mod display {
     use pretty_assertions::assert_eq;
 
@@ -1172,6 +1348,23 @@ mod test {
     }
   }
 
+  mod hue_degrees {
+    use super::*;
+
+    #[test]
+    fn it_returns_angle_in_degrees() {
+      assert!((Oklab::new(0.5, -0.1, 0.0).hue_degrees() - 180.0).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_agrees_with_oklch() {
+      let oklab = Oklab::new(0.6, -0.1, -0.08);
+
+      assert!((oklab.hue_degrees() - oklab.to_oklch().hue()).abs() < 1e-10);
+    }
+  }
+
   mod increment_a {
     use super::*;
 
@@ -1311,6 +1504,33 @@ mod test {
     }
   }
 
+  #[cfg(feature = "palette-interop")]
+  mod palette_interop {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_through_palette_oklab() {
+      let color = Oklab::new(0.6, 0.1, -0.05);
+      let back = Oklab::from(palette::Oklab::<f32>::from(color));
+
+      assert!((back.l() - color.l()).abs() < 1e-6);
+      assert!((back.a() - color.a()).abs() < 1e-6);
+      assert!((back.b() - color.b()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_agrees_with_palette_srgb_to_oklab() {
+      use palette::IntoColor;
+
+      let expected: palette::Oklab = palette::Srgb::new(0.8f32, 0.3, 0.15).into_linear().into_color();
+      let oklab = Rgb::<Srgb>::from_normalized(0.8, 0.3, 0.15).to_oklab();
+
+      assert!((oklab.l() - expected.l as f64).abs() < 1e-4);
+      assert!((oklab.a() - expected.a as f64).abs() < 1e-4);
+      assert!((oklab.b() - expected.b as f64).abs() < 1e-4);
+    }
+  }
+
   mod partial_eq {
     use super::*;
 
@@ -1331,6 +1551,23 @@ mod test {
     }
   }
 
+  mod polar_chroma {
+    use super::*;
+
+    #[test]
+    fn it_returns_distance_from_neutral_axis() {
+      assert!((Oklab::new(0.5, 0.03, 0.04).polar_chroma() - 0.05).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_agrees_with_oklch() {
+      let oklab = Oklab::new(0.6, -0.1, 0.08);
+
+      assert!((oklab.polar_chroma() - oklab.to_oklch().chroma()).abs() < 1e-10);
+    }
+  }
+
   mod scale_a {
     use super::*;
 
@@ -1367,6 +1604,51 @@ mod test {
     }
   }
 
+  #[cfg(feature = "space-lab")]
+  mod to_cielab {
+    use super::*;
+
+    #[test]
+    fn it_matches_to_lab() {
+      let oklab = Oklab::new(0.6, 0.1, -0.05).with_alpha(0.5);
+
+      assert_eq!(oklab.to_cielab(), oklab.to_lab());
+    }
+  }
+
+  #[cfg(feature = "space-lab")]
+  mod to_lab {
+    use super::*;
+
+    #[test]
+    fn it_maps_white_to_white() {
+      let lab = Oklab::new(1.0, 0.0, 0.0).to_lab();
+
+      assert!((lab.l() - 100.0).abs() < 0.05);
+      assert!(lab.a().abs() < 0.05);
+      assert!(lab.b().abs() < 0.05);
+    }
+
+    #[test]
+    fn it_differs_from_oklab_for_a_saturated_color() {
+      let oklab = Rgb::<Srgb>::new(200, 100, 50).to_oklab();
+      let lab = oklab.to_lab();
+
+      assert!((lab.a() - oklab.a()).abs() > 1.0);
+      assert!((lab.b() - oklab.b()).abs() > 1.0);
+    }
+
+    #[test]
+    fn it_round_trips_through_lab() {
+      let oklab = Oklab::new(0.6, 0.1, -0.05);
+      let back = oklab.to_lab().to_oklab();
+
+      assert!((back.l() - oklab.l()).abs() < 1e-9);
+      assert!((back.a() - oklab.a()).abs() < 1e-9);
+      assert!((back.b() - oklab.b()).abs() < 1e-9);
+    }
+  }
+
   mod to_css {
     use pretty_assertions::assert_eq;
 
@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -36,13 +40,26 @@ use crate::space::Okhwb;
 #[cfg(feature = "space-xyy")]
 use crate::space::Xyy;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext,
   component::Component,
-  space::{ColorSpace, Lms, Oklab, Rgb, RgbSpec, Srgb, Xyz},
+  space::{
+    ColorSpace, HueArc, Lms, Oklab, Rgb, RgbSpec, Srgb, Xyz,
+    interpolation::{ACHROMATIC_THRESHOLD, max_chroma_in_gamut, mix_hue, mix_hue_with_arc},
+  },
 };
 
-/// Chroma threshold below which a color is considered achromatic (hueless).
-const ACHROMATIC_THRESHOLD: f64 = 1e-4;
+/// Initial upper bound for the maximum in-gamut chroma search; doubled until out of gamut.
+const MAX_CHROMA_SEARCH_START: f64 = 0.5;
+
+/// Lightness of the darkest step produced by [`Oklch::tonal_scale`].
+const TONAL_SCALE_DARKEST: f64 = 0.25;
+
+/// Lightness of the lightest step produced by [`Oklch::tonal_scale`].
+const TONAL_SCALE_LIGHTEST: f64 = 0.97;
+
+/// Number of bisection steps used when searching for a lightness that meets a contrast target.
+#[cfg(feature = "contrast-wcag")]
+const CONTRAST_SEARCH_ITERATIONS: usize = 48;
 
 /// Oklch perceptual color space (cylindrical form of Oklab).
 ///
@@ -61,9 +78,7 @@ pub struct Oklch {
 
 impl Oklch {
   /// The default viewing context for Oklch (D65 illuminant, CIE 1931 2° observer).
-  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::new()
-    .with_illuminant(Illuminant::D65)
-    .with_observer(Observer::CIE_1931_2D);
+  pub const DEFAULT_CONTEXT: ColorimetricContext = ColorimetricContext::DEFAULT;
 
   /// Creates a new Oklch color from lightness (0.0-1.0), chroma, and hue (0-360°).
   pub fn new(l: impl Into<Component>, c: impl Into<Component>, h: impl Into<Component>) -> Self {
@@ -109,9 +124,11 @@ impl Oklch {
     &self.context
   }
 
-  /// Decreases the chroma by the given amount.
+  /// Decreases the chroma by the given amount, then [normalizes](Self::normalized) the color:
+  /// chroma stops at zero and an out-of-range hue is wrapped into 0-360°.
   pub fn decrement_c(&mut self, amount: impl Into<Component>) {
     self.c -= amount.into();
+    *self = self.normalized();
   }
 
   /// Alias for [`Self::decrement_c`].
@@ -153,6 +170,44 @@ impl Oklch {
     (0..steps).map(|i| self.mix(other, i as f64 / divisor)).collect()
   }
 
+  /// Generates a sequence of evenly-spaced colors between `self` and `other`, moving hue along
+  /// the given [`HueArc`].
+  ///
+  /// Behaves like [`Self::gradient`], which always takes the shorter arc, but lets the hue take
+  /// the longer way around or move in a fixed direction, as with the CSS Color Level 4
+  /// `longer`, `increasing`, and `decreasing` hue interpolation methods. Achromatic endpoints
+  /// are handled the same way.
+  ///
+  /// ```
+  /// use farg::space::{HueArc, Oklch};
+  ///
+  /// let start = Oklch::new(0.7, 0.1, 10.0);
+  /// let end = Oklch::new(0.7, 0.1, 350.0);
+  /// let rainbow = start.gradient_with_arc(end, 3, HueArc::Longer);
+  ///
+  /// assert!((rainbow[1].hue() - 180.0).abs() < 1e-6);
+  /// ```
+  pub fn gradient_with_arc(&self, other: impl Into<Xyz>, steps: usize, arc: HueArc) -> Vec<Self> {
+    if steps == 0 {
+      return Vec::new();
+    }
+
+    let other = Self::from(other.into());
+    let divisor = steps.saturating_sub(1).max(1) as f64;
+
+    (0..steps)
+      .map(|i| {
+        let t = i as f64 / divisor;
+        let l = Component::new(self.l()).lerp(other.l(), t);
+        let c = Component::new(self.c()).lerp(other.c(), t);
+        let h = mix_hue_with_arc(self.hue(), self.c(), other.hue(), other.c(), t, arc);
+        let alpha = Component::new(self.alpha()).lerp(other.alpha(), t);
+
+        Self::new(l, c, h).with_alpha(alpha)
+      })
+      .collect()
+  }
+
   /// Returns the normalized hue component (0.0-1.0).
   pub fn h(&self) -> f64 {
     self.h.0
@@ -188,11 +243,35 @@ impl Oklch {
     self.l += amount.into();
   }
 
+  /// Returns `true` if the chroma is below the achromatic threshold, making the hue powerless.
+  ///
+  /// This is the same test [`Self::mix`] uses to decide whether a color's hue takes part in
+  /// interpolation.
+  pub fn is_achromatic(&self) -> bool {
+    self.c.0 < ACHROMATIC_THRESHOLD
+  }
+
   /// Returns the L (lightness) component.
   pub fn l(&self) -> f64 {
     self.l.0
   }
 
+  /// Returns the largest chroma at this lightness and hue that still fits the gamut of `S`.
+  ///
+  /// Bisects chroma against [`ColorSpace::is_in_gamut`], so the returned value is always in
+  /// gamut and within floating-point precision of the boundary. Lightness at or beyond black or
+  /// white (1.0) yields 0.0.
+  pub fn max_chroma_for_gamut<S>(&self) -> f64
+  where
+    S: RgbSpec,
+  {
+    if self.l.0 <= 0.0 || self.l.0 >= 1.0 {
+      return 0.0;
+    }
+
+    max_chroma_in_gamut::<3, _, S>(MAX_CHROMA_SEARCH_START, |c| self.with_c(c))
+  }
+
   /// Interpolates between `self` and `other` at parameter `t`, returning a new color.
   ///
   /// When `t` is 0.0 the result matches `self`, when 1.0 it matches `other`.
@@ -223,9 +302,51 @@ impl Oklch {
     self.alpha = result.alpha;
   }
 
-  /// Scales the chroma by the given factor.
+  /// Returns a copy with chroma scaled toward gray by `factor` (clamped to 0.0-1.0).
+  ///
+  /// A factor of 1.0 leaves the color unchanged and 0.0 produces the gray of the same
+  /// lightness. Lightness, hue, and alpha are preserved.
+  ///
+  /// ```
+  /// use farg::space::Oklch;
+  ///
+  /// let gray = Oklch::new(0.7, 0.15, 30.0).muted(0.0);
+  /// assert_eq!(gray.c(), 0.0);
+  /// ```
+  pub fn muted(&self, factor: f64) -> Self {
+    self.with_c(self.c.0 * factor.clamp(0.0, 1.0))
+  }
+
+  /// Returns a copy with chroma clamped to be non-negative and hue wrapped into 0-360°.
+  ///
+  /// Chroma arithmetic can leave a negative value, which has no geometric meaning and produces
+  /// nonsense on conversion. [`decrement_c`](Self::decrement_c) and [`scale_c`](Self::scale_c)
+  /// apply this automatically.
+  ///
+  /// ```
+  /// use farg::space::Oklch;
+  ///
+  /// let mut color = Oklch::new(0.5, 0.05, 40.0);
+  /// color.set_c(-0.05);
+  /// color.set_h(400.0 / 360.0);
+  ///
+  /// let normalized = color.normalized();
+  /// assert_eq!(normalized.c(), 0.0);
+  /// assert!((normalized.hue() - 40.0).abs() < 1e-10);
+  /// ```
+  pub fn normalized(&self) -> Self {
+    Self {
+      c: Component::new(self.c.0.max(0.0)),
+      h: Component::new(self.h.0.rem_euclid(1.0)),
+      ..*self
+    }
+  }
+
+  /// Scales the chroma by the given factor, then [normalizes](Self::normalized) the color:
+  /// chroma stops at zero for negative factors and an out-of-range hue is wrapped into 0-360°.
   pub fn scale_c(&mut self, factor: impl Into<Component>) {
     self.c *= factor.into();
+    *self = self.normalized();
   }
 
   /// Alias for [`Self::scale_c`].
@@ -307,6 +428,35 @@ impl Oklch {
     }
   }
 
+  /// Returns a tonal scale of `count` colors from near-white to near-black at this color's hue.
+  ///
+  /// Lightness is evenly spaced from 0.97 down to 0.25, like the 50-950 steps of a design-system
+  /// palette. Each step keeps this color's chroma where the sRGB gamut allows it and otherwise
+  /// uses the largest in-gamut chroma, so chroma tapers toward the light and dark extremes. When
+  /// `count` is 0 the result is empty, and when it is 1 it contains only the lightest step.
+  /// Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::Oklch;
+  ///
+  /// let scale = Oklch::new(0.6, 0.15, 250.0).tonal_scale(9);
+  ///
+  /// assert_eq!(scale.len(), 9);
+  /// assert!(scale[0].l() > scale[8].l());
+  /// ```
+  pub fn tonal_scale(&self, count: usize) -> Vec<Self> {
+    let divisor = count.saturating_sub(1).max(1) as f64;
+
+    (0..count)
+      .map(|i| {
+        let l = TONAL_SCALE_LIGHTEST + (TONAL_SCALE_DARKEST - TONAL_SCALE_LIGHTEST) * (i as f64 / divisor);
+        let step = self.with_l(l);
+
+        step.with_c(self.c().min(step.max_chroma_for_gamut::<Srgb>()))
+      })
+      .collect()
+  }
+
   /// Converts to the Oklab perceptual color space.
   pub fn to_oklab(&self) -> Oklab {
     let h_rad = self.h.0 * 2.0 * std::f64::consts::PI;
@@ -329,6 +479,25 @@ impl Oklch {
     self.to_oklab().to_xyz()
   }
 
+  /// Returns a copy with chroma raised to the maximum that fits the gamut of `S`.
+  ///
+  /// Lightness, hue, and alpha are preserved. See
+  /// [`max_chroma_for_gamut`](Self::max_chroma_for_gamut).
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Oklch, Srgb};
+  ///
+  /// let vivid = Oklch::new(0.7, 0.05, 145.0).vivid::<Srgb>();
+  /// assert!(vivid.c() > 0.05);
+  /// assert!(vivid.is_in_gamut::<Srgb>());
+  /// ```
+  pub fn vivid<S>(&self) -> Self
+  where
+    S: RgbSpec,
+  {
+    self.with_c(self.max_chroma_for_gamut::<S>())
+  }
+
   /// Returns a new color with the given C value.
   pub fn with_c(&self, c: impl Into<Component>) -> Self {
     Self {
@@ -386,6 +555,67 @@ impl Oklch {
     }
   }
 
+  /// Returns this color with its lightness adjusted until its sRGB rendering meets `target_ratio`
+  /// WCAG contrast against `background`, keeping chroma and hue.
+  ///
+  /// Colors that already meet the target are returned unchanged. Otherwise the search moves away
+  /// from the background's luminance (darker on light backgrounds, lighter on dark ones) and
+  /// returns the lightness closest to the current one that meets the target, falling back to the
+  /// opposite direction if needed. When the target is unreachable in either direction, the
+  /// lightness extreme (0 or 1) with the highest achievable contrast is returned. Contrast is
+  /// measured on the sRGB color clipped to gamut.
+  ///
+  /// ```
+  /// use farg::space::{ColorSpace, Oklch, Rgb, Srgb};
+  ///
+  /// let white = Rgb::<Srgb>::new(255, 255, 255);
+  /// let text = Oklch::new(0.7, 0.1, 250.0).with_contrast_against(white, 4.5);
+  ///
+  /// assert!(text.l() < 0.7);
+  /// assert!(text.to_rgb::<Srgb>().contrast_ratio(white).meets_aa());
+  /// ```
+  #[cfg(feature = "contrast-wcag")]
+  pub fn with_contrast_against(&self, background: impl Into<Rgb<Srgb>>, target_ratio: f64) -> Self {
+    let background = background.into();
+    let contrast = |l: f64| {
+      let mut rgb = self.with_l(l).to_rgb::<Srgb>();
+      rgb.clip_to_gamut();
+      crate::contrast::wcag::contrast_ratio(rgb, background).value()
+    };
+
+    let current = self.l();
+    if contrast(current) >= target_ratio {
+      return *self;
+    }
+
+    let darker_first = self.to_xyz().luminance() <= background.to_xyz().luminance();
+    let directions = if darker_first { [0.0, 1.0] } else { [1.0, 0.0] };
+
+    for extreme in directions {
+      if contrast(extreme) < target_ratio {
+        continue;
+      }
+
+      let (mut failing, mut passing) = (current, extreme);
+      for _ in 0..CONTRAST_SEARCH_ITERATIONS {
+        let mid = (failing + passing) / 2.0;
+        if contrast(mid) >= target_ratio {
+          passing = mid;
+        } else {
+          failing = mid;
+        }
+      }
+
+      return self.with_l(passing);
+    }
+
+    if contrast(0.0) >= contrast(1.0) {
+      self.with_l(0.0)
+    } else {
+      self.with_l(1.0)
+    }
+  }
+
   /// Returns a new color with the given normalized hue (0.0-1.0).
   pub fn with_h(&self, h: impl Into<Component>) -> Self {
     Self {
@@ -731,6 +961,13 @@ impl From<Xyz> for Oklch {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Oklch {
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Oklch"], Oklab::hub_path()].concat()
+  }
+}
+
 impl<T> Mul<T> for Oklch
 where
   T: Into<Self>,
@@ -796,35 +1033,6 @@ impl TryFrom<String> for Oklch {
   }
 }
 
-/// Interpolates hue along the shortest arc with achromatic handling.
-///
-/// When either color is achromatic (chroma below [`ACHROMATIC_THRESHOLD`]), its hue is
-/// treated as "powerless" and the other color's hue is used. When both are achromatic,
-/// hue is 0. This follows the CSS Color Level 4 specification for hue interpolation.
-fn mix_hue(h1: f64, c1: f64, h2: f64, c2: f64, t: f64) -> f64 {
-  let achromatic1 = c1 < ACHROMATIC_THRESHOLD;
-  let achromatic2 = c2 < ACHROMATIC_THRESHOLD;
-
-  if achromatic1 && achromatic2 {
-    return 0.0;
-  }
-  if achromatic1 {
-    return h2;
-  }
-  if achromatic2 {
-    return h1;
-  }
-
-  let mut diff = h2 - h1;
-  if diff > 180.0 {
-    diff -= 360.0;
-  } else if diff < -180.0 {
-    diff += 360.0;
-  }
-
-  (h1 + diff * t).rem_euclid(360.0)
-}
-
 #[cfg(test)]
 mod test {
   use super::*;
@@ -1098,6 +1306,72 @@ mod test {
     }
   }
 
+  mod gradient_with_arc {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_passes_through_180_degrees_for_longer() {
+      let start = Oklch::new(0.7, 0.1, 10.0);
+      let end = Oklch::new(0.7, 0.1, 350.0);
+      let gradient = start.gradient_with_arc(end, 3, HueArc::Longer);
+
+      assert!((gradient[1].hue() - 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_passes_through_0_degrees_for_shorter() {
+      let start = Oklch::new(0.7, 0.1, 10.0);
+      let end = Oklch::new(0.7, 0.1, 350.0);
+      let gradient = start.gradient_with_arc(end, 3, HueArc::Shorter);
+      let hue = gradient[1].hue();
+
+      assert!(!(1e-6..=360.0 - 1e-6).contains(&hue));
+    }
+
+    #[test]
+    fn it_matches_gradient_for_shorter() {
+      let start = Oklch::new(0.7, 0.1, 40.0);
+      let end = Oklch::new(0.7, 0.1, 300.0);
+      let expected = start.gradient(end, 5);
+      let actual = start.gradient_with_arc(end, 5, HueArc::Shorter);
+
+      for (a, b) in actual.iter().zip(&expected) {
+        assert!((a.hue() - b.hue()).abs() < 1e-6);
+      }
+    }
+
+    #[test]
+    fn it_moves_in_a_fixed_direction() {
+      let start = Oklch::new(0.7, 0.1, 350.0);
+      let end = Oklch::new(0.7, 0.1, 10.0);
+      let increasing = start.gradient_with_arc(end, 3, HueArc::Increasing);
+      let decreasing = start.gradient_with_arc(end, 3, HueArc::Decreasing);
+
+      assert!(increasing[1].hue() < 1e-6 || increasing[1].hue() > 360.0 - 1e-6);
+      assert!((decreasing[1].hue() - 180.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_includes_both_endpoints() {
+      let start = Oklch::new(0.7, 0.1, 10.0);
+      let end = Oklch::new(0.7, 0.1, 350.0);
+      let gradient = start.gradient_with_arc(end, 4, HueArc::Longer);
+
+      assert_eq!(gradient.len(), 4);
+      assert!((gradient[0].hue() - 10.0).abs() < 1e-6);
+      assert!((gradient[3].hue() - 350.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_returns_empty_for_zero_steps() {
+      let color = Oklch::new(0.7, 0.1, 10.0);
+
+      assert!(color.gradient_with_arc(color, 0, HueArc::Longer).is_empty());
+    }
+  }
+
   mod h {
     use super::*;
 
@@ -1168,6 +1442,21 @@ mod test {
     }
   }
 
+  mod is_achromatic {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_for_grays() {
+      assert!(Oklch::new(0.6, 0.0, 250.0).is_achromatic());
+      assert!(Rgb::<Srgb>::new(128, 128, 128).to_oklab().to_oklch().is_achromatic());
+    }
+
+    #[test]
+    fn it_returns_false_for_saturated_colors() {
+      assert!(!Oklch::new(0.6, 0.15, 250.0).is_achromatic());
+    }
+  }
+
   mod l {
     use super::*;
 
@@ -1179,6 +1468,34 @@ mod test {
     }
   }
 
+  mod max_chroma_for_gamut {
+    use super::*;
+
+    #[test]
+    fn it_returns_an_in_gamut_chroma_on_the_boundary() {
+      let color = Oklch::new(0.6, 0.0, 250.0);
+      let max = color.max_chroma_for_gamut::<Srgb>();
+
+      assert!(max > 0.0);
+      assert!(color.with_c(max).is_in_gamut::<Srgb>());
+      assert!(!color.with_c(max * 1.001).is_in_gamut::<Srgb>());
+    }
+
+    #[test]
+    fn it_returns_zero_for_black_and_white() {
+      assert_eq!(Oklch::new(0.0, 0.1, 30.0).max_chroma_for_gamut::<Srgb>(), 0.0);
+      assert_eq!(Oklch::new(1.0, 0.1, 30.0).max_chroma_for_gamut::<Srgb>(), 0.0);
+    }
+
+    #[cfg(feature = "rgb-rec-2020")]
+    #[test]
+    fn it_is_larger_for_wider_gamuts() {
+      let color = Oklch::new(0.6, 0.0, 150.0);
+
+      assert!(color.max_chroma_for_gamut::<crate::space::Rec2020>() > color.max_chroma_for_gamut::<Srgb>());
+    }
+  }
+
   mod mix {
     use super::*;
 
@@ -1260,68 +1577,50 @@ mod test {
     }
   }
 
-  mod mix_hue_fn {
-    use super::super::mix_hue;
-
-    const EPSILON: f64 = 1e-6;
+  mod mixed_with {
+    use super::*;
 
     #[test]
-    fn shortest_arc_forward() {
-      let h = mix_hue(10.0, 0.1, 50.0, 0.1, 0.5);
-      assert!((h - 30.0).abs() < EPSILON);
+    fn it_mutates_in_place() {
+      let c1 = Oklch::new(0.6, 0.2, 30.0);
+      let c2 = Oklch::new(0.4, 0.1, 270.0);
+      let expected = c1.mix(c2.to_xyz(), 0.5);
+      let mut color = c1;
+      color.mixed_with(c2.to_xyz(), 0.5);
+      assert!((color.l() - expected.l()).abs() < 1e-10);
+      assert!((color.c() - expected.c()).abs() < 1e-10);
+      assert!((color.h() - expected.h()).abs() < 1e-10);
+      assert!((color.alpha() - expected.alpha()).abs() < 1e-10);
     }
+  }
 
-    #[test]
-    fn shortest_arc_crosses_zero() {
-      let h = mix_hue(350.0, 0.1, 10.0, 0.1, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
+  mod muted {
+    use super::*;
 
     #[test]
-    fn shortest_arc_backward() {
-      let h = mix_hue(10.0, 0.1, 350.0, 0.1, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
-    }
+    fn it_produces_gray_at_zero() {
+      let muted = Oklch::new(0.6, 0.1, 40.0).muted(0.0);
 
-    #[test]
-    fn both_achromatic() {
-      let h = mix_hue(90.0, 0.0, 270.0, 0.0, 0.5);
-      assert!((h - 0.0).abs() < EPSILON);
+      assert_eq!(muted.c(), 0.0);
+      assert!(muted.is_achromatic());
     }
 
     #[test]
-    fn first_achromatic_uses_second() {
-      let h = mix_hue(90.0, 0.0, 200.0, 0.1, 0.5);
-      assert!((h - 200.0).abs() < EPSILON);
-    }
+    fn it_is_unchanged_at_one() {
+      let color = Oklch::new(0.6, 0.1, 40.0);
 
-    #[test]
-    fn second_achromatic_uses_first() {
-      let h = mix_hue(90.0, 0.1, 200.0, 0.0, 0.5);
-      assert!((h - 90.0).abs() < EPSILON);
+      assert_eq!(color.muted(1.0), color);
     }
 
     #[test]
-    fn same_hemisphere() {
-      let h = mix_hue(100.0, 0.1, 140.0, 0.1, 0.5);
-      assert!((h - 120.0).abs() < EPSILON);
-    }
-  }
-
-  mod mixed_with {
-    use super::*;
+    fn it_scales_chroma_and_preserves_lightness_and_hue() {
+      let color = Oklch::new(0.6, 0.1, 40.0).with_alpha(0.5);
+      let muted = color.muted(0.5);
 
-    #[test]
-    fn it_mutates_in_place() {
-      let c1 = Oklch::new(0.6, 0.2, 30.0);
-      let c2 = Oklch::new(0.4, 0.1, 270.0);
-      let expected = c1.mix(c2.to_xyz(), 0.5);
-      let mut color = c1;
-      color.mixed_with(c2.to_xyz(), 0.5);
-      assert!((color.l() - expected.l()).abs() < 1e-10);
-      assert!((color.c() - expected.c()).abs() < 1e-10);
-      assert!((color.h() - expected.h()).abs() < 1e-10);
-      assert!((color.alpha() - expected.alpha()).abs() < 1e-10);
+      assert!((muted.c() - 0.1 / 2.0).abs() < 1e-12);
+      assert_eq!(muted.l(), color.l());
+      assert_eq!(muted.hue(), color.hue());
+      assert_eq!(muted.alpha(), 0.5);
     }
   }
 
@@ -1357,6 +1656,59 @@ mod test {
     }
   }
 
+  mod normalized {
+    use super::*;
+
+    #[test]
+    fn it_clamps_negative_chroma_to_zero() {
+      let mut color = Oklch::new(0.5, 0.05, 180.0);
+      color.set_c(-0.1);
+
+      assert_eq!(color.normalized().c(), 0.0);
+    }
+
+    #[test]
+    fn it_wraps_hue_into_range() {
+      let mut color = Oklch::new(0.5, 0.05, 40.0);
+      color.set_h(400.0 / 360.0);
+
+      assert!(color.hue() > 360.0);
+      assert!((color.normalized().hue() - 40.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_leaves_valid_colors_unchanged() {
+      let color = Oklch::new(0.5, 0.05, 120.0);
+
+      assert_eq!(color.normalized(), color);
+    }
+
+    #[test]
+    fn it_is_applied_by_decrement_c() {
+      let mut color = Oklch::new(0.5, 0.05, 180.0);
+      color.decrement_c(0.1);
+
+      assert_eq!(color.c(), 0.0);
+    }
+
+    #[test]
+    fn it_is_applied_by_scale_c() {
+      let mut color = Oklch::new(0.5, 0.05, 180.0);
+      color.scale_c(-1.0);
+
+      assert_eq!(color.c(), 0.0);
+    }
+
+    #[test]
+    fn it_wraps_hue_when_applied_by_decrement_c() {
+      let mut color = Oklch::new(0.5, 0.05, 40.0);
+      color.set_h(400.0 / 360.0);
+      color.decrement_c(0.01);
+
+      assert!((color.hue() - 40.0).abs() < 1e-10);
+    }
+  }
+
   mod partial_eq {
     use super::*;
 
@@ -1591,6 +1943,51 @@ mod test {
     }
   }
 
+  mod tonal_scale {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_decreases_monotonically_in_lightness() {
+      let scale = Oklch::new(0.6, 0.15, 250.0).tonal_scale(9);
+
+      assert_eq!(scale.len(), 9);
+      assert!(scale.windows(2).all(|pair| pair[0].l() > pair[1].l()));
+    }
+
+    #[test]
+    fn it_spans_very_light_to_very_dark() {
+      let scale = Oklch::new(0.6, 0.15, 250.0).tonal_scale(9);
+
+      assert!(scale[0].l() > 0.95);
+      assert!(scale[8].l() < 0.3);
+    }
+
+    #[test]
+    fn it_keeps_the_hue() {
+      let scale = Oklch::new(0.6, 0.15, 250.0).tonal_scale(9);
+
+      assert!(scale.iter().all(|step| (step.hue() - 250.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn it_tapers_chroma_at_the_extremes() {
+      let scale = Oklch::new(0.6, 0.15, 250.0).tonal_scale(9);
+
+      assert!(scale[0].c() < scale[4].c());
+      assert!(scale.iter().all(|step| step.is_in_gamut::<Srgb>()));
+    }
+
+    #[test]
+    fn it_handles_zero_and_one_step() {
+      let color = Oklch::new(0.6, 0.15, 250.0);
+
+      assert!(color.tonal_scale(0).is_empty());
+      assert_eq!(color.tonal_scale(1)[0].l(), 0.97);
+    }
+  }
+
   mod try_from_str {
     use super::*;
 
@@ -1609,6 +2006,38 @@ mod test {
     }
   }
 
+  mod vivid {
+    use super::*;
+
+    #[test]
+    fn it_lands_on_the_gamut_boundary() {
+      let vivid = Oklch::new(0.6, 0.1, 140.0).vivid::<Srgb>();
+      let rgb = vivid.to_rgb::<Srgb>();
+
+      assert!(rgb.is_in_gamut());
+      assert!(!vivid.with_c(vivid.c() * 1.001).is_in_gamut::<Srgb>());
+    }
+
+    #[test]
+    fn it_round_trips_without_clamping() {
+      let vivid = Oklch::new(0.6, 0.1, 300.0).vivid::<Srgb>();
+      let rgb = vivid.to_rgb::<Srgb>();
+      let back = rgb.to_oklab().to_oklch();
+
+      assert!((back.c() - vivid.c()).abs() < 1e-6);
+      assert!((back.l() - vivid.l()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_preserves_lightness_and_hue() {
+      let color = Oklch::new(0.6, 0.1, 200.0);
+      let vivid = color.vivid::<Srgb>();
+
+      assert_eq!(vivid.l(), color.l());
+      assert_eq!(vivid.hue(), color.hue());
+    }
+  }
+
   mod with_alpha {
     use super::*;
 
@@ -1685,6 +2114,59 @@ mod test {
     }
   }
 
+  #[cfg(feature = "contrast-wcag")]
+  mod with_contrast_against {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_darkens_to_meet_the_target_against_white() {
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let color = Oklch::new(0.7, 0.1, 250.0);
+      let adjusted = color.with_contrast_against(white, 4.5);
+
+      assert!(adjusted.l() < color.l());
+      assert!(adjusted.to_rgb::<Srgb>().contrast_ratio(white).value() >= 4.5);
+      assert_eq!(adjusted.c(), color.c());
+      assert_eq!(adjusted.h(), color.h());
+    }
+
+    #[test]
+    fn it_stays_close_to_the_target() {
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let adjusted = Oklch::new(0.7, 0.1, 250.0).with_contrast_against(white, 4.5);
+
+      assert!(adjusted.to_rgb::<Srgb>().contrast_ratio(white).value() < 4.51);
+    }
+
+    #[test]
+    fn it_lightens_to_meet_the_target_against_black() {
+      let black = Rgb::<Srgb>::new(0, 0, 0);
+      let color = Oklch::new(0.3, 0.1, 30.0);
+      let adjusted = color.with_contrast_against(black, 7.0);
+
+      assert!(adjusted.l() > color.l());
+      assert!(adjusted.to_rgb::<Srgb>().contrast_ratio(black).value() >= 7.0);
+    }
+
+    #[test]
+    fn it_returns_unchanged_when_the_target_is_already_met() {
+      let white = Rgb::<Srgb>::new(255, 255, 255);
+      let color = Oklch::new(0.2, 0.05, 120.0);
+
+      assert_eq!(color.with_contrast_against(white, 4.5).l(), color.l());
+    }
+
+    #[test]
+    fn it_returns_the_extreme_when_the_target_is_unreachable() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128);
+      let adjusted = Oklch::new(0.5, 0.0, 0.0).with_contrast_against(gray, 21.0);
+
+      assert_eq!(adjusted.l(), 0.0);
+    }
+  }
+
   mod with_h {
     use super::*;
 
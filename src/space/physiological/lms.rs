@@ -3,6 +3,8 @@ use std::{
   ops::{Add, Div, Mul, Sub},
 };
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -17,6 +19,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -183,11 +187,45 @@ impl Lms {
     self.m()
   }
 
+  /// Returns the per-channel ratio of this color's cone responses to another's.
+  ///
+  /// The result is `[L / other.L, M / other.M, S / other.S]`. When `self` and `other` are two
+  /// white points, these are the Von Kries gains that map `other` onto `self` (see
+  /// [`scale_cones`](Self::scale_cones)). Channels where `other` is zero yield 0.0.
+  pub fn ratio_to(&self, other: impl Into<Lms>) -> [f64; 3] {
+    let other = other.into();
+    let [l, m, s] = self.components();
+    let [ol, om, os] = other.components();
+    let ratio = |a: f64, b: f64| if b == 0.0 { 0.0 } else { a / b };
+
+    [ratio(l, ol), ratio(m, om), ratio(s, os)]
+  }
+
   /// Returns the S (short) cone response.
   pub fn s(&self) -> f64 {
     self.s.0
   }
 
+  /// Returns a new color with each cone channel multiplied by its own gain.
+  ///
+  /// Applies an arbitrary Von Kries-style diagonal transform without building a full
+  /// [`Cat`](crate::Cat). Alpha and context are preserved.
+  ///
+  /// ```
+  /// use farg::space::Lms;
+  ///
+  /// let lms = Lms::new(0.5, 0.4, 0.3).scale_cones(2.0, 1.0, 0.5);
+  /// assert_eq!(lms.components(), [1.0, 0.4, 0.15]);
+  /// ```
+  pub fn scale_cones(&self, l: f64, m: f64, s: f64) -> Self {
+    Self {
+      l: Component::new(self.l.0 * l),
+      m: Component::new(self.m.0 * m),
+      s: Component::new(self.s.0 * s),
+      ..*self
+    }
+  }
+
   /// Scales the L component by the given factor.
   pub fn scale_l(&mut self, factor: impl Into<Component>) {
     self.l *= factor.into();
@@ -683,6 +721,13 @@ impl From<Xyz> for Lms {
   }
 }
 
+#[cfg(feature = "trace")]
+impl HubPath for Lms {
+  fn hub_path() -> Vec<&'static str> {
+    vec!["Lms", "Xyz"]
+  }
+}
+
 impl<T> Mul<T> for Lms
 where
   T: Into<Self>,
@@ -1022,6 +1067,73 @@ mod test {
     }
   }
 
+  mod ratio_to {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_per_channel_ratios() {
+      let ratio = Lms::new(0.5, 0.4, 0.3).ratio_to(Lms::new(0.25, 0.8, 0.3));
+
+      assert_eq!(ratio, [2.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn it_returns_ones_for_itself() {
+      let lms = Lms::new(0.5, 0.4, 0.3);
+
+      assert_eq!(lms.ratio_to(lms), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn it_returns_zero_for_zero_denominator() {
+      let ratio = Lms::new(0.5, 0.4, 0.3).ratio_to(Lms::new(0.0, 0.4, 0.3));
+
+      assert_eq!(ratio, [0.0, 1.0, 1.0]);
+    }
+  }
+
+  mod scale_cones {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_is_identity_for_unit_gains() {
+      let lms = Lms::new(0.5, 0.4, 0.3).with_alpha(0.5);
+      let scaled = lms.scale_cones(1.0, 1.0, 1.0);
+
+      assert_eq!(scaled.components(), lms.components());
+      assert_eq!(scaled.alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_scales_each_channel_independently() {
+      let lms = Lms::new(0.5, 0.4, 0.3).scale_cones(2.0, 0.5, 3.0);
+
+      for (actual, expected) in lms.components().iter().zip([1.0, 0.2, 0.9]) {
+        assert!((actual - expected).abs() < 1e-12);
+      }
+    }
+
+    #[test]
+    fn it_matches_von_kries_adaptation_between_whites() {
+      let cat = *ColorimetricContext::default().cat();
+      let source_white = Xyz::new(0.95047, 1.0, 1.08883);
+      let target_white = Xyz::new(0.96422, 1.0, 0.82521);
+      let color = Xyz::new(0.3, 0.4, 0.5);
+
+      let [l, m, s] = target_white.to_lms().ratio_to(source_white.to_lms());
+      let manual = color.to_lms().scale_cones(l, m, s).to_xyz();
+      let expected = cat.adapt(color, source_white, target_white);
+
+      for (a, b) in manual.components().iter().zip(expected.components()) {
+        assert!((a - b).abs() < 1e-12);
+      }
+    }
+  }
+
   mod scale_l {
     use pretty_assertions::assert_eq;
 
@@ -0,0 +1,36 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// Uniform polar view of an opponent color space (L, a, b-style axes).
+///
+/// Exposes the chroma (distance from the neutral axis) and hue angle of a color without
+/// converting it to its dedicated cylindrical form, so appearance analysis can be written once
+/// for [`Lab`](crate::space::Lab), [`Luv`](crate::space::Luv), and [`Oklab`](crate::space::Oklab).
+///
+/// The chroma accessor is named `polar_chroma` so it does not collide with
+/// [`ColorSpace::chroma`](crate::space::ColorSpace::chroma), which returns the Oklch or LCh chroma
+/// of any color.
+///
+/// ```
+/// # #[cfg(feature = "space-lab")]
+/// # {
+/// use farg::space::{Lab, PolarColor};
+///
+/// let lab = Lab::new(50.0, 0.0, 20.0);
+///
+/// assert!((lab.polar_chroma() - 20.0).abs() < 1e-10);
+/// assert!((lab.hue_degrees() - 90.0).abs() < 1e-10);
+/// # }
+/// ```
+pub trait PolarColor {
+  /// Returns the hue angle of the opponent axes in degrees (0-360).
+  fn hue_degrees(&self) -> f64;
+
+  /// Returns the chroma: the Euclidean distance of the opponent axes from the neutral axis.
+  fn polar_chroma(&self) -> f64;
+}
+
+/// Computes `(chroma, hue in degrees)` from a pair of opponent-axis values.
+pub(crate) fn polar_coordinates(a: f64, b: f64) -> (f64, f64) {
+  (a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0))
+}
@@ -1,11 +1,16 @@
+mod channel;
 mod linear;
 mod primaries;
+mod rendering_intent;
 mod space;
 mod spec;
 mod transfer;
 
+pub use channel::Channel;
 pub use linear::LinearRgb;
 pub use primaries::RgbPrimaries;
+pub use rendering_intent::RenderingIntent;
 pub use space::*;
 pub use spec::RgbSpec;
+pub(crate) use spec::{MatrixCell, cached_matrix, cached_xyz_matrices};
 pub use transfer::TransferFunction;
@@ -0,0 +1,10 @@
+/// A single channel of an RGB color, used by [`Rgb::swizzle`](super::Rgb::swizzle).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Channel {
+  /// The blue channel.
+  B,
+  /// The green channel.
+  G,
+  /// The red channel.
+  R,
+}
@@ -5,6 +5,8 @@ use std::{
 
 use super::{RgbSpec, space::Rgb};
 use crate::component::Component;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 /// Linear (scene-referred) RGB values before transfer function encoding.
 ///
@@ -85,11 +87,54 @@ where
     [self.r.0, self.g.0, self.b.0]
   }
 
+  /// Composites this color over `background` with the Porter-Duff source-over operator.
+  ///
+  /// The output alpha is `αs + αb(1 - αs)` and each channel is the alpha-weighted sum of source
+  /// and background divided by the output alpha. When both colors are fully transparent the
+  /// result is transparent black.
+  pub fn composite_over(&self, background: Self) -> Self {
+    let source_alpha = self.alpha.0;
+    let backdrop_alpha = background.alpha.0 * (1.0 - source_alpha);
+    let alpha = source_alpha + backdrop_alpha;
+
+    if alpha <= 0.0 {
+      return Self::from_normalized(0.0, 0.0, 0.0).with_alpha(0.0);
+    }
+
+    let [r, g, b] = [
+      (self.r.0, background.r.0),
+      (self.g.0, background.g.0),
+      (self.b.0, background.b.0),
+    ]
+    .map(|(source, backdrop)| (source * source_alpha + backdrop * backdrop_alpha) / alpha);
+
+    Self::from_normalized(r, g, b).with_alpha(alpha)
+  }
+
   /// Returns the normalized green component (0.0-1.0).
   pub fn g(&self) -> f64 {
     self.g.0
   }
 
+  /// Encodes these linear values with another space's transfer function.
+  ///
+  /// Applies `OS::TRANSFER_FUNCTION` to the components as-is, assuming `S` and `OS` share
+  /// primaries and white point. This does *not* perform gamut or white point conversion;
+  /// use [`Rgb::to_rgb`] for that.
+  ///
+  /// ```
+  /// use farg::space::{LinearRgb, Srgb};
+  ///
+  /// let encoded = LinearRgb::<Srgb>::from_normalized(0.5, 0.5, 0.5).encode_with::<Srgb>();
+  /// assert!(encoded.r() > 0.5);
+  /// ```
+  pub fn encode_with<OS>(&self) -> Rgb<OS>
+  where
+    OS: RgbSpec,
+  {
+    self.reinterpret::<OS>().to_encoded()
+  }
+
   /// Returns the green component as a u8 (0-255).
   pub fn green(&self) -> u8 {
     (self.g.0 * 255.0).round() as u8
@@ -105,6 +150,17 @@ where
     (self.r.0 * 255.0).round() as u8
   }
 
+  /// Relabels these linear values as belonging to another RGB space.
+  ///
+  /// Components and alpha are copied unchanged. This does *not* perform gamut or white
+  /// point conversion.
+  pub fn reinterpret<OS>(&self) -> LinearRgb<OS>
+  where
+    OS: RgbSpec,
+  {
+    LinearRgb::<OS>::from_normalized(self.r, self.g, self.b).with_alpha(self.alpha)
+  }
+
   /// Applies the transfer function to produce encoded (gamma-corrected) RGB values.
   pub fn to_encoded(&self) -> Rgb<S> {
     let r = S::TRANSFER_FUNCTION.encode(self.r);
@@ -168,6 +224,22 @@ mod test {
     }
   }
 
+  mod composite_over {
+    use super::*;
+
+    #[test]
+    fn it_weights_channels_by_alpha() {
+      let source = LinearRgb::<Srgb>::from_normalized(1.0, 0.0, 0.0).with_alpha(0.25);
+      let result = source.composite_over(LinearRgb::from_normalized(0.0, 0.0, 1.0).with_alpha(0.5));
+      let alpha = 0.25 + 0.5 * 0.75;
+
+      assert!((result.alpha() - alpha).abs() < 1e-10);
+      assert!((result.r() - 0.25 / alpha).abs() < 1e-10);
+      assert!(result.g().abs() < 1e-10);
+      assert!((result.b() - 0.375 / alpha).abs() < 1e-10);
+    }
+  }
+
   mod display {
     use pretty_assertions::assert_eq;
 
@@ -195,6 +267,48 @@ mod test {
     }
   }
 
+  mod encode_with {
+    use super::*;
+
+    #[test]
+    fn it_matches_to_encoded_for_the_same_space() {
+      let linear = LinearRgb::<Srgb>::from_normalized(0.25, 0.5, 0.75);
+      let expected = linear.to_encoded();
+      let encoded = linear.encode_with::<Srgb>();
+
+      assert!((encoded.r() - expected.r()).abs() < 1e-10);
+      assert!((encoded.g() - expected.g()).abs() < 1e-10);
+      assert!((encoded.b() - expected.b()).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "rgb-linear-srgb")]
+    #[test]
+    fn it_is_identity_with_linear_srgb_transfer_function() {
+      use crate::space::LinearSrgb;
+
+      let linear = LinearRgb::<Srgb>::from_normalized(0.25, 0.5, 0.75).with_alpha(0.4);
+      let encoded = linear.encode_with::<LinearSrgb>();
+
+      assert!((encoded.r() - 0.25).abs() < 1e-15);
+      assert!((encoded.g() - 0.5).abs() < 1e-15);
+      assert!((encoded.b() - 0.75).abs() < 1e-15);
+      assert!((encoded.alpha() - 0.4).abs() < 1e-15);
+    }
+  }
+
+  mod reinterpret {
+    use super::*;
+
+    #[test]
+    fn it_copies_components_and_alpha() {
+      let linear = LinearRgb::<Srgb>::from_normalized(0.25, 0.5, 1.5).with_alpha(0.4);
+      let result = linear.reinterpret::<Srgb>();
+
+      assert_eq!(result.components(), [0.25, 0.5, 1.5]);
+      assert_eq!(result.alpha(), 0.4);
+    }
+  }
+
   mod to_encoded {
     use super::*;
 
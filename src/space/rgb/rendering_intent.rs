@@ -0,0 +1,73 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Strategy for converting colors between RGB spaces with different gamuts or white points.
+///
+/// Modeled after the ICC rendering intents. Used by [`Rgb::to_rgb_with_intent`](super::Rgb::to_rgb_with_intent).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RenderingIntent {
+  /// Preserves the exact XYZ values without adapting between white points.
+  ///
+  /// The source white renders as a tinted color in a destination with a different white
+  /// point. Out-of-gamut results are clipped.
+  AbsoluteColorimetric,
+  /// Maps out-of-gamut colors into the destination gamut by reducing chroma while
+  /// preserving lightness and hue.
+  Perceptual,
+  #[default]
+  /// Adapts the source white to the destination white via the destination context's
+  /// chromatic adaptation transform. Out-of-gamut results are clipped.
+  RelativeColorimetric,
+}
+
+impl Display for RenderingIntent {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::AbsoluteColorimetric => write!(f, "Absolute Colorimetric"),
+      Self::Perceptual => write!(f, "Perceptual"),
+      Self::RelativeColorimetric => write!(f, "Relative Colorimetric"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod default {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_defaults_to_relative_colorimetric() {
+      assert_eq!(RenderingIntent::default(), RenderingIntent::RelativeColorimetric);
+    }
+  }
+
+  mod display {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_formats_absolute_colorimetric() {
+      assert_eq!(
+        format!("{}", RenderingIntent::AbsoluteColorimetric),
+        "Absolute Colorimetric"
+      );
+    }
+
+    #[test]
+    fn it_formats_perceptual() {
+      assert_eq!(format!("{}", RenderingIntent::Perceptual), "Perceptual");
+    }
+
+    #[test]
+    fn it_formats_relative_colorimetric() {
+      assert_eq!(
+        format!("{}", RenderingIntent::RelativeColorimetric),
+        "Relative Colorimetric"
+      );
+    }
+  }
+}
@@ -78,6 +78,7 @@ mod wide_gamut_rgb;
 
 use std::{
   fmt::{Display, Formatter, Result as FmtResult},
+  hash::{Hash, Hasher},
   marker::PhantomData,
   ops::{Add, Div, Mul, Sub},
 };
@@ -160,7 +161,9 @@ pub use standard::Srgb;
 #[cfg(feature = "rgb-wide-gamut-rgb")]
 pub use wide_gamut_rgb::WideGamutRgb;
 
-use super::{LinearRgb, RgbSpec};
+use super::{Channel, LinearRgb, RenderingIntent, RgbSpec};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 #[cfg(feature = "space-cmy")]
 use crate::space::Cmy;
 #[cfg(feature = "space-cmyk")]
@@ -173,6 +176,8 @@ use crate::space::Hsi;
 use crate::space::Hsl;
 #[cfg(feature = "space-hsluv")]
 use crate::space::Hsluv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -200,6 +205,7 @@ use crate::space::{Hsb, Hsv};
 use crate::{
   ColorimetricContext, Error,
   component::Component,
+  matrix::Matrix3,
   space::{ColorSpace, Lms, Xyz},
 };
 
@@ -208,6 +214,10 @@ use crate::{
 /// The type parameter `S` determines which RGB space (sRGB, Display P3, etc.)
 /// this color belongs to. Defaults to [`Srgb`] when not specified.
 /// Components are stored as normalized values in the 0.0-1.0 range.
+///
+/// Equality and hashing are quantized to 8 bits per channel, alpha included: two colors whose
+/// channels and `round(alpha * 255)` match compare equal and produce the same hash, so `Rgb` can be
+/// used as a `HashMap` or `HashSet` key for palette deduplication.
 #[derive(Clone, Copy, Debug)]
 pub struct Rgb<S = Srgb>
 where
@@ -230,6 +240,33 @@ where
   /// White (255, 255, 255).
   pub const WHITE: Self = Self::new_const(255, 255, 255);
 
+  /// Returns the signed APCA-W3 0.1.9 lightness contrast (Lc) of `text` on `background`.
+  ///
+  /// Positive values mean dark text on a light background and negative values light text on a
+  /// dark background. See [`apca::calculate_srgb`](crate::contrast::apca::calculate_srgb).
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let lc = Rgb::<Srgb>::apca_contrast(Rgb::<Srgb>::new(0, 0, 0), Rgb::<Srgb>::new(255, 255, 255));
+  /// assert!(lc > 100.0);
+  /// ```
+  #[cfg(feature = "contrast-apca")]
+  pub fn apca_contrast(text: impl Into<Rgb<Srgb>>, background: impl Into<Rgb<Srgb>>) -> f64 {
+    crate::contrast::apca::calculate_srgb(text, background).value()
+  }
+
+  /// Creates an RGB color from `f32` channel values, as handed out by GPU and floating-point image
+  /// buffers.
+  ///
+  /// Values are clamped to 0.0-1.0 and non-finite values become 0.0. Use
+  /// [`from_normalized`](Self::from_normalized) to keep out-of-gamut values.
+  pub fn from_f32(r: f32, g: f32, b: f32) -> Self {
+    let clamp = |c: f32| if c.is_finite() { (c as f64).clamp(0.0, 1.0) } else { 0.0 };
+
+    Self::from_normalized(clamp(r), clamp(g), clamp(b))
+  }
+
   /// Parses a hex color code (e.g., "#FF5733" or "F00") into an RGB color.
   pub fn from_hexcode(hexcode: impl Into<String>) -> Result<Self, Error> {
     let hexcode = hexcode.into();
@@ -271,6 +308,50 @@ where
     Ok(Self::new(r, g, b))
   }
 
+  /// Parses a hex color code, rejecting anything [`from_hexcode`](Self::from_hexcode) would
+  /// quietly accept.
+  ///
+  /// The input must be a `#` followed by exactly 3 or 6 hex digits, with no surrounding or
+  /// embedded whitespace. Useful for validating user input in forms.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::UnexpectedWhitespace`] if the input contains any whitespace,
+  /// [`Error::MissingHashPrefix`] if it does not start with `#`,
+  /// [`Error::InvalidHexCharacter`] if any remaining character is not a hex digit, and
+  /// [`Error::InvalidHexLength`] if there are not 3 or 6 digits.
+  ///
+  /// ```
+  /// use farg::{Error, space::{Rgb, Srgb}};
+  ///
+  /// assert!(Rgb::<Srgb>::from_hexcode_strict("#FF0000").is_ok());
+  /// assert_eq!(
+  ///   Rgb::<Srgb>::from_hexcode_strict("FF0000"),
+  ///   Err(Error::MissingHashPrefix { input: "FF0000".to_string() })
+  /// );
+  /// ```
+  pub fn from_hexcode_strict(hexcode: &str) -> Result<Self, Error> {
+    if hexcode.chars().any(char::is_whitespace) {
+      return Err(Error::UnexpectedWhitespace {
+        input: hexcode.to_string(),
+      });
+    }
+
+    let Some(hex) = hexcode.strip_prefix('#') else {
+      return Err(Error::MissingHashPrefix {
+        input: hexcode.to_string(),
+      });
+    };
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+      return Err(Error::InvalidHexCharacter {
+        input: hexcode.to_string(),
+      });
+    }
+
+    Self::from_hexcode(hexcode)
+  }
+
   /// Creates an RGB color from normalized component values.
   ///
   /// Values outside 0.0-1.0 are preserved to retain out-of-gamut information.
@@ -285,6 +366,60 @@ where
     }
   }
 
+  /// Creates a stable, pleasant color derived from a numeric seed.
+  ///
+  /// The seed is hashed to a hue and combined with a fixed Oklch lightness and chroma, so every
+  /// generated color is equally vivid and readable. The same seed always produces the same color,
+  /// making this suitable for tagging users or items (e.g., avatar or identicon backgrounds).
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let a = Rgb::<Srgb>::from_seed(42);
+  /// let b = Rgb::<Srgb>::from_seed(42);
+  ///
+  /// assert_eq!(a, b);
+  /// ```
+  #[cfg(feature = "space-oklch")]
+  pub fn from_seed(seed: u64) -> Self {
+    const LIGHTNESS: f64 = 0.7;
+    const CHROMA: f64 = 0.12;
+
+    // SplitMix64 finalizer: spreads consecutive seeds across the full hue circle.
+    let mut hash = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+
+    let hue = (hash >> 11) as f64 / (1u64 << 53) as f64 * 360.0;
+
+    Oklch::new(LIGHTNESS, CHROMA, hue).to_rgb::<S>().gamut_mapped()
+  }
+
+  /// Creates a stable, pleasant color derived from a string seed.
+  ///
+  /// The string is hashed with 64-bit FNV-1a and passed to [`from_seed`](Self::from_seed), so the
+  /// result is stable across platforms and releases.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// assert_eq!(Rgb::<Srgb>::from_str_seed("alice"), Rgb::<Srgb>::from_str_seed("alice"));
+  /// ```
+  #[cfg(feature = "space-oklch")]
+  pub fn from_str_seed(s: &str) -> Self {
+    let hash = s.bytes().fold(0xCBF2_9CE4_8422_2325_u64, |hash, byte| {
+      (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    });
+
+    Self::from_seed(hash)
+  }
+
+  /// Creates an RGB color from 16-bit (0-65535) component values.
+  pub fn from_u16(r: u16, g: u16, b: u16) -> Self {
+    Self::from_normalized(r as f64 / 65535.0, g as f64 / 65535.0, b as f64 / 65535.0)
+  }
+
   /// Creates an RGB color from 8-bit (0-255) component values.
   pub fn new(r: u8, g: u8, b: u8) -> Self {
     Self {
@@ -318,11 +453,70 @@ where
     self.b.0
   }
 
+  /// Returns black or white, whichever has the higher WCAG 2.x contrast ratio against this color.
+  ///
+  /// See [`Self::best_text_color_with_ratio`] to also get the resulting ratio.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let background = Rgb::<Srgb>::new(30, 30, 60);
+  /// assert_eq!(background.best_text_color(), Rgb::<Srgb>::new(255, 255, 255));
+  /// ```
+  #[cfg(feature = "contrast-wcag")]
+  pub fn best_text_color(&self) -> Rgb<Srgb> {
+    self.best_text_color_with_ratio().0
+  }
+
+  /// Returns black or white, whichever has the higher WCAG 2.x contrast ratio against this color,
+  /// along with that ratio.
+  ///
+  /// Black wins when this color's relative luminance is above roughly 0.179, the point where both
+  /// choices give equal contrast; ties resolve to black.
+  #[cfg(feature = "contrast-wcag")]
+  pub fn best_text_color_with_ratio(&self) -> (Rgb<Srgb>, f64) {
+    let black = Rgb::<Srgb>::new(0, 0, 0);
+    let white = Rgb::<Srgb>::new(255, 255, 255);
+    let black_ratio = crate::contrast::wcag::contrast_ratio(*self, black).value();
+    let white_ratio = crate::contrast::wcag::contrast_ratio(*self, white).value();
+
+    if black_ratio >= white_ratio {
+      (black, black_ratio)
+    } else {
+      (white, white_ratio)
+    }
+  }
+
   /// Returns the blue component as a u8 (0-255).
   pub fn blue(&self) -> u8 {
     (self.b.0 * 255.0).round() as u8
   }
 
+  /// Returns this color with its encoded channels remixed by a 3×3 `matrix`.
+  ///
+  /// Each output channel is the dot product of the corresponding matrix row with the encoded
+  /// `[r, g, b]` vector, then clamped to 0.0-1.0, like a compositor's channel mixer. The identity
+  /// matrix is a no-op and a matrix with identical rows produces a monochrome result. Alpha and
+  /// context are preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let swap_red_and_blue = [[0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]];
+  /// let mixed = Rgb::<Srgb>::new(255, 128, 0).channel_mix(swap_red_and_blue);
+  /// assert_eq!(mixed.to_hex(), "#0080ff");
+  /// ```
+  pub fn channel_mix(&self, matrix: impl Into<Matrix3>) -> Self {
+    let [r, g, b] = matrix.into() * [self.r.0, self.g.0, self.b.0];
+
+    Self {
+      r: Component::new(r.clamp(0.0, 1.0)),
+      g: Component::new(g.clamp(0.0, 1.0)),
+      b: Component::new(b.clamp(0.0, 1.0)),
+      ..*self
+    }
+  }
+
   /// Clamps all components to the 0.0-1.0 range.
   pub fn clip_to_gamut(&mut self) {
     if self.is_in_gamut() {
@@ -339,6 +533,26 @@ where
     [self.r.0, self.g.0, self.b.0]
   }
 
+  /// Composites this color over `background` using source-over alpha blending in linear light.
+  ///
+  /// Both colors are linearized, blended with [`LinearRgb::composite_over`], and re-encoded.
+  /// Unlike [`flatten_alpha_against`](Self::flatten_alpha_against), which blends encoded values
+  /// and always yields an opaque color, the output alpha accounts for a translucent background.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let red = Rgb::<Srgb>::new(255, 0, 0).with_alpha(0.5);
+  /// let pink = red.composite_over(Rgb::<Srgb>::new(255, 255, 255));
+  /// assert_eq!(pink.to_hex(), "#ffbcbc");
+  /// ```
+  pub fn composite_over(&self, background: impl Into<Self>) -> Self {
+    self
+      .to_linear()
+      .composite_over(background.into().to_linear())
+      .to_encoded()
+  }
+
   /// Reduces chroma in CIELAB space until the color fits the gamut.
   #[cfg(feature = "space-lab")]
   pub fn compress_to_gamut(&mut self) {
@@ -439,6 +653,36 @@ where
     self.g.0
   }
 
+  /// Returns a copy brought into gamut by reducing Oklch chroma, preserving lightness and hue.
+  ///
+  /// In-gamut colors are returned unchanged. Otherwise chroma is reduced to
+  /// [`Oklch::max_chroma_for_gamut`], which avoids the hue shifts of clamping each channel
+  /// independently (see [`is_clipped`](Self::is_clipped)). Lightness beyond black or white cannot
+  /// be fixed by chroma alone, so any remaining excess is clipped. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let oversaturated = Rgb::<Srgb>::from_normalized(1.2, 0.4, -0.1);
+  /// let mapped = oversaturated.gamut_mapped();
+  ///
+  /// assert!(oversaturated.is_clipped());
+  /// assert!(!mapped.is_clipped());
+  /// ```
+  #[cfg(feature = "space-oklch")]
+  pub fn gamut_mapped(&self) -> Self {
+    if self.is_in_gamut() {
+      return *self;
+    }
+
+    let oklch = Oklch::from(self.to_xyz());
+    let chroma = oklch.c().min(oklch.max_chroma_for_gamut::<S>());
+
+    let mut result = oklch.with_c(chroma).to_xyz().to_rgb::<S>();
+    result.clip_to_gamut();
+    result.with_alpha(self.alpha)
+  }
+
   /// Generates a sequence of evenly-spaced colors between `self` and `other` in linear-light RGB.
   ///
   /// Returns `steps` colors including both endpoints, interpolated in linearized RGB
@@ -493,11 +737,86 @@ where
     self.r = (self.r + amount.into() / 255.0).clamp(0.0, 1.0);
   }
 
+  /// Returns the inverse of this color, replacing each encoded channel `c` with `1 - c`.
+  ///
+  /// This is the conventional "negative" found in image editors. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// assert_eq!(Rgb::<Srgb>::new(255, 200, 0).invert().to_hex(), "#0037ff");
+  /// ```
+  pub fn invert(&self) -> Self {
+    Self {
+      r: Component::new(1.0 - self.r.0),
+      g: Component::new(1.0 - self.g.0),
+      b: Component::new(1.0 - self.b.0),
+      ..*self
+    }
+  }
+
+  /// Returns the inverse of this color in linear light.
+  ///
+  /// Decodes each channel, replaces the linear value `c` with `1 - c`, and re-encodes, so the
+  /// inverted light intensities sum to full intensity. Mid-gray therefore inverts to a lighter
+  /// gray than with [`Self::invert`]. Alpha is preserved.
+  pub fn invert_linear(&self) -> Self {
+    let [r, g, b] = self.to_linear().components();
+
+    LinearRgb::<S>::from_normalized(1.0 - r, 1.0 - g, 1.0 - b)
+      .to_encoded()
+      .with_alpha(self.alpha)
+  }
+
+  /// Returns `true` if any component is outside the 0.0-1.0 range and would be clipped on output.
+  ///
+  /// The inverse of [`is_in_gamut`](Self::is_in_gamut).
+  pub fn is_clipped(&self) -> bool {
+    !self.is_in_gamut()
+  }
+
   /// Returns `true` if all components are within the 0.0-1.0 range.
   pub fn is_in_gamut(&self) -> bool {
     (0.0..=1.0).contains(&self.r.0) && (0.0..=1.0).contains(&self.g.0) && (0.0..=1.0).contains(&self.b.0)
   }
 
+  /// Linearly interpolates between `self` and `other` at parameter `t` in linear-light RGB.
+  ///
+  /// Same-space shorthand for [`mix_linear`](Self::mix_linear). Unlike the arithmetic operators
+  /// (`(a + b) / 2.0` averages the gamma-encoded channel values, producing midpoints that look too
+  /// dark), this interpolates in linear light.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let black = Rgb::<Srgb>::new(0, 0, 0);
+  /// let white = Rgb::<Srgb>::new(255, 255, 255);
+  ///
+  /// assert_eq!(black.lerp(white, 0.5), Rgb::<Srgb>::new(188, 188, 188));
+  /// ```
+  pub fn lerp(&self, other: impl Into<Self>, t: f64) -> Self {
+    self.mix_linear(other.into(), t)
+  }
+
+  /// Returns a copy with `f` applied to each normalized channel, clamping the results to 0.0-1.0.
+  ///
+  /// Alpha is left unchanged.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let inverted = Rgb::<Srgb>::new(255, 128, 0).map(|c| 1.0 - c);
+  /// assert_eq!(inverted, Rgb::<Srgb>::new(0, 127, 255));
+  /// ```
+  pub fn map(&self, f: impl Fn(f64) -> f64) -> Self {
+    Self::from_normalized(
+      f(self.r.0).clamp(0.0, 1.0),
+      f(self.g.0).clamp(0.0, 1.0),
+      f(self.b.0).clamp(0.0, 1.0),
+    )
+    .with_alpha(self.alpha)
+  }
+
   /// Interpolates between `self` and `other` at parameter `t` in linear-light RGB.
   ///
   /// When `t` is 0.0 the result matches `self`, when 1.0 it matches `other`.
@@ -530,6 +849,44 @@ where
     self.alpha = result.alpha;
   }
 
+  /// Scales the light intensity of the color by `factor`, like an exposure adjustment.
+  ///
+  /// The channels are decoded to linear light, multiplied by `factor`, clamped to 0.0-1.0, and
+  /// re-encoded, so doubling the factor doubles the emitted light rather than the encoded channel
+  /// values. Alpha is left unchanged.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let brighter = Rgb::<Srgb>::new(128, 128, 128).mul_scalar(2.0);
+  /// assert_eq!(brighter, Rgb::<Srgb>::new(176, 176, 176));
+  /// ```
+  pub fn mul_scalar(&self, factor: f64) -> Self {
+    let [r, g, b] = self.to_linear().components().map(|c| (c * factor).clamp(0.0, 1.0));
+
+    LinearRgb::<S>::from_normalized(r, g, b)
+      .with_alpha(self.alpha)
+      .to_encoded()
+  }
+
+  /// Returns the perceived lightness as Oklab L (0.0-1.0).
+  ///
+  /// Unlike HSL lightness, this tracks how light a color actually looks, so it is the better
+  /// key for ordering swatches (see [`sort_by_lightness`](crate::space::sort_by_lightness)).
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let yellow = Rgb::<Srgb>::new(255, 255, 0);
+  /// let blue = Rgb::<Srgb>::new(0, 0, 255);
+  ///
+  /// assert!(yellow.perceived_lightness() > blue.perceived_lightness());
+  /// ```
+  #[cfg(feature = "space-oklab")]
+  pub fn perceived_lightness(&self) -> f64 {
+    self.to_oklab().l()
+  }
+
   /// Maps to gamut by scaling LMS components relative to the reference white.
   pub fn perceptually_map_to_gamut(&mut self) {
     let lms = self.to_xyz().to_lms();
@@ -558,6 +915,33 @@ where
     self.set_components(scaled.components())
   }
 
+  /// Returns a copy with each channel multiplied by alpha, as expected by compositing backends.
+  ///
+  /// Alpha itself is kept unchanged. This operates on the encoded (gamma-corrected) values,
+  /// which matches most graphics APIs; for physically correct blending, premultiply the
+  /// [`to_linear`](Self::to_linear) values instead.
+  pub fn premultiplied(&self) -> Self {
+    let alpha = self.alpha.0;
+    Self::from_normalized(self.r * alpha, self.g * alpha, self.b * alpha).with_alpha(self.alpha)
+  }
+
+  /// Returns a copy with each channel reduced to `2^bits_per_channel` evenly spaced levels.
+  ///
+  /// Channels are rounded to the nearest level in encoded (gamma-corrected) space, so
+  /// `bits_per_channel = 5` yields 32 levels per channel. The bit depth is clamped to 1–8.
+  /// Alpha is left unchanged.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let color = Rgb::<Srgb>::new(200, 100, 30).quantize(1);
+  /// assert_eq!(color, Rgb::<Srgb>::new(255, 0, 0));
+  /// ```
+  pub fn quantize(&self, bits_per_channel: u8) -> Self {
+    let steps = ((1u32 << bits_per_channel.clamp(1, 8)) - 1) as f64;
+    self.map(|c| (c * steps).round() / steps)
+  }
+
   /// Returns the normalized red component (0.0-1.0).
   pub fn r(&self) -> f64 {
     self.r.0
@@ -568,6 +952,34 @@ where
     (self.r.0 * 255.0).round() as u8
   }
 
+  /// Returns this color with its hue rotated by `degrees`, staying in the same RGB space.
+  ///
+  /// The rotation happens in Oklch so lightness and chroma are held perceptually constant, then
+  /// the result is converted back to `S`. Achromatic colors (grays, black, and white) have no
+  /// meaningful hue and are returned unchanged. The result is not gamut mapped; chain
+  /// [`gamut_mapped`](Self::gamut_mapped) if it must stay displayable. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let gray = Rgb::<Srgb>::new(128, 128, 128);
+  /// assert_eq!(gray.rotate_hue(90.0), gray);
+  /// ```
+  #[cfg(feature = "space-oklch")]
+  pub fn rotate_hue(&self, degrees: f64) -> Self {
+    let oklch = Oklch::from(self.to_xyz());
+
+    if oklch.is_achromatic() {
+      return *self;
+    }
+
+    oklch
+      .with_hue_incremented_by(degrees)
+      .to_xyz()
+      .to_rgb::<S>()
+      .with_alpha(self.alpha)
+  }
+
   /// Scales the blue channel by the given factor, clamping to 0.0-1.0.
   pub fn scale_b(&mut self, factor: impl Into<Component>) {
     self.b = (self.b * factor.into()).clamp(0.0, 1.0);
@@ -588,6 +1000,37 @@ where
     self.scale_g(factor);
   }
 
+  /// Returns this color with all channels divided by the largest one when any exceeds 1.0.
+  ///
+  /// Unlike [`Self::clip_to_gamut`], which clamps each channel independently and so shifts hue,
+  /// this keeps the ratios between the encoded channels, bringing the brightest channel to exactly
+  /// 1.0. For pure power-law encodings this also preserves chromaticity; for piecewise curves
+  /// such as sRGB it does so approximately. [`Self::scale_to_gamut`] scales in linear light
+  /// instead. Colors with no channel above 1.0 (including those with negative channels) are
+  /// returned unchanged.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let color = Rgb::<Srgb>::from_normalized(2.0, 1.0, 0.5).scale_into_gamut();
+  ///
+  /// assert_eq!(color.components(), [1.0, 0.5, 0.25]);
+  /// ```
+  pub fn scale_into_gamut(&self) -> Self {
+    let max_value = self.r.0.max(self.g.0).max(self.b.0);
+
+    if max_value <= 1.0 {
+      return *self;
+    }
+
+    Self {
+      r: self.r / max_value,
+      g: self.g / max_value,
+      b: self.b / max_value,
+      ..*self
+    }
+  }
+
   /// Scales the red channel by the given factor, clamping to 0.0-1.0.
   pub fn scale_r(&mut self, factor: impl Into<Component>) {
     self.r = (self.r * factor.into()).clamp(0.0, 1.0);
@@ -615,6 +1058,23 @@ where
     self.set_components(result.components())
   }
 
+  /// Returns a sepia-toned version of this color.
+  ///
+  /// A [`channel_mix`](Self::channel_mix) preset using the widely used sepia matrix, which
+  /// desaturates the color and tints it toward warm brown. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let sepia = Rgb::<Srgb>::new(128, 128, 128).sepia();
+  /// assert!(sepia.r() > sepia.g() && sepia.g() > sepia.b());
+  /// ```
+  pub fn sepia(&self) -> Self {
+    const SEPIA: [[f64; 3]; 3] = [[0.393, 0.769, 0.189], [0.349, 0.686, 0.168], [0.272, 0.534, 0.131]];
+
+    self.channel_mix(SEPIA)
+  }
+
   /// Sets the blue channel to the given normalized value (0.0-1.0).
   pub fn set_b(&mut self, b: impl Into<Component>) {
     self.b = b.into().clamp(0.0, 1.0);
@@ -652,6 +1112,74 @@ where
     self.r = (red.into() / 255.0).clamp(0.0, 1.0);
   }
 
+  /// Returns a shade of this color: a mix toward black by `amount` (0.0-1.0), interpolated in Oklab.
+  ///
+  /// An `amount` of 0.0 returns the original color and 1.0 returns black. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let shade = Rgb::<Srgb>::new(255, 87, 51).shade(1.0);
+  /// assert_eq!(shade.to_hex(), "#000000");
+  /// ```
+  #[cfg(feature = "space-oklab")]
+  pub fn shade(&self, amount: f64) -> Self {
+    self.mix_oklab_toward(Self::from_normalized(0.0, 0.0, 0.0), amount)
+  }
+
+  /// Returns a color whose red, green, and blue channels are taken from `order`.
+  ///
+  /// Each output channel copies the named input channel, so `[Channel::B, Channel::G, Channel::R]`
+  /// swaps red and blue for BGR buffers and `[Channel::R, Channel::G, Channel::B]` is the
+  /// identity. Channels may repeat. Alpha and context are preserved.
+  ///
+  /// ```
+  /// use farg::space::{Channel, Rgb, Srgb};
+  ///
+  /// let color = Rgb::<Srgb>::new(10, 20, 30).swizzle([Channel::B, Channel::G, Channel::R]);
+  /// assert_eq!([color.red(), color.green(), color.blue()], [30, 20, 10]);
+  /// ```
+  pub fn swizzle(&self, order: [Channel; 3]) -> Self {
+    let [r, g, b] = order.map(|channel| match channel {
+      Channel::B => self.b,
+      Channel::G => self.g,
+      Channel::R => self.r,
+    });
+
+    Self {
+      r,
+      g,
+      b,
+      ..*self
+    }
+  }
+
+  /// Returns a tint of this color: a mix toward white by `amount` (0.0-1.0), interpolated in Oklab.
+  ///
+  /// An `amount` of 0.0 returns the original color and 1.0 returns white. Alpha is preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let tint = Rgb::<Srgb>::new(255, 87, 51).tint(1.0);
+  /// assert_eq!(tint.to_hex(), "#ffffff");
+  /// ```
+  #[cfg(feature = "space-oklab")]
+  pub fn tint(&self, amount: f64) -> Self {
+    self.mix_oklab_toward(Self::from_normalized(1.0, 1.0, 1.0), amount)
+  }
+
+  /// Returns the 8-bit channels in blue, green, red order for BGR buffers.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// assert_eq!(Rgb::<Srgb>::new(10, 20, 30).to_bgr_array(), [30, 20, 10]);
+  /// ```
+  pub fn to_bgr_array(&self) -> [u8; 3] {
+    [self.blue(), self.green(), self.red()]
+  }
+
   /// Converts to CMY in this color space.
   #[cfg(feature = "space-cmy")]
   pub fn to_cmy(&self) -> Cmy<S> {
@@ -698,6 +1226,13 @@ where
     format!("#{:02x}{:02x}{:02x}", self.red(), self.green(), self.blue())
   }
 
+  /// Returns the normalized [R, G, B] components as `f32` values.
+  ///
+  /// Out-of-gamut values are preserved.
+  pub fn to_f32(&self) -> [f32; 3] {
+    self.components().map(|c| c as f32)
+  }
+
   /// Converts to HSB in this color space. Alias for [`Self::to_hsv`].
   #[cfg(feature = "space-hsv")]
   pub fn to_hsb(&self) -> Hsb<S> {
@@ -863,18 +1398,101 @@ where
     }
   }
 
-  /// Converts to CIE XYZ via linear RGB and the space's RGB-to-XYZ matrix.
-  pub fn to_xyz(&self) -> Xyz {
-    let linear = self.to_linear();
-    let [x, y, z] = *S::xyz_matrix() * linear.components();
-    Xyz::new(x, y, z).with_context(self.context).with_alpha(self.alpha)
-  }
-
-  /// Returns a new color with the given alpha value on a 0.0 to 1.0 scale.
-  pub fn with_alpha(&self, alpha: impl Into<Component>) -> Self {
-    Self {
-      alpha: alpha.into().clamp(0.0, 1.0),
-      ..*self
+  /// Converts to a different RGB color space using the given [`RenderingIntent`].
+  ///
+  /// - [`RelativeColorimetric`](RenderingIntent::RelativeColorimetric) adapts the source white
+  ///   to the destination white via the destination context's CAT, then clips.
+  /// - [`AbsoluteColorimetric`](RenderingIntent::AbsoluteColorimetric) keeps XYZ unchanged with
+  ///   no white point adaptation, then clips.
+  /// - [`Perceptual`](RenderingIntent::Perceptual) adapts like relative, then reduces chroma in
+  ///   Oklch (or CIELAB when Oklch is unavailable) until the color fits the destination gamut.
+  ///
+  /// When both spaces share a white point the two colorimetric intents produce the same result.
+  pub fn to_rgb_with_intent<OS>(&self, intent: RenderingIntent) -> Rgb<OS>
+  where
+    OS: RgbSpec,
+  {
+    match intent {
+      RenderingIntent::AbsoluteColorimetric => {
+        let [r, g, b] = *OS::inversed_xyz_matrix() * self.to_xyz();
+        let mut result = LinearRgb::<OS>::from_normalized(r, g, b)
+          .to_encoded()
+          .with_alpha(self.alpha);
+        result.clip_to_gamut();
+        result
+      }
+      RenderingIntent::Perceptual => {
+        let mut result = self.to_rgb::<OS>();
+        result.map_chroma_to_gamut();
+        result
+      }
+      RenderingIntent::RelativeColorimetric => {
+        let mut result = self.to_rgb::<OS>();
+        result.clip_to_gamut();
+        result
+      }
+    }
+  }
+
+  /// Returns the [R, G, B] components as 16-bit (0-65535) values, clamping out-of-gamut channels.
+  pub fn to_u16(&self) -> [u16; 3] {
+    self.components().map(|c| (c.clamp(0.0, 1.0) * 65535.0).round() as u16)
+  }
+
+  /// Converts to CIE XYZ via linear RGB and the space's RGB-to-XYZ matrix.
+  pub fn to_xyz(&self) -> Xyz {
+    let linear = self.to_linear();
+    let [x, y, z] = *S::xyz_matrix() * linear.components();
+    Xyz::new(x, y, z).with_context(self.context).with_alpha(self.alpha)
+  }
+
+  /// Converts to CIE XYZ with the raw RGB-to-XYZ matrix, tagging the result with the spec's context.
+  ///
+  /// Applies the same `S::xyz_matrix()` as [`to_xyz`](Self::to_xyz), which also performs no
+  /// chromatic adaptation. The only difference is the context tag: `to_xyz` carries over this
+  /// color's context, while this always uses `S::CONTEXT`. Useful when adapting many colors later
+  /// in bulk from a known source context.
+  pub fn to_xyz_unadapted(&self) -> Xyz {
+    let [x, y, z] = *S::xyz_matrix() * self.to_linear().components();
+    Xyz::new(x, y, z).with_context(S::CONTEXT).with_alpha(self.alpha)
+  }
+
+  /// Returns a tone of this color: a mix toward 50% gray by `amount` (0.0-1.0), interpolated in
+  /// Oklab.
+  ///
+  /// An `amount` of 0.0 returns the original color and 1.0 returns 50% gray (`#808080`). Alpha is
+  /// preserved.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let tone = Rgb::<Srgb>::new(255, 87, 51).tone(1.0);
+  /// assert_eq!(tone.to_hex(), "#808080");
+  /// ```
+  #[cfg(feature = "space-oklab")]
+  pub fn tone(&self, amount: f64) -> Self {
+    self.mix_oklab_toward(Self::new(128, 128, 128), amount)
+  }
+
+  /// Returns a copy with each channel divided by alpha, reversing [`premultiplied`](Self::premultiplied).
+  ///
+  /// When alpha is zero the color carries no recoverable channel information, so all
+  /// channels are set to 0.0. Like `premultiplied`, this operates on encoded values.
+  pub fn unpremultiplied(&self) -> Self {
+    let alpha = self.alpha.0;
+
+    if alpha == 0.0 {
+      return Self::from_normalized(0.0, 0.0, 0.0).with_alpha(self.alpha);
+    }
+
+    Self::from_normalized(self.r / alpha, self.g / alpha, self.b / alpha).with_alpha(self.alpha)
+  }
+
+  /// Returns a new color with the given alpha value on a 0.0 to 1.0 scale.
+  pub fn with_alpha(&self, alpha: impl Into<Component>) -> Self {
+    Self {
+      alpha: alpha.into().clamp(0.0, 1.0),
+      ..*self
     }
   }
 
@@ -1098,6 +1716,57 @@ where
   pub fn with_red_scaled_by(&self, factor: impl Into<Component>) -> Self {
     self.with_r_scaled_by(factor)
   }
+
+  /// Combines each normalized channel with the matching channel of `other` using `f`, clamping
+  /// the results to 0.0-1.0.
+  ///
+  /// `f` receives `(self, other)` channel values. Alpha is taken from `self`.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let lighten = Rgb::<Srgb>::new(200, 50, 100).zip_with(Rgb::<Srgb>::new(100, 150, 100), f64::max);
+  /// assert_eq!(lighten, Rgb::<Srgb>::new(200, 150, 100));
+  /// ```
+  pub fn zip_with(&self, other: impl Into<Rgb<S>>, f: impl Fn(f64, f64) -> f64) -> Self {
+    let other = other.into();
+
+    Self::from_normalized(
+      f(self.r.0, other.r.0).clamp(0.0, 1.0),
+      f(self.g.0, other.g.0).clamp(0.0, 1.0),
+      f(self.b.0, other.b.0).clamp(0.0, 1.0),
+    )
+    .with_alpha(self.alpha)
+  }
+
+  /// Reduces chroma in Oklch, preserving lightness and hue, until the color fits the gamut.
+  #[cfg(feature = "space-oklch")]
+  fn map_chroma_to_gamut(&mut self) {
+    *self = self.gamut_mapped();
+  }
+
+  /// Reduces chroma in CIELAB until the color fits the gamut.
+  #[cfg(all(feature = "space-lab", not(feature = "space-oklch")))]
+  fn map_chroma_to_gamut(&mut self) {
+    self.compress_to_gamut()
+  }
+
+  /// Clamps all components to the gamut when no perceptual space is available.
+  #[cfg(not(any(feature = "space-lab", feature = "space-oklch")))]
+  fn map_chroma_to_gamut(&mut self) {
+    self.clip_to_gamut()
+  }
+
+  /// Interpolates toward `target` by `amount` in Oklab, preserving this color's alpha.
+  #[cfg(feature = "space-oklab")]
+  fn mix_oklab_toward(&self, target: Self, amount: f64) -> Self {
+    self.to_oklab().mix(target, amount).to_rgb::<S>().with_alpha(self.alpha)
+  }
+
+  /// Returns alpha quantized to 8 bits, the precision used by equality and hashing.
+  fn quantized_alpha(&self) -> u8 {
+    (self.alpha.0 * 255.0).round() as u8
+  }
 }
 
 impl<S, T> Add<T> for Rgb<S>
@@ -1212,6 +1881,8 @@ where
   }
 }
 
+impl<S> Eq for Rgb<S> where S: RgbSpec {}
+
 impl<S, T> From<[T; 3]> for Rgb<S>
 where
   S: RgbSpec,
@@ -1222,6 +1893,16 @@ where
   }
 }
 
+impl<S, T> From<[T; 4]> for Rgb<S>
+where
+  S: RgbSpec,
+  T: Into<Component>,
+{
+  fn from([r, g, b, alpha]: [T; 4]) -> Self {
+    Self::from_normalized(r, g, b).with_alpha(alpha)
+  }
+}
+
 #[cfg(feature = "space-cmy")]
 impl<OS, S> From<Cmy<OS>> for Rgb<S>
 where
@@ -1426,6 +2107,25 @@ where
   }
 }
 
+impl<S> Hash for Rgb<S>
+where
+  S: RgbSpec,
+{
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    (self.red(), self.green(), self.blue(), self.quantized_alpha()).hash(state);
+  }
+}
+
+#[cfg(feature = "trace")]
+impl<S> HubPath for Rgb<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    vec![S::NAME, "Xyz"]
+  }
+}
+
 impl<S, T> Mul<T> for Rgb<S>
 where
   S: RgbSpec,
@@ -1449,7 +2149,7 @@ where
 {
   fn eq(&self, other: &T) -> bool {
     let other = (*other).into();
-    self.alpha == other.alpha
+    self.quantized_alpha() == other.quantized_alpha()
       && self.red() == other.red()
       && self.green() == other.green()
       && self.blue() == other.blue()
@@ -1518,6 +2218,37 @@ where
 mod test {
   use super::*;
 
+  mod channel_mix {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_is_a_no_op_for_the_identity_matrix() {
+      let color = Rgb::<Srgb>::new(200, 80, 40).with_alpha(0.5);
+      let mixed = color.channel_mix([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+      assert_eq!(mixed.components(), color.components());
+      assert_eq!(mixed.alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_produces_equal_channels_for_a_grayscale_matrix() {
+      let row = [0.2126, 0.7152, 0.0722];
+      let mixed = Rgb::<Srgb>::new(200, 80, 40).channel_mix([row, row, row]);
+
+      assert_eq!(mixed.r(), mixed.g());
+      assert_eq!(mixed.g(), mixed.b());
+    }
+
+    #[test]
+    fn it_clamps_the_result() {
+      let mixed = Rgb::<Srgb>::new(255, 255, 255).channel_mix([[2.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+
+      assert_eq!(mixed.components(), [1.0, 0.0, 1.0]);
+    }
+  }
+
   mod default_type_parameter {
     use pretty_assertions::assert_eq;
 
@@ -1549,6 +2280,67 @@ mod test {
     }
   }
 
+  #[cfg(feature = "contrast-apca")]
+  mod apca_contrast {
+    use super::*;
+
+    #[test]
+    fn it_matches_apca_reference_for_gray_on_white() {
+      let text = Rgb::<Srgb>::from_hexcode("#888").unwrap();
+      let background = Rgb::<Srgb>::from_hexcode("#fff").unwrap();
+
+      assert!((Rgb::<Srgb>::apca_contrast(text, background) - 63.056).abs() < 1e-3);
+    }
+
+    #[test]
+    fn it_returns_negative_lc_for_reverse_polarity() {
+      let text = Rgb::<Srgb>::from_hexcode("#fff").unwrap();
+      let background = Rgb::<Srgb>::from_hexcode("#888").unwrap();
+
+      assert!((Rgb::<Srgb>::apca_contrast(text, background) + 68.541).abs() < 1e-3);
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod accent {
+    use super::*;
+
+    #[test]
+    fn it_yields_light_warm_accent_for_dark_blue() {
+      let accent = Rgb::<Srgb>::new(20, 30, 120).accent();
+      let rgb = accent.to_rgb::<Srgb>();
+
+      assert_eq!(accent.l(), 0.8);
+      assert!(rgb.red() > rgb.blue());
+    }
+
+    #[test]
+    fn it_yields_dark_cool_accent_for_light_warm_color() {
+      let accent = Rgb::<Srgb>::new(255, 220, 150).accent();
+      let rgb = accent.to_rgb::<Srgb>();
+
+      assert_eq!(accent.l(), 0.25);
+      assert!(rgb.blue() > rgb.red());
+    }
+
+    #[test]
+    fn it_rotates_hue_by_half_a_turn() {
+      let color = Rgb::<Srgb>::new(200, 40, 60);
+      let accent = color.accent();
+      let difference = (accent.hue() - ColorSpace::to_oklch(&color).hue()).rem_euclid(360.0);
+
+      assert!((difference - 180.0).abs() < 1e-9);
+      assert!((accent.c() - 0.12).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let accent = Rgb::<Srgb>::new(20, 30, 120).with_alpha(0.5).accent();
+
+      assert!((accent.alpha() - 0.5).abs() < 1e-12);
+    }
+  }
+
   mod add {
     use pretty_assertions::assert_eq;
 
@@ -1586,6 +2378,81 @@ mod test {
     }
   }
 
+  mod approx_eq {
+    use super::*;
+
+    #[test]
+    fn it_is_true_within_epsilon() {
+      let a = Rgb::<Srgb>::from_normalized(0.2, 0.4, 0.6);
+      let b = Rgb::<Srgb>::from_normalized(0.2 + 1e-12, 0.4, 0.6);
+
+      assert!(a.approx_eq(b, 1e-9));
+    }
+
+    #[test]
+    fn it_is_false_outside_epsilon() {
+      let a = Rgb::<Srgb>::from_normalized(0.2, 0.4, 0.6);
+      let b = Rgb::<Srgb>::from_normalized(0.2 + 1e-12, 0.4, 0.6);
+
+      assert!(!a.approx_eq(b, 1e-15));
+    }
+
+    #[test]
+    fn it_distinguishes_colors_that_round_to_the_same_8_bit_value() {
+      let a = Rgb::<Srgb>::from_normalized(0.5, 0.5, 0.5);
+      let b = Rgb::<Srgb>::from_normalized(0.501, 0.5, 0.5);
+
+      assert_eq!(a, b);
+      assert!(!a.approx_eq(b, 1e-6));
+    }
+  }
+
+  #[cfg(feature = "contrast-wcag")]
+  mod best_text_color {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_picks_black_on_light_background() {
+      let (color, ratio) = Rgb::<Srgb>::new(240, 230, 140).best_text_color_with_ratio();
+
+      assert_eq!(color, Rgb::<Srgb>::new(0, 0, 0));
+      assert!(ratio > 1.0);
+    }
+
+    #[test]
+    fn it_picks_white_on_dark_background() {
+      let color = Rgb::<Srgb>::new(20, 40, 90).best_text_color();
+
+      assert_eq!(color, Rgb::<Srgb>::new(255, 255, 255));
+    }
+
+    #[test]
+    fn it_switches_at_the_luminance_crossover() {
+      let crossover = (1.05_f64 * 0.05).sqrt() - 0.05;
+
+      assert!((crossover - 0.179).abs() < 1e-3);
+
+      for value in 0..=255 {
+        let gray = Rgb::<Srgb>::new(value, value, value);
+        let expected = if gray.to_xyz().luminance() > crossover { 0 } else { 255 };
+
+        assert_eq!(gray.best_text_color().red(), expected);
+      }
+
+      assert_eq!(Rgb::<Srgb>::new(117, 117, 117).best_text_color().red(), 255);
+      assert_eq!(Rgb::<Srgb>::new(118, 118, 118).best_text_color().red(), 0);
+    }
+
+    #[test]
+    fn it_returns_the_chosen_ratio() {
+      let (_, ratio) = Rgb::<Srgb>::new(255, 255, 255).best_text_color_with_ratio();
+
+      assert!((ratio - 21.0).abs() < 1e-6);
+    }
+  }
+
   mod clip_to_gamut {
     use super::*;
 
@@ -1619,6 +2486,50 @@ mod test {
     }
   }
 
+  mod composite_over {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_blends_half_transparent_red_over_white_in_linear_light() {
+      let red = Rgb::<Srgb>::new(255, 0, 0).with_alpha(0.5);
+      let result = red.composite_over(Rgb::<Srgb>::new(255, 255, 255));
+      let [r, g, b] = result.to_linear().components();
+
+      assert!((r - 1.0).abs() < 1e-10);
+      assert!((g - 0.5).abs() < 1e-10);
+      assert!((b - 0.5).abs() < 1e-10);
+      assert_eq!(result.alpha(), 1.0);
+      assert_eq!(result.to_hex(), "#ffbcbc");
+    }
+
+    #[test]
+    fn it_returns_the_source_when_opaque() {
+      let source = Rgb::<Srgb>::new(30, 120, 200);
+      let result = source.composite_over(Rgb::<Srgb>::new(255, 255, 255));
+
+      assert_eq!(result, source);
+    }
+
+    #[test]
+    fn it_combines_translucent_alphas() {
+      let source = Rgb::<Srgb>::new(255, 0, 0).with_alpha(0.5);
+      let result = source.composite_over(Rgb::<Srgb>::new(0, 0, 255).with_alpha(0.5));
+
+      assert!((result.alpha() - 0.75).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_returns_transparent_black_when_both_are_transparent() {
+      let source = Rgb::<Srgb>::new(255, 0, 0).with_alpha(0.0);
+      let result = source.composite_over(Rgb::<Srgb>::new(0, 0, 255).with_alpha(0.0));
+
+      assert_eq!(result.alpha(), 0.0);
+      assert_eq!(result.components(), [0.0, 0.0, 0.0]);
+    }
+  }
+
   #[cfg(feature = "space-lab")]
   mod compress_to_gamut {
     use super::*;
@@ -1827,6 +2738,24 @@ mod test {
     }
   }
 
+  mod from_f32 {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_creates_from_f32_channels() {
+      assert_eq!(Rgb::<Srgb>::from_f32(1.0, 0.5, 0.0), Rgb::<Srgb>::new(255, 128, 0));
+    }
+
+    #[test]
+    fn it_clamps_out_of_range_inputs() {
+      let rgb = Rgb::<Srgb>::from_f32(1.5, -0.25, f32::NAN);
+
+      assert_eq!(rgb.components(), [1.0, 0.0, 0.0]);
+    }
+  }
+
   mod from_hexcode {
     use pretty_assertions::assert_eq;
 
@@ -1916,6 +2845,62 @@ mod test {
     }
   }
 
+  mod from_hexcode_strict {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_a_prefixed_hex_code() {
+      let rgb = Rgb::<Srgb>::from_hexcode_strict("#FF0000").unwrap();
+
+      assert_eq!(rgb.to_hex(), "#ff0000");
+      assert_eq!(Rgb::<Srgb>::from_hexcode_strict("#F84").unwrap().to_hex(), "#ff8844");
+    }
+
+    #[test]
+    fn it_rejects_a_missing_hash_the_lenient_parser_accepts() {
+      assert!(Rgb::<Srgb>::from_hexcode("FF0000").is_ok());
+      assert_eq!(
+        Rgb::<Srgb>::from_hexcode_strict("FF0000"),
+        Err(Error::MissingHashPrefix {
+          input: "FF0000".to_string(),
+        })
+      );
+    }
+
+    #[test]
+    fn it_rejects_whitespace() {
+      assert_eq!(
+        Rgb::<Srgb>::from_hexcode_strict(" #FF0000"),
+        Err(Error::UnexpectedWhitespace {
+          input: " #FF0000".to_string(),
+        })
+      );
+    }
+
+    #[test]
+    fn it_rejects_non_hex_characters() {
+      assert_eq!(
+        Rgb::<Srgb>::from_hexcode_strict("#+F+F+F"),
+        Err(Error::InvalidHexCharacter {
+          input: "#+F+F+F".to_string(),
+        })
+      );
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_length() {
+      assert_eq!(
+        Rgb::<Srgb>::from_hexcode_strict("#FF00"),
+        Err(Error::InvalidHexLength {
+          input: "#FF00".to_string(),
+          length: 4,
+        })
+      );
+    }
+  }
+
   mod from_normalized {
     use pretty_assertions::assert_eq;
 
@@ -1944,6 +2929,14 @@ mod test {
       assert_eq!(rgb.g(), 0.25);
       assert_eq!(rgb.b(), 0.75);
     }
+
+    #[test]
+    fn it_creates_rgba_from_a_four_element_array() {
+      let rgb = Rgb::<Srgb>::from([1.0, 0.0, 0.0, 0.5]);
+
+      assert_eq!(rgb.components(), [1.0, 0.0, 0.0]);
+      assert_eq!(rgb.alpha(), 0.5);
+    }
   }
 
   #[cfg(feature = "space-cmyk")]
@@ -2041,28 +3034,154 @@ mod test {
     }
   }
 
-  mod gradient_linear {
+  #[cfg(feature = "space-oklch")]
+  mod from_seed {
     use super::*;
 
     #[test]
-    fn zero_steps_is_empty() {
-      let c1 = Rgb::<Srgb>::new(255, 0, 0);
-      let c2 = Rgb::<Srgb>::new(0, 0, 255);
-      assert!(c1.gradient_linear(c2.to_xyz(), 0).is_empty());
+    fn it_is_deterministic() {
+      assert_eq!(Rgb::<Srgb>::from_seed(1234), Rgb::<Srgb>::from_seed(1234));
     }
 
     #[test]
-    fn one_step_returns_self() {
-      let c1 = Rgb::<Srgb>::new(255, 0, 0);
-      let c2 = Rgb::<Srgb>::new(0, 0, 255);
-      let steps = c1.gradient_linear(c2.to_xyz(), 1);
-      assert_eq!(steps.len(), 1);
-      assert_eq!(steps[0].red(), c1.red());
+    fn it_usually_differs_between_seeds() {
+      let colors: Vec<_> = (0..32).map(|seed| Rgb::<Srgb>::from_seed(seed).to_hex()).collect();
+      let distinct = colors
+        .iter()
+        .filter(|c| colors.iter().filter(|o| o == c).count() == 1)
+        .count();
+
+      assert!(distinct >= 28);
     }
 
     #[test]
-    fn two_steps_returns_endpoints() {
-      let c1 = Rgb::<Srgb>::new(255, 0, 0);
+    fn it_produces_uniform_lightness() {
+      for seed in 0..64 {
+        let l = Rgb::<Srgb>::from_seed(seed).to_oklab().l();
+
+        assert!((l - 0.7).abs() < 0.02, "seed {seed} produced lightness {l}");
+      }
+    }
+
+    #[test]
+    fn it_stays_in_gamut() {
+      for seed in 0..64 {
+        assert!(Rgb::<Srgb>::from_seed(seed).is_in_gamut());
+      }
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod from_str_seed {
+    use super::*;
+
+    #[test]
+    fn it_is_deterministic() {
+      assert_eq!(Rgb::<Srgb>::from_str_seed("alice"), Rgb::<Srgb>::from_str_seed("alice"));
+    }
+
+    #[test]
+    fn it_differs_between_strings() {
+      assert_ne!(Rgb::<Srgb>::from_str_seed("alice"), Rgb::<Srgb>::from_str_seed("bob"));
+    }
+  }
+
+  mod from_u16 {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_creates_white_from_max_values() {
+      assert_eq!(Rgb::<Srgb>::from_u16(65535, 65535, 65535).components(), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn it_round_trips_through_to_u16() {
+      let channels = [0, 1234, 65535];
+      let [r, g, b] = channels;
+
+      assert_eq!(Rgb::<Srgb>::from_u16(r, g, b).to_u16(), channels);
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod gamut_mapped {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_leaves_in_gamut_colors_unchanged() {
+      let color = Rgb::<Srgb>::new(200, 100, 50).with_alpha(0.5);
+
+      assert_eq!(color.gamut_mapped(), color);
+    }
+
+    #[test]
+    fn it_maps_oversaturated_colors_to_the_boundary() {
+      let mapped = Rgb::<Srgb>::from_normalized(1.3, 0.4, -0.2).gamut_mapped();
+      let [r, g, b] = mapped.components();
+
+      assert!(mapped.is_in_gamut());
+      assert!([r, g, b].iter().any(|c| !(1e-3..=1.0 - 1e-3).contains(c)));
+    }
+
+    #[test]
+    fn it_has_smaller_hue_error_than_clamping() {
+      let color = Rgb::<Srgb>::from_normalized(1.3, 0.4, -0.2);
+      let hue = Oklch::from(color.to_xyz()).hue();
+      let hue_error = |rgb: Rgb<Srgb>| {
+        let diff = (Oklch::from(rgb.to_xyz()).hue() - hue).rem_euclid(360.0);
+        diff.min(360.0 - diff)
+      };
+
+      let mapped = hue_error(color.gamut_mapped());
+      let clamped = hue_error(color.with_gamut_clipped());
+
+      assert!(mapped < clamped);
+      assert!(mapped < 1.0);
+    }
+
+    #[test]
+    fn it_preserves_lightness() {
+      let color = Rgb::<Srgb>::from_normalized(0.2, 1.2, 0.3);
+      let before = Oklch::from(color.to_xyz()).l();
+      let after = Oklch::from(color.gamut_mapped().to_xyz()).l();
+
+      assert!((before - after).abs() < 1e-3);
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let color = Rgb::<Srgb>::from_normalized(1.3, 0.4, -0.2).with_alpha(0.25);
+
+      assert_eq!(color.gamut_mapped().alpha(), 0.25);
+    }
+  }
+
+  mod gradient_linear {
+    use super::*;
+
+    #[test]
+    fn zero_steps_is_empty() {
+      let c1 = Rgb::<Srgb>::new(255, 0, 0);
+      let c2 = Rgb::<Srgb>::new(0, 0, 255);
+      assert!(c1.gradient_linear(c2.to_xyz(), 0).is_empty());
+    }
+
+    #[test]
+    fn one_step_returns_self() {
+      let c1 = Rgb::<Srgb>::new(255, 0, 0);
+      let c2 = Rgb::<Srgb>::new(0, 0, 255);
+      let steps = c1.gradient_linear(c2.to_xyz(), 1);
+      assert_eq!(steps.len(), 1);
+      assert_eq!(steps[0].red(), c1.red());
+    }
+
+    #[test]
+    fn two_steps_returns_endpoints() {
+      let c1 = Rgb::<Srgb>::new(255, 0, 0);
       let c2 = Rgb::<Srgb>::new(0, 0, 255);
       let steps = c1.gradient_linear(c2.to_xyz(), 2);
       assert_eq!(steps.len(), 2);
@@ -2078,6 +3197,61 @@ mod test {
     }
   }
 
+  mod hash {
+    use std::{
+      collections::{HashSet, hash_map::DefaultHasher},
+      hash::{Hash, Hasher},
+    };
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn hash_of(color: &Rgb<Srgb>) -> u64 {
+      let mut hasher = DefaultHasher::new();
+      color.hash(&mut hasher);
+      hasher.finish()
+    }
+
+    #[test]
+    fn it_hashes_colors_differing_below_one_step_equally() {
+      let a = Rgb::<Srgb>::from_normalized(0.5, 0.25, 0.75);
+      let b = Rgb::<Srgb>::from_normalized(0.5 + 0.1 / 255.0, 0.25 - 0.1 / 255.0, 0.75);
+
+      assert_eq!(a, b);
+      assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn it_hashes_distinct_colors_differently() {
+      let a = Rgb::<Srgb>::new(128, 64, 32);
+      let b = Rgb::<Srgb>::new(128, 64, 33);
+
+      assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn it_deduplicates_in_hash_set() {
+      let mut set = HashSet::new();
+      set.insert(Rgb::<Srgb>::from_normalized(0.5, 0.25, 0.75));
+      set.insert(Rgb::<Srgb>::from_normalized(0.5 + 0.1 / 255.0, 0.25, 0.75));
+      set.insert(Rgb::<Srgb>::new(255, 0, 0));
+
+      assert_eq!(set.len(), 2);
+      assert!(set.contains(&Rgb::<Srgb>::new(255, 0, 0)));
+    }
+
+    #[test]
+    fn it_deduplicates_alphas_differing_below_one_step() {
+      let mut set = HashSet::new();
+      set.insert(Rgb::<Srgb>::new(128, 64, 32).with_alpha(0.4));
+      set.insert(Rgb::<Srgb>::new(128, 64, 32).with_alpha(0.4 + 0.1 / 255.0));
+      set.insert(Rgb::<Srgb>::new(128, 64, 32).with_alpha(0.4 - 1e-12));
+
+      assert_eq!(set.len(), 1);
+    }
+  }
+
   mod increment_b {
     use super::*;
 
@@ -2204,6 +3378,83 @@ mod test {
     }
   }
 
+  mod invert {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_inverts_white_to_black() {
+      assert_eq!(Rgb::<Srgb>::new(255, 255, 255).invert().to_hex(), "#000000");
+    }
+
+    #[test]
+    fn it_is_its_own_inverse() {
+      let color = Rgb::<Srgb>::new(255, 87, 51);
+
+      for (a, b) in color.invert().invert().components().iter().zip(color.components()) {
+        assert!((a - b).abs() < 1e-12);
+      }
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      assert_eq!(Rgb::<Srgb>::new(10, 20, 30).with_alpha(0.3).invert().alpha(), 0.3);
+    }
+  }
+
+  mod invert_linear {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_inverts_white_to_black() {
+      assert_eq!(Rgb::<Srgb>::new(255, 255, 255).invert_linear().to_hex(), "#000000");
+    }
+
+    #[test]
+    fn it_is_its_own_inverse() {
+      let color = Rgb::<Srgb>::new(255, 87, 51);
+      let twice = color.invert_linear().invert_linear();
+
+      for (a, b) in twice.components().iter().zip(color.components()) {
+        assert!((a - b).abs() < 1e-12);
+      }
+    }
+
+    #[test]
+    fn it_differs_from_encoded_inversion_for_mid_gray() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128);
+
+      assert_eq!(gray.invert().to_hex(), "#7f7f7f");
+      assert_eq!(gray.invert_linear().to_hex(), "#e5e5e5");
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      assert_eq!(
+        Rgb::<Srgb>::new(10, 20, 30).with_alpha(0.3).invert_linear().alpha(),
+        0.3
+      );
+    }
+  }
+
+  mod is_clipped {
+    use super::*;
+
+    #[test]
+    fn it_returns_false_for_in_range_colors() {
+      assert!(!Rgb::<Srgb>::new(0, 128, 255).is_clipped());
+    }
+
+    #[test]
+    fn it_returns_true_when_any_channel_is_out_of_range() {
+      assert!(Rgb::<Srgb>::from_normalized(1.1, 0.5, 0.5).is_clipped());
+      assert!(Rgb::<Srgb>::from_normalized(0.5, -0.1, 0.5).is_clipped());
+    }
+  }
+
   mod is_in_gamut {
     use super::*;
 
@@ -2236,6 +3487,77 @@ mod test {
     }
   }
 
+  mod lerp {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_self_at_zero() {
+      let a = Rgb::<Srgb>::new(255, 100, 0);
+      let b = Rgb::<Srgb>::new(0, 50, 255);
+
+      assert_eq!(a.lerp(b, 0.0), a);
+    }
+
+    #[test]
+    fn it_returns_other_at_one() {
+      let a = Rgb::<Srgb>::new(255, 100, 0);
+      let b = Rgb::<Srgb>::new(0, 50, 255);
+
+      assert_eq!(a.lerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn it_averages_linear_light_at_midpoint() {
+      let a = Rgb::<Srgb>::new(0, 0, 0);
+      let b = Rgb::<Srgb>::new(255, 255, 255);
+      let mid = a.lerp(b, 0.5);
+      let expected = (a.to_xyz().luminance() + b.to_xyz().luminance()) / 2.0;
+
+      assert!((mid.to_xyz().luminance() - expected).abs() < 1e-2);
+      assert_ne!(mid.red(), 128);
+    }
+
+    #[test]
+    fn it_interpolates_alpha() {
+      let a = Rgb::<Srgb>::new(0, 0, 0).with_alpha(0.0);
+      let b = Rgb::<Srgb>::new(0, 0, 0);
+
+      assert!((a.lerp(b, 0.25).alpha() - 0.25).abs() < 1e-10);
+    }
+  }
+
+  mod map {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_inverts_a_color() {
+      let rgb = Rgb::<Srgb>::new(255, 100, 0).map(|c| 1.0 - c);
+
+      assert_eq!(rgb.red(), 0);
+      assert_eq!(rgb.green(), 155);
+      assert_eq!(rgb.blue(), 255);
+    }
+
+    #[test]
+    fn it_clamps_results() {
+      let rgb = Rgb::<Srgb>::new(200, 100, 0).map(|c| c * 2.0 - 0.5);
+
+      assert_eq!(rgb.r(), 1.0);
+      assert_eq!(rgb.b(), 0.0);
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let rgb = Rgb::<Srgb>::new(10, 20, 30).with_alpha(0.4).map(|c| c);
+
+      assert_eq!(rgb.alpha(), 0.4);
+    }
+  }
+
   mod mix_linear {
     use super::*;
 
@@ -2317,6 +3639,67 @@ mod test {
     }
   }
 
+  mod mul_scalar {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_is_identity_at_one() {
+      let rgb = Rgb::<Srgb>::new(200, 100, 50);
+
+      assert_eq!(rgb.mul_scalar(1.0), rgb);
+    }
+
+    #[test]
+    fn it_returns_black_at_zero() {
+      let rgb = Rgb::<Srgb>::new(200, 100, 50).mul_scalar(0.0);
+
+      assert_eq!(rgb, Rgb::<Srgb>::new(0, 0, 0));
+    }
+
+    #[test]
+    fn it_doubles_linear_light_not_encoded_value() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128);
+      let brighter = gray.mul_scalar(2.0);
+
+      assert!((brighter.to_linear().r() - gray.to_linear().r() * 2.0).abs() < 1e-2);
+      assert_eq!(brighter.red(), 176);
+    }
+
+    #[test]
+    fn it_clamps_to_white() {
+      assert_eq!(
+        Rgb::<Srgb>::new(200, 200, 200).mul_scalar(10.0),
+        Rgb::<Srgb>::new(255, 255, 255)
+      );
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      assert_eq!(
+        Rgb::<Srgb>::new(10, 20, 30).with_alpha(0.4).mul_scalar(1.5).alpha(),
+        0.4
+      );
+    }
+  }
+
+  mod opacity {
+    use super::*;
+
+    #[test]
+    fn it_returns_alpha_as_a_percentage() {
+      let rgb = Rgb::<Srgb>::new(255, 128, 64).with_alpha(0.25);
+
+      assert!((rgb.opacity() - 25.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_defaults_to_fully_opaque() {
+      assert_eq!(Rgb::<Srgb>::new(255, 128, 64).opacity(), 100.0);
+    }
+  }
+
   mod partial_eq {
     use pretty_assertions::{assert_eq, assert_ne};
 
@@ -2345,6 +3728,41 @@ mod test {
 
       assert_ne!(a, b);
     }
+
+    #[test]
+    fn it_compares_equal_when_alpha_differs_below_one_step() {
+      let a = Rgb::<Srgb>::new(128, 64, 32).with_alpha(0.4);
+      let b = Rgb::<Srgb>::new(128, 64, 32).with_alpha(0.4 + 0.1 / 255.0);
+
+      assert_eq!(a, b);
+    }
+
+    #[test]
+    fn it_is_reflexive_for_nan_alpha() {
+      let mut a = Rgb::<Srgb>::new(128, 64, 32);
+      a.alpha = Component::new(f64::NAN);
+
+      assert_eq!(a, a);
+    }
+  }
+
+  #[cfg(feature = "space-oklab")]
+  mod perceived_lightness {
+    use super::*;
+
+    #[test]
+    fn it_returns_zero_for_black_and_one_for_white() {
+      assert!(Rgb::<Srgb>::new(0, 0, 0).perceived_lightness().abs() < 1e-6);
+      assert!((Rgb::<Srgb>::new(255, 255, 255).perceived_lightness() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_ranks_yellow_lighter_than_blue_despite_equal_hsl_lightness() {
+      let yellow = Rgb::<Srgb>::new(255, 255, 0);
+      let blue = Rgb::<Srgb>::new(0, 0, 255);
+
+      assert!(yellow.perceived_lightness() > blue.perceived_lightness());
+    }
   }
 
   mod perceptually_map_to_gamut {
@@ -2355,26 +3773,135 @@ mod test {
       let mut rgb = Rgb::<Srgb>::from_normalized(1.5, -0.2, 0.5);
       rgb.perceptually_map_to_gamut();
 
-      assert!(rgb.is_in_gamut());
+      assert!(rgb.is_in_gamut());
+    }
+
+    #[test]
+    fn it_returns_unchanged_when_in_gamut() {
+      let mut rgb = Rgb::<Srgb>::from_normalized(0.5, 0.3, 0.8);
+      let orig = rgb;
+      rgb.perceptually_map_to_gamut();
+
+      assert!((rgb.r() - orig.r()).abs() < 1e-10);
+      assert!((rgb.g() - orig.g()).abs() < 1e-10);
+      assert!((rgb.b() - orig.b()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let mut rgb = Rgb::<Srgb>::from_normalized(1.5, 0.5, 0.5).with_alpha(0.6);
+      rgb.perceptually_map_to_gamut();
+
+      assert!((rgb.alpha() - 0.6).abs() < 1e-10);
+    }
+  }
+
+  mod premultiplied {
+    use super::*;
+
+    #[test]
+    fn it_multiplies_channels_by_alpha() {
+      let color = Rgb::<Srgb>::from_normalized(0.8, 0.4, 0.2).with_alpha(0.5);
+      let result = color.premultiplied();
+
+      assert!((result.r() - 0.4).abs() < 1e-10);
+      assert!((result.g() - 0.2).abs() < 1e-10);
+      assert!((result.b() - 0.1).abs() < 1e-10);
+      assert!((result.alpha() - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_leaves_opaque_colors_unchanged() {
+      let color = Rgb::<Srgb>::from_normalized(0.8, 0.4, 0.2);
+
+      assert_eq!(color.premultiplied().components(), color.components());
+    }
+  }
+
+  mod quantize {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_is_identity_at_8_bits() {
+      let color = Rgb::<Srgb>::new(200, 100, 30).with_alpha(0.5);
+
+      assert_eq!(color.quantize(8), color);
+      assert_eq!(color.quantize(8).alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_snaps_channels_to_0_or_1_at_1_bit() {
+      let color = Rgb::<Srgb>::new(200, 100, 128).quantize(1);
+
+      assert_eq!(color.components(), [1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn it_produces_multiples_of_the_step() {
+      let step = 1.0 / 31.0;
+
+      for value in (0..=255).step_by(7) {
+        let color = Rgb::<Srgb>::new(value, 255 - value, value / 2).quantize(5);
+
+        for c in [color.r(), color.g(), color.b()] {
+          let levels = c / step;
+          assert!((levels - levels.round()).abs() < 1e-9);
+        }
+      }
+    }
+
+    #[test]
+    fn it_clamps_bits_to_valid_range() {
+      let color = Rgb::<Srgb>::new(200, 100, 30);
+
+      assert_eq!(color.quantize(0), color.quantize(1));
+      assert_eq!(color.quantize(16), color.quantize(8));
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod rotate_hue {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_rotates_red_toward_green() {
+      let rotated = Rgb::<Srgb>::new(255, 0, 0).rotate_hue(120.0);
+
+      assert!(rotated.g() > rotated.r());
+      assert!(rotated.g() > rotated.b());
+    }
+
+    #[test]
+    fn it_returns_near_identity_for_a_full_turn() {
+      let color = Rgb::<Srgb>::new(200, 80, 40);
+      let rotated = color.rotate_hue(360.0);
+
+      assert!((rotated.r() - color.r()).abs() < 1e-6);
+      assert!((rotated.g() - color.g()).abs() < 1e-6);
+      assert!((rotated.b() - color.b()).abs() < 1e-6);
     }
 
     #[test]
-    fn it_returns_unchanged_when_in_gamut() {
-      let mut rgb = Rgb::<Srgb>::from_normalized(0.5, 0.3, 0.8);
-      let orig = rgb;
-      rgb.perceptually_map_to_gamut();
+    fn it_leaves_grays_unchanged() {
+      let gray = Rgb::<Srgb>::new(128, 128, 128);
 
-      assert!((rgb.r() - orig.r()).abs() < 1e-10);
-      assert!((rgb.g() - orig.g()).abs() < 1e-10);
-      assert!((rgb.b() - orig.b()).abs() < 1e-10);
+      assert_eq!(gray.rotate_hue(90.0), gray);
     }
 
+    #[cfg(feature = "rgb-display-p3")]
     #[test]
-    fn it_preserves_alpha() {
-      let mut rgb = Rgb::<Srgb>::from_normalized(1.5, 0.5, 0.5).with_alpha(0.6);
-      rgb.perceptually_map_to_gamut();
+    fn it_stays_in_the_same_rgb_space() {
+      use crate::space::DisplayP3;
 
-      assert!((rgb.alpha() - 0.6).abs() < 1e-10);
+      let color = Rgb::<DisplayP3>::new(200, 80, 40).with_alpha(0.5);
+      let rotated = color.rotate_hue(180.0);
+
+      assert_eq!(rotated.alpha(), 0.5);
+      assert!((rotated.rotate_hue(180.0).r() - color.r()).abs() < 1e-6);
     }
   }
 
@@ -2414,6 +3941,47 @@ mod test {
     }
   }
 
+  mod scale_into_gamut {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_divides_by_the_brightest_channel() {
+      let rgb = Rgb::<Srgb>::from_normalized(2.0, 1.0, 0.5).scale_into_gamut();
+
+      assert_eq!(rgb.components(), [1.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn it_differs_from_clipping() {
+      let mut clipped = Rgb::<Srgb>::from_normalized(2.0, 1.0, 0.5);
+      clipped.clip_to_gamut();
+
+      assert_eq!(clipped.components(), [1.0, 1.0, 0.5]);
+      assert_eq!(
+        Rgb::<Srgb>::from_normalized(2.0, 1.0, 0.5)
+          .scale_into_gamut()
+          .components(),
+        [1.0, 0.5, 0.25]
+      );
+    }
+
+    #[test]
+    fn it_returns_unchanged_when_no_channel_exceeds_one() {
+      let rgb = Rgb::<Srgb>::from_normalized(0.5, 0.3, 0.8);
+
+      assert_eq!(rgb.scale_into_gamut().components(), rgb.components());
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let rgb = Rgb::<Srgb>::from_normalized(1.5, 0.5, 0.5).with_alpha(0.5);
+
+      assert_eq!(rgb.scale_into_gamut().alpha(), 0.5);
+    }
+  }
+
   mod scale_r {
     use pretty_assertions::assert_eq;
 
@@ -2459,6 +4027,67 @@ mod test {
     }
   }
 
+  mod sepia {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_warms_a_gray() {
+      let sepia = Rgb::<Srgb>::new(128, 128, 128).sepia();
+
+      assert!(sepia.r() > sepia.g());
+      assert!(sepia.g() > sepia.b());
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      assert_eq!(Rgb::<Srgb>::new(128, 128, 128).with_alpha(0.25).sepia().alpha(), 0.25);
+    }
+  }
+
+  mod set_alpha {
+    use super::*;
+
+    #[test]
+    fn it_sets_alpha_in_place() {
+      let mut rgb = Rgb::<Srgb>::new(255, 128, 64);
+      rgb.set_alpha(0.4);
+
+      assert_eq!(rgb.alpha(), 0.4);
+      assert_eq!(format!("{}", rgb), "sRGB(255, 128, 64, 40%)");
+    }
+  }
+
+  #[cfg(feature = "space-oklab")]
+  mod shade {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_black_at_full_amount() {
+      assert_eq!(Rgb::<Srgb>::new(255, 87, 51).shade(1.0).to_hex(), "#000000");
+    }
+
+    #[test]
+    fn it_returns_the_original_at_zero_amount() {
+      assert_eq!(Rgb::<Srgb>::new(255, 87, 51).shade(0.0).to_hex(), "#ff5733");
+    }
+
+    #[test]
+    fn it_darkens_at_partial_amounts() {
+      let color = Rgb::<Srgb>::new(255, 87, 51);
+
+      assert!(color.shade(0.5).to_oklab().l() < color.to_oklab().l());
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      assert_eq!(Rgb::<Srgb>::new(255, 87, 51).with_alpha(0.5).shade(0.5).alpha(), 0.5);
+    }
+  }
+
   mod sub {
     use pretty_assertions::assert_eq;
 
@@ -2487,6 +4116,70 @@ mod test {
     }
   }
 
+  mod swizzle {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_reorders_channels_to_bgr() {
+      let color = Rgb::<Srgb>::new(10, 20, 30).swizzle([Channel::B, Channel::G, Channel::R]);
+
+      assert_eq!([color.red(), color.green(), color.blue()], [30, 20, 10]);
+    }
+
+    #[test]
+    fn it_is_a_no_op_for_the_identity_order() {
+      let color = Rgb::<Srgb>::new(10, 20, 30).with_alpha(0.5);
+      let swizzled = color.swizzle([Channel::R, Channel::G, Channel::B]);
+
+      assert_eq!(swizzled.components(), color.components());
+      assert_eq!(swizzled.alpha(), 0.5);
+    }
+
+    #[test]
+    fn it_allows_repeated_channels() {
+      let color = Rgb::<Srgb>::new(10, 20, 30).swizzle([Channel::G, Channel::G, Channel::G]);
+
+      assert_eq!(color.to_hex(), "#141414");
+    }
+  }
+
+  #[cfg(feature = "space-oklab")]
+  mod tint {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_white_at_full_amount() {
+      assert_eq!(Rgb::<Srgb>::new(255, 87, 51).tint(1.0).to_hex(), "#ffffff");
+    }
+
+    #[test]
+    fn it_returns_the_original_at_zero_amount() {
+      assert_eq!(Rgb::<Srgb>::new(255, 87, 51).tint(0.0).to_hex(), "#ff5733");
+    }
+
+    #[test]
+    fn it_lightens_at_partial_amounts() {
+      let color = Rgb::<Srgb>::new(255, 87, 51);
+
+      assert!(color.tint(0.5).to_oklab().l() > color.to_oklab().l());
+    }
+  }
+
+  mod to_bgr_array {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_channels_in_bgr_order() {
+      assert_eq!(Rgb::<Srgb>::new(10, 20, 30).to_bgr_array(), [30, 20, 10]);
+    }
+  }
+
   mod to_css {
     use pretty_assertions::assert_eq;
 
@@ -2517,6 +4210,22 @@ mod test {
     }
   }
 
+  mod to_f32 {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_normalized_channels() {
+      assert_eq!(Rgb::<Srgb>::from_normalized(1.0, 0.5, 0.25).to_f32(), [1.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn it_preserves_out_of_gamut_values() {
+      assert_eq!(Rgb::<Srgb>::from_normalized(1.5, -0.5, 0.0).to_f32(), [1.5, -0.5, 0.0]);
+    }
+  }
+
   mod to_hex {
     use pretty_assertions::assert_eq;
 
@@ -2877,6 +4586,90 @@ mod test {
     }
   }
 
+  mod to_rgb_with_intent {
+    use super::*;
+
+    #[test]
+    fn it_matches_to_rgb_within_the_same_space() {
+      let color = Rgb::<Srgb>::new(200, 100, 50);
+
+      for intent in [
+        RenderingIntent::AbsoluteColorimetric,
+        RenderingIntent::Perceptual,
+        RenderingIntent::RelativeColorimetric,
+      ] {
+        let result = color.to_rgb_with_intent::<Srgb>(intent);
+
+        assert!((result.r() - color.r()).abs() < 1e-10);
+        assert!((result.g() - color.g()).abs() < 1e-10);
+        assert!((result.b() - color.b()).abs() < 1e-10);
+      }
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let color = Rgb::<Srgb>::new(200, 100, 50).with_alpha(0.5);
+      let result = color.to_rgb_with_intent::<Srgb>(RenderingIntent::AbsoluteColorimetric);
+
+      assert!((result.alpha() - 0.5).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "rgb-display-p3")]
+    #[test]
+    fn it_agrees_for_colorimetric_intents_when_white_points_match() {
+      use crate::space::DisplayP3;
+
+      let color = Rgb::<Srgb>::new(200, 100, 50);
+      let relative = color.to_rgb_with_intent::<DisplayP3>(RenderingIntent::RelativeColorimetric);
+      let absolute = color.to_rgb_with_intent::<DisplayP3>(RenderingIntent::AbsoluteColorimetric);
+
+      assert!((relative.r() - absolute.r()).abs() < 1e-10);
+      assert!((relative.g() - absolute.g()).abs() < 1e-10);
+      assert!((relative.b() - absolute.b()).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "rgb-prophoto-rgb")]
+    #[test]
+    fn it_differs_for_colorimetric_intents_when_white_points_differ() {
+      use crate::space::ProPhotoRgb;
+
+      let color = Rgb::<Srgb>::new(128, 128, 128);
+      let relative = color.to_rgb_with_intent::<ProPhotoRgb>(RenderingIntent::RelativeColorimetric);
+      let absolute = color.to_rgb_with_intent::<ProPhotoRgb>(RenderingIntent::AbsoluteColorimetric);
+
+      assert!((relative.r() - relative.b()).abs() < 1e-3);
+      assert!((relative.b() - absolute.b()).abs() > 1e-3);
+    }
+
+    #[cfg(feature = "rgb-display-p3")]
+    #[test]
+    fn it_maps_out_of_gamut_colors_perceptually() {
+      use crate::space::DisplayP3;
+
+      let p3_red = Rgb::<DisplayP3>::new(255, 0, 0);
+      let result = p3_red.to_rgb_with_intent::<Srgb>(RenderingIntent::Perceptual);
+
+      assert!(result.is_in_gamut());
+      assert!(result.r() > result.g() && result.r() > result.b());
+    }
+  }
+
+  mod to_u16 {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_scales_to_16_bit() {
+      assert_eq!(Rgb::<Srgb>::new(255, 0, 0).to_u16(), [65535, 0, 0]);
+    }
+
+    #[test]
+    fn it_clamps_out_of_gamut_values() {
+      assert_eq!(Rgb::<Srgb>::from_normalized(1.5, -0.5, 0.5).to_u16(), [65535, 0, 32768]);
+    }
+  }
+
   mod to_xyz {
     use super::*;
 
@@ -2920,6 +4713,75 @@ mod test {
     }
   }
 
+  mod to_xyz_unadapted {
+    use super::*;
+
+    #[test]
+    fn it_matches_to_xyz_for_srgb() {
+      let rgb = Rgb::<Srgb>::new(200, 100, 50);
+
+      assert_eq!(rgb.to_xyz_unadapted().components(), rgb.to_xyz().components());
+    }
+
+    #[test]
+    #[cfg(feature = "illuminant-d50")]
+    fn it_ignores_the_color_context() {
+      use crate::Illuminant;
+
+      let d50 = ColorimetricContext::new().with_illuminant(Illuminant::D50);
+      let rgb = Rgb {
+        context: d50,
+        ..Rgb::<Srgb>::new(200, 100, 50)
+      };
+      let xyz = rgb.to_xyz_unadapted();
+
+      assert_eq!(xyz.context().illuminant().name(), "D65");
+      assert_eq!(xyz.components(), Rgb::<Srgb>::new(200, 100, 50).to_xyz().components());
+    }
+
+    #[test]
+    #[cfg(feature = "rgb-prophoto-rgb")]
+    fn it_differs_from_an_adapted_conversion_for_d50_primaries() {
+      use crate::space::ProPhotoRgb;
+
+      let rgb = Rgb::<ProPhotoRgb>::new(200, 100, 50);
+      let unadapted = rgb.to_xyz_unadapted();
+      let adapted = rgb.to_xyz_in(ColorimetricContext::new());
+
+      assert_eq!(unadapted.context().illuminant().name(), "D50");
+      assert!((unadapted.x() - adapted.x()).abs() > 1e-3 || (unadapted.z() - adapted.z()).abs() > 1e-3);
+    }
+  }
+
+  #[cfg(feature = "space-oklab")]
+  mod tone {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_neutral_gray_at_full_amount() {
+      let tone = Rgb::<Srgb>::new(255, 87, 51).tone(1.0);
+      let [_, a, b] = tone.to_oklab().components();
+
+      assert_eq!(tone.to_hex(), "#808080");
+      assert!(a.abs() < 1e-4 && b.abs() < 1e-4);
+    }
+
+    #[test]
+    fn it_returns_the_original_at_zero_amount() {
+      assert_eq!(Rgb::<Srgb>::new(255, 87, 51).tone(0.0).to_hex(), "#ff5733");
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_desaturates_at_partial_amounts() {
+      let color = Rgb::<Srgb>::new(255, 87, 51);
+
+      assert!(color.tone(0.5).to_oklch().c() < color.to_oklch().c());
+    }
+  }
+
   mod try_from_str {
     use pretty_assertions::assert_eq;
 
@@ -2957,6 +4819,52 @@ mod test {
     }
   }
 
+  mod unpremultiplied {
+    use super::*;
+
+    #[test]
+    fn it_roundtrips_with_premultiplied() {
+      for alpha in [0.1, 0.5, 0.75, 1.0] {
+        let color = Rgb::<Srgb>::from_normalized(0.8, 0.4, 0.2).with_alpha(alpha);
+        let result = color.premultiplied().unpremultiplied();
+
+        assert!((result.r() - 0.8).abs() < 1e-10);
+        assert!((result.g() - 0.4).abs() < 1e-10);
+        assert!((result.b() - 0.2).abs() < 1e-10);
+        assert!((result.alpha() - alpha).abs() < 1e-10);
+      }
+    }
+
+    #[test]
+    fn it_zeroes_channels_when_alpha_is_zero() {
+      let color = Rgb::<Srgb>::from_normalized(0.8, 0.4, 0.2).with_alpha(0.0);
+      let result = color.unpremultiplied();
+
+      assert_eq!(result.components(), [0.0, 0.0, 0.0]);
+      assert_eq!(result.alpha(), 0.0);
+    }
+  }
+
+  mod with_alpha {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_a_copy_with_the_given_alpha() {
+      let rgb = Rgb::<Srgb>::new(255, 128, 64).with_alpha(0.5);
+
+      assert_eq!(rgb.alpha(), 0.5);
+      assert_eq!(rgb.to_hex(), "#ff8040");
+    }
+
+    #[test]
+    fn it_clamps_alpha_to_unit_range() {
+      assert_eq!(Rgb::<Srgb>::new(0, 0, 0).with_alpha(1.5).alpha(), 1.0);
+      assert_eq!(Rgb::<Srgb>::new(0, 0, 0).with_alpha(-0.5).alpha(), 0.0);
+    }
+  }
+
   mod with_alpha_flattened {
     use pretty_assertions::assert_eq;
 
@@ -3387,4 +5295,38 @@ mod test {
       assert_eq!(result.red(), 128);
     }
   }
+
+  mod zip_with {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_reproduces_lighten_blend_with_max() {
+      let base = Rgb::<Srgb>::new(200, 50, 120);
+      let blend = Rgb::<Srgb>::new(100, 150, 120);
+      let result = base.zip_with(blend, f64::max);
+
+      assert_eq!(result.red(), 200);
+      assert_eq!(result.green(), 150);
+      assert_eq!(result.blue(), 120);
+    }
+
+    #[test]
+    fn it_clamps_results() {
+      let result = Rgb::<Srgb>::new(200, 50, 0).zip_with(Rgb::<Srgb>::new(200, 10, 0), |a, b| a + b - 0.5);
+
+      assert_eq!(result.r(), 1.0);
+      assert_eq!(result.g(), 0.0);
+    }
+
+    #[test]
+    fn it_preserves_self_alpha() {
+      let result = Rgb::<Srgb>::new(0, 0, 0)
+        .with_alpha(0.3)
+        .zip_with(Rgb::<Srgb>::new(255, 255, 255).with_alpha(0.9), |a, b| (a + b) / 2.0);
+
+      assert_eq!(result.alpha(), 0.3);
+    }
+  }
 }
@@ -1,7 +1,9 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
   ColorimetricContext, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices},
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -18,6 +20,8 @@ impl RgbSpec for DisplayP3 {
     Xy::new_const(0.150, 0.060),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Srgb;
+
+  cached_xyz_matrices!();
 }
 
 impl super::super::Rgb<DisplayP3> {
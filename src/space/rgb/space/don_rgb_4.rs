@@ -1,7 +1,7 @@
 use crate::{
   ColorimetricContext, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices},
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -18,4 +18,6 @@ impl RgbSpec for DonRgb4 {
     Xy::new_const(0.1300, 0.0350),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Gamma(2.2);
+
+  cached_xyz_matrices!();
 }
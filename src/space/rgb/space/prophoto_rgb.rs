@@ -1,7 +1,9 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
   ColorimetricContext, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices},
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -18,6 +20,8 @@ impl RgbSpec for ProPhotoRgb {
     Xy::new_const(0.036598, 0.000105),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::ProPhotoRgb;
+
+  cached_xyz_matrices!();
 }
 
 impl super::super::Rgb<ProPhotoRgb> {
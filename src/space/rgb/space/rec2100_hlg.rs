@@ -1,9 +1,15 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{
   ColorimetricContext, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices},
 };
 
+const LUMA_B: f64 = 0.0593;
+const LUMA_G: f64 = 0.6780;
+const LUMA_R: f64 = 0.2627;
+
 #[derive(Clone, Copy, Debug)]
 pub struct Rec2100Hlg;
 
@@ -18,4 +24,131 @@ impl RgbSpec for Rec2100Hlg {
     Xy::new_const(0.131, 0.046),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Hlg;
+
+  cached_xyz_matrices!();
+}
+
+impl super::super::Rgb<Rec2100Hlg> {
+  /// Creates a new HLG-encoded color from absolute display luminance values in cd/m² (nits).
+  ///
+  /// Applies the inverse of the BT.2100 HLG OOTF for a display with the given nominal peak
+  /// luminance, then encodes the resulting scene light with the HLG OETF. This is the inverse
+  /// of [`to_nits`](Self::to_nits).
+  ///
+  /// ```
+  /// use farg::space::{Rec2100Hlg, Rgb};
+  ///
+  /// let color = Rgb::<Rec2100Hlg>::from_nits(1000.0, 1000.0, 1000.0, 1000.0);
+  /// assert!((color.r() - 1.0).abs() < 1e-6);
+  /// ```
+  pub fn from_nits(r: f64, g: f64, b: f64, peak_luminance: f64) -> Self {
+    let gamma = hlg_system_gamma(peak_luminance);
+    let display_luminance = luminance(r, g, b);
+    let tf = TransferFunction::Hlg;
+
+    if display_luminance <= 0.0 || peak_luminance <= 0.0 {
+      return Self::from_normalized(0.0, 0.0, 0.0);
+    }
+
+    let scene_luminance = (display_luminance / peak_luminance).powf(1.0 / gamma);
+    let scale = peak_luminance * scene_luminance.powf(gamma - 1.0);
+    Self::from_normalized(tf.encode(r / scale), tf.encode(g / scale), tf.encode(b / scale))
+  }
+
+  /// Returns the absolute display luminance of each channel in cd/m² (nits).
+  ///
+  /// Decodes the HLG signal to scene light and applies the BT.2100 HLG OOTF for a display with
+  /// the given nominal peak luminance. The system gamma is `1.2 + 0.42 * log10(peak / 1000)`,
+  /// so a 1000 nit display uses the reference gamma of 1.2. Black level is assumed to be zero.
+  ///
+  /// ```
+  /// use farg::space::{Rec2100Hlg, Rgb};
+  ///
+  /// let [r, g, b] = Rgb::<Rec2100Hlg>::from_normalized(1.0, 1.0, 1.0).to_nits(1000.0);
+  /// assert!((r - 1000.0).abs() < 1e-3);
+  /// ```
+  pub fn to_nits(&self, peak_luminance: f64) -> [f64; 3] {
+    let tf = TransferFunction::Hlg;
+    let [r, g, b] = [tf.decode(self.r()), tf.decode(self.g()), tf.decode(self.b())];
+    let scene_luminance = luminance(r, g, b);
+
+    if scene_luminance <= 0.0 {
+      return [0.0, 0.0, 0.0];
+    }
+
+    let scale = peak_luminance * scene_luminance.powf(hlg_system_gamma(peak_luminance) - 1.0);
+    [r * scale, g * scale, b * scale]
+  }
+}
+
+fn hlg_system_gamma(peak_luminance: f64) -> f64 {
+  1.2 + 0.42 * (peak_luminance / 1000.0).log10()
+}
+
+fn luminance(r: f64, g: f64, b: f64) -> f64 {
+  LUMA_R * r + LUMA_G * g + LUMA_B * b
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::space::Rgb;
+
+  mod from_nits {
+    use super::*;
+
+    #[test]
+    fn it_encodes_zero_to_black() {
+      let color = Rgb::<Rec2100Hlg>::from_nits(0.0, 0.0, 0.0, 1000.0);
+
+      assert_eq!(color.r(), 0.0);
+      assert_eq!(color.g(), 0.0);
+      assert_eq!(color.b(), 0.0);
+    }
+
+    #[test]
+    fn it_roundtrips_through_to_nits() {
+      for peak in [400.0, 1000.0, 2000.0] {
+        let [r, g, b] = Rgb::<Rec2100Hlg>::from_normalized(0.75, 0.5, 0.25).to_nits(peak);
+        let color = Rgb::<Rec2100Hlg>::from_nits(r, g, b, peak);
+
+        assert!((color.r() - 0.75).abs() < 1e-10);
+        assert!((color.g() - 0.5).abs() < 1e-10);
+        assert!((color.b() - 0.25).abs() < 1e-10);
+      }
+    }
+  }
+
+  mod to_nits {
+    use super::*;
+
+    #[test]
+    fn it_maps_peak_white_to_peak_luminance() {
+      for peak in [400.0, 1000.0, 2000.0] {
+        let [r, g, b] = Rgb::<Rec2100Hlg>::from_normalized(1.0, 1.0, 1.0).to_nits(peak);
+
+        assert!((r - peak).abs() < peak * 1e-6);
+        assert!((g - peak).abs() < peak * 1e-6);
+        assert!((b - peak).abs() < peak * 1e-6);
+      }
+    }
+
+    #[test]
+    fn it_maps_zero_to_zero_nits() {
+      let [r, g, b] = Rgb::<Rec2100Hlg>::from_normalized(0.0, 0.0, 0.0).to_nits(1000.0);
+
+      assert_eq!(r, 0.0);
+      assert_eq!(g, 0.0);
+      assert_eq!(b, 0.0);
+    }
+
+    #[test]
+    fn it_applies_higher_system_gamma_on_brighter_displays() {
+      let color = Rgb::<Rec2100Hlg>::from_normalized(0.5, 0.5, 0.5);
+      let [dim, _, _] = color.to_nits(1000.0);
+      let [bright, _, _] = color.to_nits(4000.0);
+
+      assert!(dim / 1000.0 > bright / 4000.0);
+    }
+  }
 }
@@ -1,7 +1,7 @@
 use crate::{
   ColorimetricContext, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices},
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -18,4 +18,97 @@ impl RgbSpec for Rec2100Pq {
     Xy::new_const(0.131, 0.046),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Pq;
+
+  cached_xyz_matrices!();
+}
+
+impl super::super::Rgb<Rec2100Pq> {
+  /// Creates a new PQ-encoded color from absolute display luminance values in cd/m² (nits).
+  ///
+  /// Each channel is encoded with the SMPTE ST 2084 inverse EOTF, where 10000 nits maps to a
+  /// code value of 1.0. Negative values encode to 0.0.
+  ///
+  /// ```
+  /// use farg::space::{Rec2100Pq, Rgb};
+  ///
+  /// let color = Rgb::<Rec2100Pq>::from_nits(10000.0, 10000.0, 10000.0);
+  /// assert!((color.r() - 1.0).abs() < 1e-10);
+  /// ```
+  pub fn from_nits(r: f64, g: f64, b: f64) -> Self {
+    let tf = TransferFunction::Pq;
+    Self::from_normalized(tf.encode(r), tf.encode(g), tf.encode(b))
+  }
+
+  /// Returns the absolute display luminance of each channel in cd/m² (nits).
+  ///
+  /// Applies the SMPTE ST 2084 EOTF, mapping a code value of 1.0 to 10000 nits.
+  ///
+  /// ```
+  /// use farg::space::{Rec2100Pq, Rgb};
+  ///
+  /// let [r, _, _] = Rgb::<Rec2100Pq>::from_normalized(1.0, 0.0, 0.0).to_nits();
+  /// assert!((r - 10000.0).abs() < 1e-6);
+  /// ```
+  pub fn to_nits(&self) -> [f64; 3] {
+    let tf = TransferFunction::Pq;
+    [tf.decode(self.r()), tf.decode(self.g()), tf.decode(self.b())]
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::space::Rgb;
+
+  mod from_nits {
+    use super::*;
+
+    #[test]
+    fn it_encodes_peak_luminance_to_one() {
+      let color = Rgb::<Rec2100Pq>::from_nits(10000.0, 10000.0, 10000.0);
+
+      assert!((color.r() - 1.0).abs() < 1e-10);
+      assert!((color.g() - 1.0).abs() < 1e-10);
+      assert!((color.b() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_encodes_zero_to_zero() {
+      let color = Rgb::<Rec2100Pq>::from_nits(0.0, 0.0, 0.0);
+
+      assert!(color.r().abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_roundtrips_through_to_nits() {
+      let color = Rgb::<Rec2100Pq>::from_nits(100.0, 203.0, 1000.0);
+      let [r, g, b] = color.to_nits();
+
+      assert!((r - 100.0).abs() < 1e-6);
+      assert!((g - 203.0).abs() < 1e-6);
+      assert!((b - 1000.0).abs() < 1e-6);
+    }
+  }
+
+  mod to_nits {
+    use super::*;
+
+    #[test]
+    fn it_maps_one_to_10000_nits() {
+      let [r, g, b] = Rgb::<Rec2100Pq>::from_normalized(1.0, 1.0, 1.0).to_nits();
+
+      assert!((r - 10000.0).abs() < 1e-6);
+      assert!((g - 10000.0).abs() < 1e-6);
+      assert!((b - 10000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_maps_zero_to_zero_nits() {
+      let [r, g, b] = Rgb::<Rec2100Pq>::from_normalized(0.0, 0.0, 0.0).to_nits();
+
+      assert_eq!(r, 0.0);
+      assert_eq!(g, 0.0);
+      assert_eq!(b, 0.0);
+    }
+  }
 }
@@ -1,7 +1,7 @@
 use crate::{
   ColorimetricContext, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices},
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -18,4 +18,6 @@ impl RgbSpec for Rec709 {
     Xy::new_const(0.150, 0.060),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Bt709;
+
+  cached_xyz_matrices!();
 }
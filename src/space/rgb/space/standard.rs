@@ -1,9 +1,175 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+#[cfg(feature = "image-interop")]
+use crate::space::ColorSpace;
 use crate::{
-  ColorimetricContext, Illuminant, Observer,
+  ColorimetricContext, Error, Illuminant, Observer,
   chromaticity::Xy,
-  space::rgb::{RgbPrimaries, RgbSpec, TransferFunction},
+  space::{
+    Xyz,
+    rgb::{RgbPrimaries, RgbSpec, TransferFunction, cached_xyz_matrices, transfer::srgb_decode_const},
+  },
 };
 
+/// The CSS Color Level 4 named color keywords and their sRGB values, sorted by name.
+const CSS_NAMED_COLORS: [(&str, [u8; 3]); 148] = [
+  ("aliceblue", [240, 248, 255]),
+  ("antiquewhite", [250, 235, 215]),
+  ("aqua", [0, 255, 255]),
+  ("aquamarine", [127, 255, 212]),
+  ("azure", [240, 255, 255]),
+  ("beige", [245, 245, 220]),
+  ("bisque", [255, 228, 196]),
+  ("black", [0, 0, 0]),
+  ("blanchedalmond", [255, 235, 205]),
+  ("blue", [0, 0, 255]),
+  ("blueviolet", [138, 43, 226]),
+  ("brown", [165, 42, 42]),
+  ("burlywood", [222, 184, 135]),
+  ("cadetblue", [95, 158, 160]),
+  ("chartreuse", [127, 255, 0]),
+  ("chocolate", [210, 105, 30]),
+  ("coral", [255, 127, 80]),
+  ("cornflowerblue", [100, 149, 237]),
+  ("cornsilk", [255, 248, 220]),
+  ("crimson", [220, 20, 60]),
+  ("cyan", [0, 255, 255]),
+  ("darkblue", [0, 0, 139]),
+  ("darkcyan", [0, 139, 139]),
+  ("darkgoldenrod", [184, 134, 11]),
+  ("darkgray", [169, 169, 169]),
+  ("darkgreen", [0, 100, 0]),
+  ("darkgrey", [169, 169, 169]),
+  ("darkkhaki", [189, 183, 107]),
+  ("darkmagenta", [139, 0, 139]),
+  ("darkolivegreen", [85, 107, 47]),
+  ("darkorange", [255, 140, 0]),
+  ("darkorchid", [153, 50, 204]),
+  ("darkred", [139, 0, 0]),
+  ("darksalmon", [233, 150, 122]),
+  ("darkseagreen", [143, 188, 143]),
+  ("darkslateblue", [72, 61, 139]),
+  ("darkslategray", [47, 79, 79]),
+  ("darkslategrey", [47, 79, 79]),
+  ("darkturquoise", [0, 206, 209]),
+  ("darkviolet", [148, 0, 211]),
+  ("deeppink", [255, 20, 147]),
+  ("deepskyblue", [0, 191, 255]),
+  ("dimgray", [105, 105, 105]),
+  ("dimgrey", [105, 105, 105]),
+  ("dodgerblue", [30, 144, 255]),
+  ("firebrick", [178, 34, 34]),
+  ("floralwhite", [255, 250, 240]),
+  ("forestgreen", [34, 139, 34]),
+  ("fuchsia", [255, 0, 255]),
+  ("gainsboro", [220, 220, 220]),
+  ("ghostwhite", [248, 248, 255]),
+  ("gold", [255, 215, 0]),
+  ("goldenrod", [218, 165, 32]),
+  ("gray", [128, 128, 128]),
+  ("green", [0, 128, 0]),
+  ("greenyellow", [173, 255, 47]),
+  ("grey", [128, 128, 128]),
+  ("honeydew", [240, 255, 240]),
+  ("hotpink", [255, 105, 180]),
+  ("indianred", [205, 92, 92]),
+  ("indigo", [75, 0, 130]),
+  ("ivory", [255, 255, 240]),
+  ("khaki", [240, 230, 140]),
+  ("lavender", [230, 230, 250]),
+  ("lavenderblush", [255, 240, 245]),
+  ("lawngreen", [124, 252, 0]),
+  ("lemonchiffon", [255, 250, 205]),
+  ("lightblue", [173, 216, 230]),
+  ("lightcoral", [240, 128, 128]),
+  ("lightcyan", [224, 255, 255]),
+  ("lightgoldenrodyellow", [250, 250, 210]),
+  ("lightgray", [211, 211, 211]),
+  ("lightgreen", [144, 238, 144]),
+  ("lightgrey", [211, 211, 211]),
+  ("lightpink", [255, 182, 193]),
+  ("lightsalmon", [255, 160, 122]),
+  ("lightseagreen", [32, 178, 170]),
+  ("lightskyblue", [135, 206, 250]),
+  ("lightslategray", [119, 136, 153]),
+  ("lightslategrey", [119, 136, 153]),
+  ("lightsteelblue", [176, 196, 222]),
+  ("lightyellow", [255, 255, 224]),
+  ("lime", [0, 255, 0]),
+  ("limegreen", [50, 205, 50]),
+  ("linen", [250, 240, 230]),
+  ("magenta", [255, 0, 255]),
+  ("maroon", [128, 0, 0]),
+  ("mediumaquamarine", [102, 205, 170]),
+  ("mediumblue", [0, 0, 205]),
+  ("mediumorchid", [186, 85, 211]),
+  ("mediumpurple", [147, 112, 219]),
+  ("mediumseagreen", [60, 179, 113]),
+  ("mediumslateblue", [123, 104, 238]),
+  ("mediumspringgreen", [0, 250, 154]),
+  ("mediumturquoise", [72, 209, 204]),
+  ("mediumvioletred", [199, 21, 133]),
+  ("midnightblue", [25, 25, 112]),
+  ("mintcream", [245, 255, 250]),
+  ("mistyrose", [255, 228, 225]),
+  ("moccasin", [255, 228, 181]),
+  ("navajowhite", [255, 222, 173]),
+  ("navy", [0, 0, 128]),
+  ("oldlace", [253, 245, 230]),
+  ("olive", [128, 128, 0]),
+  ("olivedrab", [107, 142, 35]),
+  ("orange", [255, 165, 0]),
+  ("orangered", [255, 69, 0]),
+  ("orchid", [218, 112, 214]),
+  ("palegoldenrod", [238, 232, 170]),
+  ("palegreen", [152, 251, 152]),
+  ("paleturquoise", [175, 238, 238]),
+  ("palevioletred", [219, 112, 147]),
+  ("papayawhip", [255, 239, 213]),
+  ("peachpuff", [255, 218, 185]),
+  ("peru", [205, 133, 63]),
+  ("pink", [255, 192, 203]),
+  ("plum", [221, 160, 221]),
+  ("powderblue", [176, 224, 230]),
+  ("purple", [128, 0, 128]),
+  ("rebeccapurple", [102, 51, 153]),
+  ("red", [255, 0, 0]),
+  ("rosybrown", [188, 143, 143]),
+  ("royalblue", [65, 105, 225]),
+  ("saddlebrown", [139, 69, 19]),
+  ("salmon", [250, 128, 114]),
+  ("sandybrown", [244, 164, 96]),
+  ("seagreen", [46, 139, 87]),
+  ("seashell", [255, 245, 238]),
+  ("sienna", [160, 82, 45]),
+  ("silver", [192, 192, 192]),
+  ("skyblue", [135, 206, 235]),
+  ("slateblue", [106, 90, 205]),
+  ("slategray", [112, 128, 144]),
+  ("slategrey", [112, 128, 144]),
+  ("snow", [255, 250, 250]),
+  ("springgreen", [0, 255, 127]),
+  ("steelblue", [70, 130, 180]),
+  ("tan", [210, 180, 140]),
+  ("teal", [0, 128, 128]),
+  ("thistle", [216, 191, 216]),
+  ("tomato", [255, 99, 71]),
+  ("turquoise", [64, 224, 208]),
+  ("violet", [238, 130, 238]),
+  ("wheat", [245, 222, 179]),
+  ("white", [255, 255, 255]),
+  ("whitesmoke", [245, 245, 245]),
+  ("yellow", [255, 255, 0]),
+  ("yellowgreen", [154, 205, 50]),
+];
+
+/// Linear sRGB to XYZ matrix for the D65 / CIE 1931 2° context, matching [`Srgb::xyz_matrix`].
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+  [0.4124574455823677, 0.3575758652455159, 0.1804372478263997],
+  [0.21267337037840833, 0.7151517304910318, 0.07217489913055988],
+  [0.019333942761673464, 0.1191919550818386, 0.9503028385523719],
+];
+
 /// The standard RGB (sRGB) color space specification (IEC 61966-2-1).
 ///
 /// Uses D65 illuminant, CIE 1931 2 degree observer, and the sRGB transfer function.
@@ -21,9 +187,93 @@ impl RgbSpec for Srgb {
     Xy::new_const(0.15, 0.06),
   );
   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Srgb;
+
+  cached_xyz_matrices!();
 }
 
 impl super::super::Rgb<Srgb> {
+  /// Creates a new sRGB color from a CSS named color keyword (e.g. `"rebeccapurple"`).
+  ///
+  /// Matching is case-insensitive and ignores surrounding whitespace.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let color = Rgb::<Srgb>::from_name("RebeccaPurple").unwrap();
+  /// assert_eq!(color.to_hex(), "#663399");
+  /// ```
+  pub fn from_name(name: impl Into<String>) -> Result<Self, Error> {
+    let name = name.into();
+    let key = name.trim().to_ascii_lowercase();
+
+    CSS_NAMED_COLORS
+      .binary_search_by(|(candidate, _)| (*candidate).cmp(key.as_str()))
+      .map(|index| {
+        let [r, g, b] = CSS_NAMED_COLORS[index].1;
+        Self::new(r, g, b)
+      })
+      .map_err(|_| Error::UnknownColorName {
+        name,
+      })
+  }
+
+  /// Returns the CSS named color keyword closest to this color by Oklab distance.
+  ///
+  /// Alpha is ignored. When several keywords share the same value (e.g. `aqua` and
+  /// `cyan`), the alphabetically first is returned.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// assert_eq!(Rgb::<Srgb>::new(250, 5, 10).nearest_name(), "red");
+  /// ```
+  #[cfg(feature = "space-oklab")]
+  pub fn nearest_name(&self) -> &'static str {
+    let [l, a, b] = self.to_oklab().components();
+    let mut nearest = CSS_NAMED_COLORS[0].0;
+    let mut min_distance = f64::MAX;
+
+    for (name, [r, g, bl]) in CSS_NAMED_COLORS {
+      let [l2, a2, b2] = Self::new(r, g, bl).to_oklab().components();
+      let distance = (l - l2).powi(2) + (a - a2).powi(2) + (b - b2).powi(2);
+
+      if distance < min_distance {
+        min_distance = distance;
+        nearest = name;
+      }
+    }
+
+    nearest
+  }
+
+  /// Converts to CIE XYZ in a const context.
+  ///
+  /// Produces the same result as [`to_xyz`](Self::to_xyz) for use in `const` items. The sRGB
+  /// decode is evaluated with series approximations of `ln` and `exp` (since `powf` is not
+  /// `const`), and the RGB-to-XYZ matrix is a precomputed copy of the runtime matrix, so values
+  /// agree with the runtime path to within about 1e-12. The result is in the default
+  /// D65 / CIE 1931 2° context.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb, Xyz};
+  ///
+  /// const WHITE_XYZ: Xyz = Rgb::<Srgb>::new_const(255, 255, 255).to_xyz_const();
+  /// assert!((WHITE_XYZ.y() - 1.0).abs() < 1e-10);
+  /// ```
+  pub const fn to_xyz_const(&self) -> Xyz {
+    let r = srgb_decode_const(self.r.0);
+    let g = srgb_decode_const(self.g.0);
+    let b = srgb_decode_const(self.b.0);
+    let [m0, m1, m2] = SRGB_TO_XYZ;
+
+    Xyz::new_const(
+      m0[0] * r + m0[1] * g + m0[2] * b,
+      m1[0] * r + m1[1] * g + m1[2] * b,
+      m2[0] * r + m2[1] * g + m2[2] * b,
+    )
+    .with_alpha_const(self.alpha.0)
+  }
+
   /// Returns this color as a CSS Color Level 4 `rgb(...)` string.
   ///
   /// Uses space-separated modern syntax with integer 0-255 channel values.
@@ -50,4 +300,276 @@ impl super::super::Rgb<Srgb> {
       format!("rgb({} {} {})", self.red(), self.green(), self.blue())
     }
   }
+
+  /// Returns a tonal scale of `count` sRGB colors from near-white to near-black at this color's
+  /// hue.
+  ///
+  /// See [`Oklch::tonal_scale`](crate::space::Oklch::tonal_scale) for how lightness and chroma
+  /// are chosen. Every step is within the sRGB gamut.
+  ///
+  /// ```
+  /// use farg::space::{Rgb, Srgb};
+  ///
+  /// let scale = Rgb::<Srgb>::new(59, 130, 246).tonal_scale(11);
+  ///
+  /// assert_eq!(scale.len(), 11);
+  /// ```
+  #[cfg(feature = "space-oklch")]
+  pub fn tonal_scale(&self, count: usize) -> Vec<Self> {
+    use crate::space::ColorSpace;
+
+    self
+      .to_oklch()
+      .tonal_scale(count)
+      .into_iter()
+      .map(|step| {
+        let mut rgb = step.to_rgb::<Srgb>();
+        rgb.clip_to_gamut();
+        rgb
+      })
+      .collect()
+  }
+}
+
+#[cfg(feature = "image-interop")]
+impl From<image::Rgb<u8>> for super::super::Rgb<Srgb> {
+  fn from(image::Rgb([r, g, b]): image::Rgb<u8>) -> Self {
+    Self::new(r, g, b)
+  }
+}
+
+#[cfg(feature = "image-interop")]
+impl From<image::Rgba<u8>> for super::super::Rgb<Srgb> {
+  fn from(image::Rgba([r, g, b, alpha]): image::Rgba<u8>) -> Self {
+    Self::new(r, g, b).with_alpha(alpha as f64 / 255.0)
+  }
+}
+
+#[cfg(feature = "palette-interop")]
+impl From<palette::Srgb<f32>> for super::super::Rgb<Srgb> {
+  fn from(rgb: palette::Srgb<f32>) -> Self {
+    Self::from_f32(rgb.red, rgb.green, rgb.blue)
+  }
+}
+
+#[cfg(feature = "palette-interop")]
+impl From<palette::Srgb<u8>> for super::super::Rgb<Srgb> {
+  fn from(rgb: palette::Srgb<u8>) -> Self {
+    Self::new(rgb.red, rgb.green, rgb.blue)
+  }
+}
+
+#[cfg(feature = "image-interop")]
+impl From<super::super::Rgb<Srgb>> for image::Rgb<u8> {
+  fn from(rgb: super::super::Rgb<Srgb>) -> Self {
+    Self([rgb.red(), rgb.green(), rgb.blue()])
+  }
+}
+
+#[cfg(feature = "image-interop")]
+impl From<super::super::Rgb<Srgb>> for image::Rgba<u8> {
+  fn from(rgb: super::super::Rgb<Srgb>) -> Self {
+    Self([rgb.red(), rgb.green(), rgb.blue(), (rgb.alpha() * 255.0).round() as u8])
+  }
+}
+
+#[cfg(feature = "palette-interop")]
+impl From<super::super::Rgb<Srgb>> for palette::Srgb<f32> {
+  fn from(rgb: super::super::Rgb<Srgb>) -> Self {
+    let [r, g, b] = rgb.to_f32();
+    Self::new(r, g, b)
+  }
+}
+
+#[cfg(feature = "palette-interop")]
+impl From<super::super::Rgb<Srgb>> for palette::Srgb<u8> {
+  fn from(rgb: super::super::Rgb<Srgb>) -> Self {
+    Self::new(rgb.red(), rgb.green(), rgb.blue())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::space::Rgb;
+
+  mod css_named_colors {
+    use super::*;
+
+    #[test]
+    fn it_is_sorted_by_name() {
+      assert!(CSS_NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+  }
+
+  mod from_name {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_resolves_css_keywords() {
+      assert_eq!(Rgb::<Srgb>::from_name("red").unwrap(), Rgb::<Srgb>::new(255, 0, 0));
+      assert_eq!(Rgb::<Srgb>::from_name("rebeccapurple").unwrap().to_hex(), "#663399");
+    }
+
+    #[test]
+    fn it_ignores_case_and_whitespace() {
+      assert_eq!(Rgb::<Srgb>::from_name("  CornflowerBlue ").unwrap().to_hex(), "#6495ed");
+    }
+
+    #[test]
+    fn it_returns_error_for_unknown_name() {
+      assert_eq!(
+        Rgb::<Srgb>::from_name("notacolor").unwrap_err(),
+        Error::UnknownColorName {
+          name: "notacolor".to_string(),
+        }
+      );
+    }
+  }
+
+  #[cfg(feature = "image-interop")]
+  mod image_interop {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::space::Rgb;
+
+    #[test]
+    fn it_round_trips_8_bit_pixels_exactly() {
+      for pixel in [[0, 0, 0], [255, 255, 255], [1, 128, 254], [200, 80, 40]] {
+        let color = Rgb::<Srgb>::from(image::Rgb(pixel));
+
+        assert_eq!(image::Rgb::<u8>::from(color), image::Rgb(pixel));
+      }
+    }
+
+    #[test]
+    fn it_carries_alpha_through_rgba() {
+      let color = Rgb::<Srgb>::from(image::Rgba([200, 80, 40, 128]));
+
+      assert!((color.alpha() - 128.0 / 255.0).abs() < 1e-12);
+      assert_eq!(image::Rgba::<u8>::from(color), image::Rgba([200, 80, 40, 128]));
+    }
+
+    #[test]
+    fn it_writes_opaque_alpha_for_opaque_colors() {
+      assert_eq!(
+        image::Rgba::<u8>::from(Rgb::<Srgb>::new(10, 20, 30)),
+        image::Rgba([10, 20, 30, 255])
+      );
+    }
+  }
+
+  #[cfg(feature = "space-oklab")]
+  mod nearest_name {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_own_name_for_exact_css_colors() {
+      for name in ["red", "navy", "rebeccapurple", "papayawhip", "white", "black"] {
+        assert_eq!(Rgb::<Srgb>::from_name(name).unwrap().nearest_name(), name);
+      }
+    }
+
+    #[test]
+    fn it_resolves_slightly_off_red_to_red() {
+      assert_eq!(Rgb::<Srgb>::new(250, 5, 10).nearest_name(), "red");
+    }
+
+    #[test]
+    fn it_returns_first_alphabetical_name_for_duplicates() {
+      assert_eq!(Rgb::<Srgb>::new(0, 255, 255).nearest_name(), "aqua");
+    }
+  }
+
+  #[cfg(feature = "palette-interop")]
+  mod palette_interop {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::space::Rgb;
+
+    #[test]
+    fn it_round_trips_through_palette_srgb() {
+      let color = Rgb::<Srgb>::new(200, 80, 40);
+      let back = Rgb::<Srgb>::from(palette::Srgb::<f32>::from(color));
+
+      assert!((back.r() - color.r()).abs() < 1e-6);
+      assert!((back.g() - color.g()).abs() < 1e-6);
+      assert!((back.b() - color.b()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_normalizes_8_bit_palette_colors() {
+      let color = Rgb::<Srgb>::from(palette::Srgb::new(255u8, 0, 51));
+
+      assert_eq!(color.components(), [1.0, 0.0, 0.2]);
+      assert_eq!(palette::Srgb::<u8>::from(color), palette::Srgb::new(255u8, 0, 51));
+    }
+  }
+
+  mod to_xyz_const {
+    use super::*;
+    use crate::space::ColorSpace;
+
+    #[test]
+    fn it_matches_runtime_to_xyz() {
+      for [r, g, b] in [
+        [255, 255, 255],
+        [0, 0, 0],
+        [255, 0, 0],
+        [18, 200, 97],
+        [128, 128, 128],
+        [1, 2, 3],
+      ] {
+        let color = Rgb::<Srgb>::new(r, g, b);
+        let expected = color.to_xyz();
+        let result = color.to_xyz_const();
+
+        assert!((result.x() - expected.x()).abs() < 1e-4);
+        assert!((result.y() - expected.y()).abs() < 1e-4);
+        assert!((result.z() - expected.z()).abs() < 1e-4);
+      }
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let color = Rgb::<Srgb>::new(255, 0, 0).with_alpha(0.25);
+
+      assert_eq!(color.to_xyz_const().alpha(), 0.25);
+    }
+
+    #[test]
+    fn it_evaluates_in_const_context() {
+      const WHITE_XYZ: Xyz = Rgb::<Srgb>::new_const(255, 255, 255).to_xyz_const();
+
+      assert!((WHITE_XYZ.x() - 0.95047).abs() < 1e-4);
+      assert!((WHITE_XYZ.y() - 1.0).abs() < 1e-10);
+    }
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod tonal_scale {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::space::ColorSpace;
+
+    #[test]
+    fn it_returns_in_gamut_srgb_steps_from_light_to_dark() {
+      let scale = Rgb::<Srgb>::new(59, 130, 246).with_alpha(0.5).tonal_scale(9);
+
+      assert_eq!(scale.len(), 9);
+      assert!(scale.iter().all(|step| step.is_in_gamut() && step.alpha() == 0.5));
+      assert!(
+        scale
+          .windows(2)
+          .all(|pair| pair[0].to_oklch().l() > pair[1].to_oklch().l())
+      );
+    }
+  }
 }
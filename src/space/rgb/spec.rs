@@ -1,7 +1,63 @@
+#[cfg(feature = "std")]
 use std::sync::OnceLock;
 
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use once_cell::race::OnceBox;
+
 use super::{RgbPrimaries, TransferFunction};
-use crate::{ColorimetricContext, matrix::Matrix3};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::{ColorimetricContext, chromaticity::Xy, matrix::Matrix3};
+
+/// A lazily initialized matrix held in a `static`.
+#[cfg(feature = "std")]
+pub(crate) type MatrixCell = OnceLock<Matrix3>;
+
+/// A lazily initialized matrix held in a `static`.
+#[cfg(not(feature = "std"))]
+pub(crate) type MatrixCell = OnceBox<Matrix3>;
+
+/// Stands in for `once_cell`'s `OnceBox` when neither `std` nor `libm` is enabled, so the
+/// `compile_error!` in the crate root is the only error such a build reports.
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub(crate) struct OnceBox<T>(std::marker::PhantomData<T>);
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+impl<T> OnceBox<T> {
+  pub(crate) const fn new() -> Self {
+    Self(std::marker::PhantomData)
+  }
+
+  pub(crate) fn get(&self) -> Option<&T> {
+    None
+  }
+
+  pub(crate) fn get_or_init(&self, _init: impl FnOnce() -> Box<T>) -> &T {
+    unreachable!("farg cannot be built without the `std` or `libm` feature")
+  }
+}
+
+/// Overrides the [`RgbSpec`] matrix accessors inside an `impl RgbSpec` block.
+///
+/// Each built-in spec expands this to give its matrices their own statics, skipping the registry
+/// lookup the default methods need to tell implementors apart.
+macro_rules! cached_xyz_matrices {
+  () => {
+    fn inversed_xyz_matrix() -> &'static $crate::matrix::Matrix3 {
+      static MATRIX: $crate::space::rgb::MatrixCell = $crate::space::rgb::MatrixCell::new();
+      $crate::space::rgb::cached_matrix(&MATRIX, || Self::xyz_matrix().inverse())
+    }
+
+    fn xyz_matrix() -> &'static $crate::matrix::Matrix3 {
+      static MATRIX: $crate::space::rgb::MatrixCell = $crate::space::rgb::MatrixCell::new();
+      $crate::space::rgb::cached_matrix(&MATRIX, || {
+        Self::PRIMARIES.calculate_xyz_matrix(Self::CONTEXT.reference_white())
+      })
+    }
+  };
+}
+
+pub(crate) use cached_xyz_matrices;
 
 /// Defines the characteristics of an RGB color space.
 ///
@@ -18,15 +74,271 @@ pub trait RgbSpec: Clone + Copy + Send + Sync {
   /// The electro-optical transfer function (gamma curve).
   const TRANSFER_FUNCTION: TransferFunction;
 
+  /// Converts an encoded channel value to display light with this space's reference EOTF.
+  ///
+  /// See [`TransferFunction::eotf`].
+  fn eotf(encoded: f64) -> f64 {
+    Self::TRANSFER_FUNCTION.eotf(encoded)
+  }
+
+  /// Converts display light to an encoded channel value, inverting [`Self::eotf`].
+  fn inverse_eotf(display: f64) -> f64 {
+    Self::TRANSFER_FUNCTION.inverse_eotf(display)
+  }
+
+  /// Converts an encoded channel value back to scene light, inverting [`Self::oetf`].
+  fn inverse_oetf(encoded: f64) -> f64 {
+    Self::TRANSFER_FUNCTION.inverse_oetf(encoded)
+  }
+
   /// Returns the cached XYZ-to-RGB matrix (inverse of the RGB-to-XYZ matrix).
+  ///
+  /// The default implementation caches the matrix per implementing type, keyed by its type
+  /// name and primaries.
   fn inversed_xyz_matrix() -> &'static Matrix3 {
-    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
-    MATRIX.get_or_init(|| Self::xyz_matrix().inverse())
+    registered_matrix(MatrixKey::new::<Self>(true), || Self::xyz_matrix().inverse())
+  }
+
+  /// Converts scene light to an encoded channel value with this space's camera OETF.
+  ///
+  /// See [`TransferFunction::oetf`].
+  fn oetf(scene: f64) -> f64 {
+    Self::TRANSFER_FUNCTION.oetf(scene)
+  }
+
+  /// Returns the red, green, and blue primary chromaticities, in that order.
+  ///
+  /// ```
+  /// use farg::space::{RgbSpec, Srgb};
+  ///
+  /// let [red, _, _] = Srgb::primaries();
+  /// assert!((red.x() - 0.64).abs() < 1e-4);
+  /// ```
+  fn primaries() -> [Xy; 3] {
+    [
+      *Self::PRIMARIES.red(),
+      *Self::PRIMARIES.green(),
+      *Self::PRIMARIES.blue(),
+    ]
+  }
+
+  /// Returns the chromaticity of this space's reference white.
+  fn white_point() -> Xy {
+    Self::CONTEXT.white_point_xy()
   }
 
   /// Returns the cached RGB-to-XYZ matrix, computed from primaries and reference white.
+  ///
+  /// Like [`Self::inversed_xyz_matrix`], the default implementation caches the matrix per
+  /// implementing type.
   fn xyz_matrix() -> &'static Matrix3 {
-    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
-    MATRIX.get_or_init(|| Self::PRIMARIES.calculate_xyz_matrix(Self::CONTEXT.reference_white()))
+    registered_matrix(MatrixKey::new::<Self>(false), || {
+      Self::PRIMARIES.calculate_xyz_matrix(Self::CONTEXT.reference_white())
+    })
+  }
+}
+
+/// Identifies a matrix computed by the default [`RgbSpec`] matrix methods.
+///
+/// Primaries are compared by their bits so that a NaN coordinate still matches its own entry.
+#[derive(Clone, Copy, PartialEq)]
+struct MatrixKey {
+  inverse: bool,
+  primaries: [u64; 6],
+  spec: &'static str,
+}
+
+impl MatrixKey {
+  fn new<S: RgbSpec>(inverse: bool) -> Self {
+    let [red, green, blue] = S::primaries();
+
+    Self {
+      inverse,
+      primaries: [red.x(), red.y(), green.x(), green.y(), blue.x(), blue.y()].map(f64::to_bits),
+      spec: std::any::type_name::<S>(),
+    }
+  }
+}
+
+/// A cached matrix and the link to the next entry in the registry.
+struct RegisteredMatrix {
+  key: MatrixKey,
+  matrix: Matrix3,
+  next: RegistryCell,
+}
+
+#[cfg(feature = "std")]
+type RegistryCell = OnceLock<Box<RegisteredMatrix>>;
+
+#[cfg(not(feature = "std"))]
+type RegistryCell = OnceBox<RegisteredMatrix>;
+
+/// Returns the registered matrix for `key`, computing it with `init` on first access.
+///
+/// Specs without their own statics share this append-only list, so a generic default method
+/// can still cache one matrix per implementing type. `init` runs outside any cell
+/// initialization, because computing an inverse looks up the forward matrix in the same list.
+fn registered_matrix(key: MatrixKey, init: impl Fn() -> Matrix3) -> &'static Matrix3 {
+  static REGISTRY: RegistryCell = RegistryCell::new();
+
+  let mut cell = &REGISTRY;
+  loop {
+    let entry = match cell.get() {
+      Some(entry) => entry,
+      None => {
+        let entry = RegisteredMatrix {
+          key,
+          matrix: init(),
+          next: RegistryCell::new(),
+        };
+        cell.get_or_init(|| Box::new(entry))
+      }
+    };
+
+    if entry.key == key {
+      return &entry.matrix;
+    }
+    cell = &entry.next;
+  }
+}
+
+/// Returns the matrix in `cell`, computing it with `init` on first access.
+pub(crate) fn cached_matrix(cell: &'static MatrixCell, init: impl FnOnce() -> Matrix3) -> &'static Matrix3 {
+  #[cfg(feature = "std")]
+  {
+    cell.get_or_init(init)
+  }
+  #[cfg(not(feature = "std"))]
+  {
+    cell.get_or_init(|| Box::new(init()))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::space::Srgb;
+
+  mod eotf {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_coincides_with_inverse_oetf_for_display_referred_srgb() {
+      for value in [0.0, 0.1, 0.5, 1.0] {
+        assert_eq!(Srgb::eotf(value), Srgb::inverse_oetf(value));
+      }
+    }
+
+    #[cfg(feature = "rgb-rec-2100-hlg")]
+    #[test]
+    fn it_differs_from_inverse_oetf_for_hlg() {
+      use crate::space::Rec2100Hlg;
+
+      assert!((Rec2100Hlg::eotf(0.5) - Rec2100Hlg::inverse_oetf(0.5)).abs() > 1e-3);
+    }
+  }
+
+  mod primaries {
+    use super::*;
+
+    #[test]
+    fn it_returns_bt709_primaries_for_srgb() {
+      let expected = [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)];
+
+      for (primary, (x, y)) in Srgb::primaries().iter().zip(expected) {
+        assert!((primary.x() - x).abs() < 1e-4);
+        assert!((primary.y() - y).abs() < 1e-4);
+      }
+    }
+
+    #[cfg(feature = "rgb-rec-2020")]
+    #[test]
+    fn it_returns_rec2020_primaries() {
+      use crate::space::Rec2020;
+
+      let [red, green, blue] = Rec2020::primaries();
+
+      assert!((red.x() - 0.708).abs() < 1e-4);
+      assert!((green.y() - 0.797).abs() < 1e-4);
+      assert!((blue.x() - 0.131).abs() < 1e-4);
+    }
+  }
+
+  mod white_point {
+    use super::*;
+
+    #[test]
+    fn it_returns_d65_for_srgb() {
+      let white = Srgb::white_point();
+
+      assert!((white.x() - 0.3127).abs() < 1e-4);
+      assert!((white.y() - 0.3290).abs() < 1e-4);
+    }
+  }
+
+  mod xyz_matrix {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_same_cached_matrix_for_a_spec() {
+      assert!(std::ptr::eq(Srgb::xyz_matrix(), Srgb::xyz_matrix()));
+    }
+
+    #[cfg(feature = "rgb-display-p3")]
+    #[test]
+    fn it_caches_matrices_per_spec() {
+      use crate::space::DisplayP3;
+
+      let srgb = Srgb::xyz_matrix().data();
+      let p3 = DisplayP3::xyz_matrix().data();
+
+      assert!((srgb[0][0] - p3[0][0]).abs() > 1e-3);
+      assert!((Srgb::xyz_matrix().data()[0][0] - srgb[0][0]).abs() < 1e-15);
+    }
+
+    #[test]
+    fn it_caches_matrices_per_custom_spec_using_the_defaults() {
+      #[derive(Clone, Copy)]
+      struct CustomSrgb;
+
+      impl RgbSpec for CustomSrgb {
+        const CONTEXT: ColorimetricContext = Srgb::CONTEXT;
+        const NAME: &'static str = "Custom sRGB";
+        const PRIMARIES: RgbPrimaries = Srgb::PRIMARIES;
+        const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Srgb;
+      }
+
+      #[derive(Clone, Copy)]
+      struct CustomRec2020;
+
+      impl RgbSpec for CustomRec2020 {
+        const CONTEXT: ColorimetricContext = Srgb::CONTEXT;
+        const NAME: &'static str = "Custom Rec. 2020";
+        const PRIMARIES: RgbPrimaries = RgbPrimaries::new_const(
+          Xy::new_const(0.708, 0.292),
+          Xy::new_const(0.170, 0.797),
+          Xy::new_const(0.131, 0.046),
+        );
+        const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Srgb;
+      }
+
+      let srgb = CustomSrgb::xyz_matrix();
+      let rec2020 = CustomRec2020::xyz_matrix();
+
+      assert!(!std::ptr::eq(srgb, rec2020));
+      assert!((srgb.data()[0][0] - rec2020.data()[0][0]).abs() > 1e-3);
+      assert!(std::ptr::eq(srgb, CustomSrgb::xyz_matrix()));
+      assert!((srgb.data()[0][0] - Srgb::xyz_matrix().data()[0][0]).abs() < 1e-12);
+
+      let inverse = CustomRec2020::inversed_xyz_matrix();
+      let identity = *inverse * *rec2020;
+      for (i, row) in identity.data().iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+          assert!((value - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+        }
+      }
+    }
   }
 }
@@ -1,7 +1,10 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use crate::component::Component;
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
+const BT1886_GAMMA: f64 = 2.4;
 const BT709_ALPHA: f64 = 0.099;
 const BT709_ENCODED_THRESHOLD: f64 = 0.081;
 const BT709_GAMMA: f64 = 1.0 / 0.45;
@@ -10,11 +13,14 @@ const BT709_LINEAR_THRESHOLD: f64 = 0.018;
 const HLG_A: f64 = 0.17883277;
 const HLG_B: f64 = 0.28466892;
 const HLG_C: f64 = 0.55991073;
+const HLG_SYSTEM_GAMMA: f64 = 1.2;
 const PQ_C1: f64 = 3424.0 / 4096.0;
 const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
 const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
 const PQ_M1: f64 = 2610.0 / 16384.0;
 const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_OOTF_PEAK: f64 = 100.0;
+const PQ_OOTF_SCALE: f64 = 59.5208;
 const PROPHOTO_ENCODED_THRESHOLD: f64 = 16.0 / 512.0;
 const PROPHOTO_GAMMA: f64 = 1.8;
 const PROPHOTO_LINEAR_SLOPE: f64 = 16.0;
@@ -29,11 +35,36 @@ const SRGB_LINEAR_THRESHOLD: f64 = 0.0031308;
 ///
 /// Transfer functions map between linear light values and the nonlinear encoding
 /// used for display or storage. Also known as gamma curves.
+///
+/// Every [`RgbSpec`](super::RgbSpec) selects one through its `TRANSFER_FUNCTION` constant, so a custom
+/// space can plug in any variant — including a pure power law or no encoding at all:
+///
+/// ```
+/// use farg::{ColorimetricContext, chromaticity::Xy};
+/// use farg::space::{ColorSpace, Rgb, RgbPrimaries, RgbSpec, TransferFunction};
+///
+/// #[derive(Clone, Copy, Debug)]
+/// struct Gamma22Srgb;
+///
+/// impl RgbSpec for Gamma22Srgb {
+///   const CONTEXT: ColorimetricContext = ColorimetricContext::new();
+///   const NAME: &'static str = "sRGB (gamma 2.2)";
+///   const PRIMARIES: RgbPrimaries = RgbPrimaries::new_const(
+///     Xy::new_const(0.64, 0.33),
+///     Xy::new_const(0.30, 0.60),
+///     Xy::new_const(0.15, 0.06),
+///   );
+///   const TRANSFER_FUNCTION: TransferFunction = TransferFunction::Gamma(2.2);
+/// }
+///
+/// let color = Rgb::<Gamma22Srgb>::from_normalized(0.5, 0.5, 0.5);
+/// assert!((color.to_linear().r() - 0.5_f64.powf(2.2)).abs() < 1e-12);
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum TransferFunction {
   /// ITU-R BT.601 transfer function.
   Bt601,
-  /// ITU-R BT.709 transfer function.
+  /// ITU-R BT.709 (Rec. 709) transfer function.
   Bt709,
   /// Pure power-law gamma with the given exponent.
   Gamma(f64),
@@ -82,6 +113,65 @@ impl TransferFunction {
       Self::ProPhotoRgb => prophoto_encode(linear),
     }
   }
+
+  /// Converts an encoded signal to display light using the reference display EOTF.
+  ///
+  /// For display-referred encodings (sRGB, pure gamma, ProPhoto RGB, PQ, linear) this is the same
+  /// as [`Self::decode`]. BT.709 and BT.601 are camera OETFs whose reference display is BT.1886,
+  /// modeled here as a pure 2.4 gamma with zero black level. HLG applies the inverse OETF followed
+  /// by the OOTF with the 1000 cd/m² system gamma of 1.2, returning display light relative to the
+  /// nominal peak; the OOTF is applied per channel, which matches the BT.2100 luminance-based
+  /// OOTF for achromatic signals.
+  #[must_use]
+  pub fn eotf(&self, encoded: impl Into<Component>) -> f64 {
+    let encoded = encoded.into().0;
+
+    match self {
+      Self::Bt709 | Self::Bt601 => encoded.powf(BT1886_GAMMA),
+      Self::Hlg => hlg_decode(encoded).powf(HLG_SYSTEM_GAMMA),
+      _ => self.decode(encoded),
+    }
+  }
+
+  /// Converts display light back to an encoded signal, inverting [`Self::eotf`].
+  #[must_use]
+  pub fn inverse_eotf(&self, display: impl Into<Component>) -> f64 {
+    let display = display.into().0;
+
+    match self {
+      Self::Bt709 | Self::Bt601 => display.powf(1.0 / BT1886_GAMMA),
+      Self::Hlg => hlg_encode(display.powf(1.0 / HLG_SYSTEM_GAMMA)),
+      _ => self.encode(display),
+    }
+  }
+
+  /// Converts an encoded signal back to scene light, inverting [`Self::oetf`].
+  #[must_use]
+  pub fn inverse_oetf(&self, encoded: impl Into<Component>) -> f64 {
+    let encoded = encoded.into().0;
+
+    match self {
+      Self::Pq => pq_inverse_oetf(encoded),
+      _ => self.decode(encoded),
+    }
+  }
+
+  /// Converts scene light to an encoded signal using the camera OETF.
+  ///
+  /// For display-referred encodings the scene and display are taken to be the same, so this is
+  /// the same as [`Self::encode`]. BT.709, BT.601, and HLG are defined as OETFs and also match
+  /// [`Self::encode`]. PQ is defined by its EOTF, so its OETF applies the BT.2100 reference OOTF
+  /// (BT.709 OETF scaled by 59.5208 followed by a BT.1886 display peaking at 100 cd/m²) before the
+  /// inverse EOTF; scene light is 0.0-1.0 and PQ display light is in cd/m².
+  #[must_use]
+  pub fn oetf(&self, scene: impl Into<Component>) -> f64 {
+    let scene = scene.into().0;
+
+    match self {
+      Self::Pq => pq_oetf(scene),
+      _ => self.encode(scene),
+    }
+  }
 }
 
 impl Display for TransferFunction {
@@ -145,6 +235,16 @@ fn pq_encode(linear: f64) -> f64 {
   ((PQ_C1 + PQ_C2 * y_m1) / (1.0 + PQ_C3 * y_m1)).powf(PQ_M2)
 }
 
+fn pq_inverse_oetf(encoded: f64) -> f64 {
+  let display = (pq_decode(encoded) / PQ_OOTF_PEAK).max(0.0);
+  bt709_decode(display.powf(1.0 / BT1886_GAMMA)) / PQ_OOTF_SCALE
+}
+
+fn pq_oetf(scene: f64) -> f64 {
+  let display = PQ_OOTF_PEAK * bt709_encode(PQ_OOTF_SCALE * scene).max(0.0).powf(BT1886_GAMMA);
+  pq_encode(display)
+}
+
 fn prophoto_decode(encoded: f64) -> f64 {
   if encoded < PROPHOTO_ENCODED_THRESHOLD {
     encoded / PROPHOTO_LINEAR_SLOPE
@@ -161,6 +261,59 @@ fn prophoto_encode(linear: f64) -> f64 {
   }
 }
 
+/// Decodes an sRGB value in a const context.
+///
+/// `powf` is not a `const fn`, so the power segment is evaluated as `exp(2.4 * ln(x))` using
+/// series approximations of `ln` and `exp`. Results agree with [`srgb_decode`] to within
+/// 1e-14 across the 0.0-1.0 range.
+pub(crate) const fn srgb_decode_const(encoded: f64) -> f64 {
+  if encoded <= SRGB_ENCODED_THRESHOLD {
+    encoded / SRGB_LINEAR_SLOPE
+  } else {
+    const_exp(SRGB_GAMMA * const_ln((encoded + SRGB_ALPHA) / (1.0 + SRGB_ALPHA)))
+  }
+}
+
+/// Natural exponential for const contexts via range reduction and a Taylor series.
+const fn const_exp(y: f64) -> f64 {
+  let k = (y / std::f64::consts::LN_2 + if y < 0.0 { -0.5 } else { 0.5 }) as i64;
+  let r = y - k as f64 * std::f64::consts::LN_2;
+
+  let mut sum = 1.0;
+  let mut term = 1.0;
+  let mut n = 1;
+  while n < 25 {
+    term *= r / n as f64;
+    sum += term;
+    n += 1;
+  }
+
+  sum * f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// Natural logarithm of a positive normal value for const contexts.
+///
+/// Splits `x` into `m * 2^e` with `m` in [1, 2) and evaluates `ln(m)` with the
+/// `2 * atanh((m - 1) / (m + 1))` series.
+const fn const_ln(x: f64) -> f64 {
+  let bits = x.to_bits();
+  let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+  let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | (1023_u64 << 52));
+
+  let s = (mantissa - 1.0) / (mantissa + 1.0);
+  let s2 = s * s;
+  let mut sum = 0.0;
+  let mut power = s;
+  let mut n = 1;
+  while n < 60 {
+    sum += power / n as f64;
+    power *= s2;
+    n += 2;
+  }
+
+  exponent as f64 * std::f64::consts::LN_2 + 2.0 * sum
+}
+
 fn srgb_decode(encoded: f64) -> f64 {
   if encoded <= SRGB_ENCODED_THRESHOLD {
     encoded / SRGB_LINEAR_SLOPE
@@ -238,6 +391,15 @@ mod test {
       assert!((tf.decode(encoded) - expected).abs() < 1e-10);
     }
 
+    #[test]
+    fn it_decodes_srgb_continuously_at_breakpoint() {
+      let tf = TransferFunction::Srgb;
+      let below = tf.decode(SRGB_ENCODED_THRESHOLD - 1e-9);
+      let above = tf.decode(SRGB_ENCODED_THRESHOLD + 1e-9);
+
+      assert!((below - above).abs() < 1e-7);
+    }
+
     #[test]
     fn it_decodes_bt601_same_as_bt709() {
       let encoded = 0.5;
@@ -325,6 +487,16 @@ mod test {
       assert!((tf.encode(linear) - expected).abs() < 1e-10);
     }
 
+    #[test]
+    fn it_encodes_srgb_at_linear_breakpoint() {
+      let tf = TransferFunction::Srgb;
+      let expected = SRGB_LINEAR_THRESHOLD * SRGB_LINEAR_SLOPE;
+      let piecewise = (1.0 + SRGB_ALPHA) * SRGB_LINEAR_THRESHOLD.powf(1.0 / SRGB_GAMMA) - SRGB_ALPHA;
+
+      assert!((tf.encode(SRGB_LINEAR_THRESHOLD) - expected).abs() < 1e-12);
+      assert!((tf.encode(SRGB_LINEAR_THRESHOLD) - piecewise).abs() < 1e-6);
+    }
+
     #[test]
     fn it_encodes_srgb_in_gamma_region() {
       let tf = TransferFunction::Srgb;
@@ -335,6 +507,103 @@ mod test {
     }
   }
 
+  mod eotf {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_matches_decode_for_srgb() {
+      for value in [0.0, 0.02, 0.2, 0.5, 0.9, 1.0] {
+        assert_eq!(TransferFunction::Srgb.eotf(value), TransferFunction::Srgb.decode(value));
+      }
+    }
+
+    #[test]
+    fn it_coincides_with_inverse_oetf_for_srgb() {
+      for value in [0.0, 0.02, 0.2, 0.5, 0.9, 1.0] {
+        assert_eq!(
+          TransferFunction::Srgb.eotf(value),
+          TransferFunction::Srgb.inverse_oetf(value)
+        );
+      }
+    }
+
+    #[test]
+    fn it_uses_bt1886_for_bt709() {
+      assert!((TransferFunction::Bt709.eotf(0.5) - 0.5_f64.powf(2.4)).abs() < 1e-12);
+      assert!((TransferFunction::Bt709.eotf(0.5) - TransferFunction::Bt709.inverse_oetf(0.5)).abs() > 1e-3);
+    }
+
+    #[test]
+    fn it_applies_the_system_gamma_for_hlg() {
+      let tf = TransferFunction::Hlg;
+
+      assert!((tf.eotf(0.5) - (1.0_f64 / 12.0).powf(1.2)).abs() < 1e-12);
+      assert!((tf.eotf(0.5) - tf.inverse_oetf(0.5)).abs() > 1e-3);
+    }
+
+    #[test]
+    fn it_maps_hlg_nominal_peak_to_one() {
+      assert!((TransferFunction::Hlg.eotf(1.0) - 1.0).abs() < 1e-6);
+    }
+  }
+
+  mod inverse_eotf {
+    use super::*;
+
+    #[test]
+    fn it_roundtrips_through_eotf() {
+      for tf in [
+        TransferFunction::Srgb,
+        TransferFunction::Bt709,
+        TransferFunction::Hlg,
+        TransferFunction::Pq,
+        TransferFunction::Gamma(2.2),
+      ] {
+        for value in [0.05, 0.3, 0.5, 0.8] {
+          assert!((tf.inverse_eotf(tf.eotf(value)) - value).abs() < 1e-9);
+        }
+      }
+    }
+  }
+
+  mod oetf {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_matches_encode_for_hlg() {
+      for value in [0.0, 0.05, 0.5, 1.0] {
+        assert_eq!(TransferFunction::Hlg.oetf(value), TransferFunction::Hlg.encode(value));
+      }
+    }
+
+    #[test]
+    fn it_applies_the_reference_ootf_for_pq() {
+      let tf = TransferFunction::Pq;
+      let display = PQ_OOTF_PEAK * bt709_encode(PQ_OOTF_SCALE * 0.5).powf(BT1886_GAMMA);
+
+      assert!((tf.oetf(0.5) - tf.encode(display)).abs() < 1e-12);
+      assert!((tf.oetf(0.5) - tf.encode(0.5)).abs() > 1e-3);
+    }
+
+    #[test]
+    fn it_roundtrips_through_inverse_oetf() {
+      for tf in [
+        TransferFunction::Srgb,
+        TransferFunction::Bt709,
+        TransferFunction::Hlg,
+        TransferFunction::Pq,
+      ] {
+        for value in [0.001, 0.05, 0.3, 0.5, 0.8] {
+          assert!((tf.inverse_oetf(tf.oetf(value)) - value).abs() < 1e-9);
+        }
+      }
+    }
+  }
+
   mod roundtrip {
     use super::*;
 
@@ -346,6 +615,14 @@ mod test {
       assert!((tf.decode(tf.encode(original)) - original).abs() < 1e-10);
     }
 
+    #[test]
+    fn it_leaves_out_of_range_values_untouched_for_linear() {
+      let tf = TransferFunction::Linear;
+
+      assert_eq!(tf.encode(-0.25), -0.25);
+      assert_eq!(tf.decode(1.5), 1.5);
+    }
+
     #[test]
     fn it_roundtrips_gamma() {
       let tf = TransferFunction::Gamma(2.2);
@@ -354,6 +631,17 @@ mod test {
       assert!((tf.decode(tf.encode(original)) - original).abs() < 1e-10);
     }
 
+    #[test]
+    fn it_roundtrips_gamma_across_unit_range() {
+      let tf = TransferFunction::Gamma(2.2);
+
+      for i in 0..=20 {
+        let original = i as f64 / 20.0;
+
+        assert!((tf.decode(tf.encode(original)) - original).abs() < 1e-10);
+      }
+    }
+
     #[test]
     fn it_roundtrips_srgb() {
       let tf = TransferFunction::Srgb;
@@ -394,4 +682,24 @@ mod test {
       assert!((tf.decode(tf.encode(original)) - original).abs() < 1e-6);
     }
   }
+
+  mod srgb_decode_const {
+    use super::*;
+
+    #[test]
+    fn it_matches_runtime_decode() {
+      for i in 0..=255 {
+        let encoded = i as f64 / 255.0;
+
+        assert!((srgb_decode_const(encoded) - srgb_decode(encoded)).abs() < 1e-14);
+      }
+    }
+
+    #[test]
+    fn it_evaluates_in_const_context() {
+      const WHITE: f64 = srgb_decode_const(1.0);
+
+      assert!((WHITE - 1.0).abs() < 1e-15);
+    }
+  }
 }
@@ -0,0 +1,96 @@
+//! Compact array (de)serialization for any [`ColorSpace`].
+//!
+//! Use with serde's `with` attribute to encode a color as `[c0, c1, c2]` (or `[c0, c1, c2, c3]`
+//! for four-component spaces) instead of a per-field map. Alpha is appended as a trailing element
+//! only when less than 1.0, mirroring the map encoding. Components are the same normalized values
+//! returned by [`ColorSpace::components`], and deserialized colors use the default viewing
+//! context.
+//!
+//! ```
+//! use farg::space::{Rgb, Srgb, serde_array};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Swatch {
+//!   #[serde(with = "serde_array")]
+//!   color: Rgb<Srgb>,
+//! }
+//!
+//! let swatch = Swatch { color: Rgb::<Srgb>::new(255, 0, 0) };
+//! let json = serde_json::to_string(&swatch).unwrap();
+//! assert_eq!(json, r#"{"color":[1.0,0.0,0.0]}"#);
+//! ```
+
+use std::{fmt::Formatter, marker::PhantomData};
+
+use serde::{
+  Deserializer, Serializer,
+  de::{Error, SeqAccess, Visitor},
+  ser::SerializeSeq,
+};
+
+use crate::space::{ColorSpace, Xyz};
+
+/// Deserializes a color from an array of `N` components with an optional trailing alpha.
+pub fn deserialize<'de, C, D, const N: usize>(deserializer: D) -> Result<C, D::Error>
+where
+  C: ColorSpace<N>,
+  D: Deserializer<'de>,
+{
+  deserializer.deserialize_seq(ArrayVisitor::<C, N>(PhantomData))
+}
+
+/// Serializes a color as an array of its components, appending alpha when translucent.
+pub fn serialize<C, S, const N: usize>(color: &C, serializer: S) -> Result<S::Ok, S::Error>
+where
+  C: ColorSpace<N>,
+  S: Serializer,
+{
+  let alpha = color.alpha();
+  let len = if alpha < 1.0 { N + 1 } else { N };
+  let mut seq = serializer.serialize_seq(Some(len))?;
+
+  for component in color.components() {
+    seq.serialize_element(&component)?;
+  }
+
+  if alpha < 1.0 {
+    seq.serialize_element(&alpha)?;
+  }
+
+  seq.end()
+}
+
+/// Visitor collecting `N` or `N + 1` numbers into a color.
+struct ArrayVisitor<C, const N: usize>(PhantomData<C>);
+
+impl<'de, C, const N: usize> Visitor<'de> for ArrayVisitor<C, N>
+where
+  C: ColorSpace<N>,
+{
+  type Value = C;
+
+  fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+    write!(f, "an array of {} or {} numbers", N, N + 1)
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut components = [0.0; N];
+
+    for (index, component) in components.iter_mut().enumerate() {
+      *component = seq
+        .next_element()?
+        .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+    }
+
+    let alpha: f64 = seq.next_element()?.unwrap_or(1.0);
+
+    if seq.next_element::<f64>()?.is_some() {
+      return Err(A::Error::invalid_length(N + 2, &self));
+    }
+
+    let mut color = C::from(Xyz::new(0.0, 0.0, 0.0));
+    color.set_components(components);
+    color.set_alpha(alpha);
+    Ok(color)
+  }
+}
@@ -16,6 +16,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -254,13 +256,27 @@ where
   /// Converts this CMY color to a [`Cmyk`] color in the specified output space.
   #[cfg(feature = "space-cmyk")]
   pub fn to_cmyk<OS>(&self) -> Cmyk<OS>
+  where
+    OS: RgbSpec,
+  {
+    self.to_cmyk_with_k_limit(1.0)
+  }
+
+  /// Converts this CMY color to a [`Cmyk`] color, capping the extracted black (K) at `max_k`
+  /// (normalized 0.0-1.0).
+  ///
+  /// Any gray component above the cap is left in C, M, and Y rather than moved into K, so the
+  /// reproduced RGB is unchanged. A `max_k` of 1.0 is identical to [`Self::to_cmyk`]; lower
+  /// limits are useful when a press restricts how much black ink may be laid down.
+  #[cfg(feature = "space-cmyk")]
+  pub fn to_cmyk_with_k_limit<OS>(&self, max_k: impl Into<Component>) -> Cmyk<OS>
   where
     OS: RgbSpec,
   {
     let nc = self.c.0;
     let nm = self.m.0;
     let ny = self.y.0;
-    let k = nc.min(nm).min(ny);
+    let k = nc.min(nm).min(ny).min(max_k.into().0.clamp(0.0, 1.0));
 
     if (k - 1.0).abs() < f64::EPSILON {
       return Cmyk::<OS>::new(0.0, 0.0, 0.0, 100.0).with_alpha(self.alpha);
@@ -788,6 +804,16 @@ where
   }
 }
 
+#[cfg(feature = "trace")]
+impl<S> HubPath for Cmy<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Cmy"], Rgb::<S>::hub_path()].concat()
+  }
+}
+
 impl<S, T> Mul<T> for Cmy<S>
 where
   S: RgbSpec,
@@ -1382,6 +1408,61 @@ mod test {
     }
   }
 
+  #[cfg(feature = "space-cmyk")]
+  mod to_cmyk_with_k_limit {
+    use super::*;
+
+    #[test]
+    fn it_matches_standard_extraction_at_full_limit() {
+      let cmy = Cmy::<Srgb>::new(25.0, 50.0, 75.0);
+      let limited: Cmyk<Srgb> = cmy.to_cmyk_with_k_limit(1.0);
+      let standard: Cmyk<Srgb> = cmy.to_cmyk();
+
+      assert!((limited.cyan() - standard.cyan()).abs() < 1e-10);
+      assert!((limited.magenta() - standard.magenta()).abs() < 1e-10);
+      assert!((limited.yellow() - standard.yellow()).abs() < 1e-10);
+      assert!((limited.key() - standard.key()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_caps_key_and_preserves_rgb() {
+      let cmy = Cmy::<Srgb>::new(60.0, 70.0, 80.0);
+      let standard: Cmyk<Srgb> = cmy.to_cmyk();
+      let limited: Cmyk<Srgb> = cmy.to_cmyk_with_k_limit(0.3);
+      let original_rgb: Rgb<Srgb> = cmy.to_rgb();
+      let limited_rgb: Rgb<Srgb> = limited.to_rgb();
+
+      assert!((limited.key() - 30.0).abs() < 1e-10);
+      assert!(limited.key() < standard.key());
+      assert!((original_rgb.red() as i16 - limited_rgb.red() as i16).abs() <= 1);
+      assert!((original_rgb.green() as i16 - limited_rgb.green() as i16).abs() <= 1);
+      assert!((original_rgb.blue() as i16 - limited_rgb.blue() as i16).abs() <= 1);
+    }
+
+    #[test]
+    fn it_extracts_no_key_at_zero_limit() {
+      let cmy = Cmy::<Srgb>::new(40.0, 50.0, 60.0);
+      let cmyk: Cmyk<Srgb> = cmy.to_cmyk_with_k_limit(0.0);
+
+      assert!(cmyk.key().abs() < 1e-10);
+      assert!((cmyk.cyan() - 40.0).abs() < 1e-10);
+      assert!((cmyk.magenta() - 50.0).abs() < 1e-10);
+      assert!((cmyk.yellow() - 60.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_keeps_limited_black_black() {
+      let cmy = Cmy::<Srgb>::new(100.0, 100.0, 100.0);
+      let cmyk: Cmyk<Srgb> = cmy.to_cmyk_with_k_limit(0.5);
+      let rgb: Rgb<Srgb> = cmyk.to_rgb();
+
+      assert!((cmyk.key() - 50.0).abs() < 1e-10);
+      assert_eq!(rgb.red(), 0);
+      assert_eq!(rgb.green(), 0);
+      assert_eq!(rgb.blue(), 0);
+    }
+  }
+
   mod to_rgb {
     use pretty_assertions::assert_eq;
 
@@ -16,6 +16,8 @@ use crate::space::Hsl;
 use crate::space::Hsluv;
 #[cfg(feature = "space-hsv")]
 use crate::space::Hsv;
+#[cfg(feature = "trace")]
+use crate::space::HubPath;
 #[cfg(feature = "space-hwb")]
 use crate::space::Hwb;
 #[cfg(feature = "space-lab")]
@@ -217,6 +219,50 @@ where
     self.k.0 * 100.0
   }
 
+  /// Returns this color with its total ink coverage reduced to at most `max_coverage` percent.
+  ///
+  /// Ink is removed from cyan, magenta, and yellow first, scaling them down proportionally while
+  /// keeping black, which carries most of the darkness (under-color removal). Black is only
+  /// reduced when `max_coverage` is below the black channel alone, in which case the result is
+  /// pure black at the limit. Colors already within the limit are returned unchanged.
+  ///
+  /// ```
+  /// use farg::space::{Cmyk, Srgb};
+  ///
+  /// let rich_black = Cmyk::<Srgb>::new(100.0, 100.0, 100.0, 100.0);
+  /// let limited = rich_black.limited_to(300.0);
+  ///
+  /// assert!((limited.total_ink_coverage() - 300.0).abs() < 1e-9);
+  /// assert_eq!(limited.key(), 100.0);
+  /// ```
+  pub fn limited_to(&self, max_coverage: f64) -> Self {
+    let limit = max_coverage.max(0.0) / 100.0;
+    let cmy = self.c.0 + self.m.0 + self.y.0;
+
+    if cmy + self.k.0 <= limit {
+      return *self;
+    }
+
+    if self.k.0 >= limit {
+      return Self {
+        c: Component::new(0.0),
+        m: Component::new(0.0),
+        y: Component::new(0.0),
+        k: Component::new(limit),
+        ..*self
+      };
+    }
+
+    let scale = (limit - self.k.0) / cmy;
+
+    Self {
+      c: self.c * scale,
+      m: self.m * scale,
+      y: self.y * scale,
+      ..*self
+    }
+  }
+
   /// Returns the normalized magenta component (0.0-1.0).
   pub fn m(&self) -> f64 {
     self.m.0
@@ -353,6 +399,17 @@ where
     .with_alpha(self.alpha)
   }
 
+  /// Returns the total ink coverage (TAC) as the sum of all four channels in percent (0-400%).
+  ///
+  /// ```
+  /// use farg::space::{Cmyk, Srgb};
+  ///
+  /// assert_eq!(Cmyk::<Srgb>::new(20.0, 30.0, 40.0, 10.0).total_ink_coverage(), 100.0);
+  /// ```
+  pub fn total_ink_coverage(&self) -> f64 {
+    (self.c.0 + self.m.0 + self.y.0 + self.k.0) * 100.0
+  }
+
   /// Returns this color with a different viewing context (without adaptation).
   pub fn with_context(&self, context: ColorimetricContext) -> Self {
     Self {
@@ -916,6 +973,16 @@ where
   }
 }
 
+#[cfg(feature = "trace")]
+impl<S> HubPath for Cmyk<S>
+where
+  S: RgbSpec,
+{
+  fn hub_path() -> Vec<&'static str> {
+    [vec!["Cmyk"], Rgb::<S>::hub_path()].concat()
+  }
+}
+
 impl<S, T> Mul<T> for Cmyk<S>
 where
   S: RgbSpec,
@@ -1406,6 +1473,54 @@ mod test {
     }
   }
 
+  mod limited_to {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_limits_rich_black_to_the_coverage() {
+      let limited = Cmyk::<Srgb>::new(100.0, 100.0, 100.0, 100.0).limited_to(300.0);
+
+      assert!((limited.total_ink_coverage() - 300.0).abs() < 1e-9);
+      assert_eq!(limited.key(), 100.0);
+      assert!((limited.cyan() - 200.0 / 3.0).abs() < 1e-9);
+      assert_eq!(limited.to_rgb::<Srgb>().to_hex(), "#000000");
+    }
+
+    #[test]
+    fn it_keeps_cmy_ratios() {
+      let limited = Cmyk::<Srgb>::new(90.0, 60.0, 30.0, 50.0).limited_to(200.0);
+
+      assert!((limited.total_ink_coverage() - 200.0).abs() < 1e-9);
+      assert!((limited.cyan() / limited.magenta() - 1.5).abs() < 1e-9);
+      assert!((limited.magenta() / limited.yellow() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_leaves_colors_under_the_limit_unchanged() {
+      let color = Cmyk::<Srgb>::new(20.0, 10.0, 5.0, 0.0);
+
+      assert_eq!(color.limited_to(300.0).components(), color.components());
+    }
+
+    #[test]
+    fn it_reduces_black_when_the_limit_is_below_black() {
+      let limited = Cmyk::<Srgb>::new(50.0, 50.0, 50.0, 80.0).limited_to(60.0);
+
+      assert!((limited.key() - 60.0).abs() < 1e-9);
+      assert_eq!(limited.cyan(), 0.0);
+      assert!((limited.total_ink_coverage() - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_preserves_alpha() {
+      let color = Cmyk::<Srgb>::new(100.0, 100.0, 100.0, 100.0).with_alpha(0.5);
+
+      assert_eq!(color.limited_to(300.0).alpha(), 0.5);
+    }
+  }
+
   mod mul {
     use super::*;
 
@@ -1691,6 +1806,23 @@ mod test {
     }
   }
 
+  #[cfg(feature = "space-oklch")]
+  mod to_space {
+    use super::*;
+    use crate::space::Oklch;
+
+    #[test]
+    fn it_matches_the_explicit_oklch_conversion() {
+      let cmyk = Cmyk::<Srgb>::new(25.0, 50.0, 75.0, 10.0);
+      let generic: Oklch = cmyk.to_space();
+      let explicit = cmyk.to_oklch();
+
+      for (g, e) in generic.components().iter().zip(explicit.components()) {
+        assert!((g - e).abs() < 1e-10);
+      }
+    }
+  }
+
   mod to_xyz {
     use super::*;
 
@@ -1718,6 +1850,21 @@ mod test {
     }
   }
 
+  mod total_ink_coverage {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_sums_all_channels_in_percent() {
+      assert_eq!(
+        Cmyk::<Srgb>::new(100.0, 100.0, 100.0, 100.0).total_ink_coverage(),
+        400.0
+      );
+      assert_eq!(Cmyk::<Srgb>::new(0.0, 0.0, 0.0, 0.0).total_ink_coverage(), 0.0);
+    }
+  }
+
   mod with_c {
     use pretty_assertions::assert_eq;
 
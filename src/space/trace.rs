@@ -0,0 +1,82 @@
+/// Describes the chain of spaces a color passes through on its way to the [`Xyz`](super::Xyz) hub.
+///
+/// Used by [`conversion_path`] to report which intermediate spaces a conversion touches, which
+/// helps diagnose precision loss through round trips.
+pub trait HubPath {
+  /// Returns the spaces from this one to `Xyz`, starting with this space and ending with `"Xyz"`.
+  fn hub_path() -> Vec<&'static str>;
+}
+
+/// Returns the chain of spaces a hub conversion from `A` to `B` passes through.
+///
+/// Generic conversions (e.g. [`to_space`](super::ColorSpace::to_space) or `B::from(a.to_xyz())`)
+/// always route through [`Xyz`](super::Xyz): the source walks down to the hub and the target is
+/// built back up from it. The result lists every space visited in order, including both ends.
+/// RGB spaces are reported by their spec's [`NAME`](super::RgbSpec::NAME).
+///
+/// ```
+/// # #[cfg(all(feature = "space-hsv", feature = "space-lab"))]
+/// # {
+/// use farg::space::{Hsv, Lab, Srgb, conversion_path};
+///
+/// assert_eq!(conversion_path::<Hsv<Srgb>, Lab>(), ["Hsv", "sRGB", "Xyz", "Lab"]);
+/// # }
+/// ```
+pub fn conversion_path<A, B>() -> Vec<&'static str>
+where
+  A: HubPath,
+  B: HubPath,
+{
+  let mut path = A::hub_path();
+  path.extend(B::hub_path().into_iter().rev().skip(1));
+  path
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod conversion_path {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::space::Xyz;
+
+    #[cfg(all(feature = "space-hsv", feature = "space-lab"))]
+    #[test]
+    fn it_routes_hsv_to_lab_through_rgb_and_xyz() {
+      use crate::space::{Hsv, Lab, Srgb};
+
+      assert_eq!(conversion_path::<Hsv<Srgb>, Lab>(), ["Hsv", "sRGB", "Xyz", "Lab"]);
+    }
+
+    #[cfg(feature = "space-lab")]
+    #[test]
+    fn it_converts_xyz_to_lab_directly() {
+      use crate::space::Lab;
+
+      assert_eq!(conversion_path::<Xyz, Lab>(), ["Xyz", "Lab"]);
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_builds_the_target_back_up_from_the_hub() {
+      use crate::space::{Oklch, Rgb, Srgb};
+
+      assert_eq!(conversion_path::<Rgb<Srgb>, Oklch>(), ["sRGB", "Xyz", "Oklab", "Oklch"]);
+    }
+
+    #[cfg(feature = "rgb-display-p3")]
+    #[test]
+    fn it_names_rgb_spaces_by_their_spec() {
+      use crate::space::{DisplayP3, Rgb};
+
+      assert_eq!(conversion_path::<Rgb<DisplayP3>, Xyz>(), ["Display P3", "Xyz"]);
+    }
+
+    #[test]
+    fn it_reports_a_single_space_for_xyz_to_xyz() {
+      assert_eq!(conversion_path::<Xyz, Xyz>(), ["Xyz"]);
+    }
+  }
+}
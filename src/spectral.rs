@@ -9,9 +9,14 @@ pub use chromaticity_coordinates::ChromaticityCoordinates;
 pub use color_matching_function::{Cmf, ColorMatchingFunction};
 pub use cone_fundamentals::ConeFundamentals;
 pub use cone_response::ConeResponse;
+#[cfg(feature = "cri")]
+pub(crate) use spectral_power_distribution::blackbody_exitance;
 pub use spectral_power_distribution::{Spd, SpectralPowerDistribution};
 pub use tristimulus_response::TristimulusResponse;
 
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
 /// Common interface for wavelength-indexed spectral data.
 ///
 /// All spectral data types (SPD, CMF, chromaticity coordinates, cone fundamentals)
@@ -51,6 +56,25 @@ pub trait Table {
     self.table().first().map(|(w, _)| *w)
   }
 
+  /// Returns the wavelength-value pairs between `start_nm` and `end_nm` (inclusive).
+  ///
+  /// Bounds outside the table's data simply clamp to what is available, and a reversed range
+  /// yields an empty result.
+  fn range(&self, start_nm: u32, end_nm: u32) -> Vec<(u32, Self::Value)>
+  where
+    Self::Value: Clone,
+  {
+    let table = self.table();
+    let start = table.partition_point(|(w, _)| *w < start_nm);
+    let end = table.partition_point(|(w, _)| *w <= end_nm);
+
+    if start >= end {
+      return Vec::new();
+    }
+
+    table[start..end].to_vec()
+  }
+
   /// Returns the minimum step size between consecutive wavelengths.
   fn step(&self) -> u32 {
     if self.table().len() < 2 {
@@ -187,6 +211,57 @@ mod test {
       }
     }
 
+    mod range {
+      use pretty_assertions::assert_eq;
+
+      use super::*;
+      use crate::Observer;
+
+      #[test]
+      fn it_returns_samples_within_inclusive_bounds() {
+        let spd = Spd::from_fn(380, 780, 1, |w| w as f64);
+        let slice = spd.range(500, 510);
+
+        assert_eq!(slice.len(), 11);
+        assert_eq!(slice.first(), Some(&(500, 500.0)));
+        assert_eq!(slice.last(), Some(&(510, 510.0)));
+      }
+
+      #[test]
+      fn it_skips_bounds_that_fall_between_samples() {
+        let spd = Spd::new(TEST_SPD);
+
+        assert_eq!(spd.range(385, 405), vec![(390, 0.2), (400, 0.3)]);
+      }
+
+      #[test]
+      fn it_clamps_out_of_range_bounds_to_available_data() {
+        let spd = Spd::new(TEST_SPD);
+
+        assert_eq!(spd.range(0, 10_000), TEST_SPD.to_vec());
+        assert_eq!(spd.range(400, 10_000), vec![(400, 0.3), (410, 0.4)]);
+      }
+
+      #[test]
+      fn it_returns_empty_for_reversed_or_disjoint_ranges() {
+        let spd = Spd::new(TEST_SPD);
+
+        assert!(spd.range(410, 380).is_empty());
+        assert!(spd.range(700, 800).is_empty());
+        assert!(Spd::new(EMPTY_SPD).range(380, 780).is_empty());
+      }
+
+      #[test]
+      fn it_slices_color_matching_functions() {
+        let cmf = Observer::CIE_1931_2D.cmf();
+        let slice = cmf.range(500, 510);
+        let expected = cmf.wavelengths().filter(|w| (500..=510).contains(w)).count();
+
+        assert_eq!(slice.len(), expected);
+        assert!(slice.iter().all(|(w, _)| (500..=510).contains(w)));
+      }
+    }
+
     mod step {
       use pretty_assertions::assert_eq;
 
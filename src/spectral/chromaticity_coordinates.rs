@@ -1,4 +1,6 @@
 use super::{Cmf, Table};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::{chromaticity::Xy, space::Xyz};
 
 /// Spectral locus chromaticity coordinates derived from color matching functions.
@@ -1,4 +1,6 @@
 use super::{Spd, Table, TristimulusResponse};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::Xyz;
 
 /// Shorthand alias for [`ColorMatchingFunction`].
@@ -50,6 +52,39 @@ impl ColorMatchingFunction {
 
     Xyz::new(components[0], components[1], components[2])
   }
+
+  /// Returns the x̄ color matching function as an [`Spd`] over the same wavelengths.
+  ///
+  /// ```
+  /// use farg::{Observer, SpectralTable};
+  ///
+  /// let x_bar = Observer::CIE_1931_2D.cmf().x_bar();
+  ///
+  /// assert_eq!(x_bar.peak_wavelength(), Some(599));
+  /// ```
+  pub fn x_bar(&self) -> Spd {
+    self.channel(TristimulusResponse::x)
+  }
+
+  /// Returns the ȳ color matching function (the photopic luminous efficiency function for the
+  /// 2° observer) as an [`Spd`] over the same wavelengths.
+  pub fn y_bar(&self) -> Spd {
+    self.channel(TristimulusResponse::y)
+  }
+
+  /// Returns the z̄ color matching function as an [`Spd`] over the same wavelengths.
+  pub fn z_bar(&self) -> Spd {
+    self.channel(TristimulusResponse::z)
+  }
+
+  fn channel(&self, component: impl Fn(&TristimulusResponse) -> f64) -> Spd {
+    self
+      .table()
+      .iter()
+      .map(|(wavelength, response)| (*wavelength, component(response)))
+      .collect::<Vec<_>>()
+      .into()
+  }
 }
 
 impl Table for ColorMatchingFunction {
@@ -181,4 +216,67 @@ mod test {
       assert_eq!(cmf.spd_to_xyz(&spd), cmf.spectral_power_distribution_to_xyz(&spd));
     }
   }
+
+  mod x_bar {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_extracts_the_x_channel() {
+      let x_bar = Cmf::new(TEST_CMF).x_bar();
+
+      assert_eq!(x_bar.table(), &[(380, 0.001), (400, 0.014), (420, 0.134), (440, 0.348)]);
+    }
+  }
+
+  mod y_bar {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Observer;
+
+    #[test]
+    fn it_extracts_the_y_channel() {
+      let y_bar = Cmf::new(TEST_CMF).y_bar();
+
+      assert_eq!(
+        y_bar.table(),
+        &[(380, 0.0001), (400, 0.0004), (420, 0.004), (440, 0.023)]
+      );
+    }
+
+    #[test]
+    fn it_peaks_near_555_nm() {
+      let peak = Observer::CIE_1931_2D.cmf().y_bar().peak_wavelength().unwrap();
+
+      assert!((553..=557).contains(&peak));
+    }
+
+    #[test]
+    fn it_integrates_to_equal_energy_white_with_x_bar_and_z_bar() {
+      let cmf = Observer::CIE_1931_2D.cmf();
+      let flat = Spd::from_fn(360, 830, 1, |_| 1.0);
+      let [x, y, z] = cmf.spd_to_xyz(&flat).components();
+
+      assert!((cmf.x_bar().total_power() - x).abs() < 1e-9);
+      assert!((cmf.y_bar().total_power() - y).abs() < 1e-9);
+      assert!((cmf.z_bar().total_power() - z).abs() < 1e-9);
+      assert!((x / y - 1.0).abs() < 0.01);
+      assert!((z / y - 1.0).abs() < 0.01);
+    }
+  }
+
+  mod z_bar {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_extracts_the_z_channel() {
+      let z_bar = Cmf::new(TEST_CMF).z_bar();
+
+      assert_eq!(z_bar.table(), &[(380, 0.006), (400, 0.068), (420, 0.646), (440, 1.747)]);
+    }
+  }
 }
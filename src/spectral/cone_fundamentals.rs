@@ -1,4 +1,6 @@
 use super::{Cmf, ConeResponse, Spd, Table};
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use crate::space::{Lms, Xyz};
 
 /// Spectral cone sensitivity functions mapping wavelengths to LMS cone responses.
@@ -1,16 +1,196 @@
+#[cfg(not(feature = "std"))]
+use alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+use std::ops::Add;
+
 use super::Table;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+/// First radiation constant for spectral radiant exitance, c1 = 2πhc² (W·m²).
+const PLANCK_C1: f64 = 3.741_771_852e-16;
+
+/// Second radiation constant, c2 = hc/k (m·K).
+const PLANCK_C2: f64 = 1.438_776_877e-2;
 
 /// Shorthand alias for [`SpectralPowerDistribution`].
 pub type Spd = SpectralPowerDistribution;
 
 /// Spectral power distribution — the power of a light source at each wavelength.
-#[derive(Clone, Copy, Debug)]
-pub struct SpectralPowerDistribution(&'static [(u32, f64)]);
+///
+/// Built-in tables borrow their static samples; SPDs built at runtime own theirs.
+#[derive(Clone, Debug)]
+pub struct SpectralPowerDistribution(Cow<'static, [(u32, f64)]>);
 
 impl SpectralPowerDistribution {
+  /// Creates a rectangular band-pass SPD: 1.0 from `low_nm` through `high_nm` (inclusive) and 0.0
+  /// elsewhere.
+  ///
+  /// Sampled at every `step_nm` from `start_nm` through `end_nm` with the same stepping rules as
+  /// [`from_fn`](Self::from_fn). Multiplying a band with an illuminant SPD models an ideal colored
+  /// filter.
+  ///
+  /// ```
+  /// use farg::{Spd, SpectralTable};
+  ///
+  /// let green_filter = Spd::band(500.0, 570.0, 380, 780, 5);
+  /// let d65 = farg::Illuminant::D65.spd();
+  /// let filtered = Spd::from_fn(380, 780, 5, |wavelength| {
+  ///   d65.at(wavelength).copied().unwrap_or(0.0) * green_filter.at(wavelength).copied().unwrap_or(0.0)
+  /// });
+  ///
+  /// assert_eq!(filtered.at(450), Some(&0.0));
+  /// ```
+  pub fn band(low_nm: f64, high_nm: f64, start_nm: u32, end_nm: u32, step_nm: u32) -> Self {
+    Self::from_fn(start_nm, end_nm, step_nm, |wavelength| {
+      if (low_nm..=high_nm).contains(&(wavelength as f64)) {
+        1.0
+      } else {
+        0.0
+      }
+    })
+  }
+
+  /// Creates the SPD of a blackbody radiator at `kelvin` using Planck's law.
+  ///
+  /// Spectral radiant exitance is evaluated at every `step_nm` from `start_nm` through `end_nm`
+  /// (inclusive, with the same stepping rules as [`from_fn`](Self::from_fn)) and normalized so
+  /// the largest sample is 1.0. Non-positive temperatures produce an all-zero SPD.
+  ///
+  /// ```
+  /// use farg::Spd;
+  ///
+  /// let tungsten = Spd::blackbody(2856.0, 380, 780, 5);
+  ///
+  /// assert_eq!(tungsten.peak_power(), 1.0);
+  /// assert_eq!(tungsten.peak_wavelength(), Some(780));
+  /// ```
+  pub fn blackbody(kelvin: f64, start_nm: u32, end_nm: u32, step_nm: u32) -> Self {
+    let exitance = |wavelength: u32| blackbody_exitance(wavelength, kelvin);
+    let peak = (start_nm..=end_nm)
+      .step_by(step_nm.max(1) as usize)
+      .map(exitance)
+      .fold(0.0, f64::max);
+
+    Self::from_fn(start_nm, end_nm, step_nm, |wavelength| {
+      if peak > 0.0 { exitance(wavelength) / peak } else { 0.0 }
+    })
+  }
+
+  /// Creates an SPD by sampling `f` at every `step_nm` from `start_nm` through `end_nm` (inclusive).
+  ///
+  /// Useful for synthetic light sources such as Gaussians, monochromatic spikes, or flat
+  /// illuminants. A step of 0 is treated as 1, and an `end_nm` below `start_nm` yields an empty
+  /// SPD.
+  ///
+  /// ```
+  /// use farg::{Spd, SpectralTable};
+  ///
+  /// let spd = Spd::from_fn(380, 780, 5, |wavelength| if wavelength == 550 { 1.0 } else { 0.0 });
+  ///
+  /// assert_eq!(spd.len(), 81);
+  /// assert_eq!(spd.peak_wavelength(), Some(550));
+  /// ```
+  pub fn from_fn(start_nm: u32, end_nm: u32, step_nm: u32, f: impl Fn(u32) -> f64) -> Self {
+    (start_nm..=end_nm)
+      .step_by(step_nm.max(1) as usize)
+      .map(|wavelength| (wavelength, f(wavelength)))
+      .collect::<Vec<_>>()
+      .into()
+  }
+
+  /// Creates a Gaussian SPD centered on `center_nm` with standard deviation `sigma_nm` and a peak
+  /// of 1.0.
+  ///
+  /// Sampled at every `step_nm` from `start_nm` through `end_nm` with the same stepping rules as
+  /// [`from_fn`](Self::from_fn). Useful for modeling narrow-band filters and LEDs. A non-positive
+  /// `sigma_nm` produces a spike of 1.0 at `center_nm` (if sampled) and 0.0 elsewhere.
+  ///
+  /// ```
+  /// use farg::Spd;
+  ///
+  /// let led = Spd::gaussian(530.0, 12.0, 380, 780, 1);
+  ///
+  /// assert_eq!(led.peak_wavelength(), Some(530));
+  /// assert!((led.full_width_half_max().unwrap() - 28.26).abs() < 0.1);
+  /// ```
+  pub fn gaussian(center_nm: f64, sigma_nm: f64, start_nm: u32, end_nm: u32, step_nm: u32) -> Self {
+    Self::from_fn(start_nm, end_nm, step_nm, |wavelength| {
+      let offset = wavelength as f64 - center_nm;
+
+      if sigma_nm > 0.0 {
+        (-0.5 * (offset / sigma_nm).powi(2)).exp()
+      } else if offset == 0.0 {
+        1.0
+      } else {
+        0.0
+      }
+    })
+  }
+
   /// Creates a new SPD from static wavelength-power pairs.
   pub const fn new(table: &'static [(u32, f64)]) -> Self {
-    Self(table)
+    Self(Cow::Borrowed(table))
+  }
+
+  /// Returns the Pearson correlation between this SPD and `other` over their overlapping range.
+  ///
+  /// Both spectra are linearly interpolated onto a common grid spanning the overlapping
+  /// wavelengths at the finer of the two steps. Because correlation ignores overall scale, a
+  /// scaled copy of a spectrum correlates perfectly (1.0). Returns `NaN` when the spectra do not
+  /// overlap or either is constant over the overlap.
+  ///
+  /// ```
+  /// use farg::Spd;
+  ///
+  /// let gaussian = |wavelength: u32| (-((wavelength as f64 - 550.0) / 40.0).powi(2)).exp();
+  /// let a = Spd::from_fn(380, 780, 5, gaussian);
+  /// let b = Spd::from_fn(380, 780, 5, |wavelength| gaussian(wavelength) * 0.5);
+  ///
+  /// assert!((a.correlation(&b) - 1.0).abs() < 1e-9);
+  /// ```
+  pub fn correlation(&self, other: &Spd) -> f64 {
+    let samples = self.overlapping_samples(other);
+    let n = samples.len() as f64;
+    let mean_a = samples.iter().map(|(a, _)| a).sum::<f64>() / n;
+    let mean_b = samples.iter().map(|(_, b)| b).sum::<f64>() / n;
+
+    let (covariance, variance_a, variance_b) = samples.iter().fold((0.0, 0.0, 0.0), |(cov, var_a, var_b), (a, b)| {
+      let (da, db) = (a - mean_a, b - mean_b);
+      (cov + da * db, var_a + da * da, var_b + db * db)
+    });
+
+    if variance_a == 0.0 || variance_b == 0.0 {
+      return f64::NAN;
+    }
+
+    covariance / (variance_a * variance_b).sqrt()
+  }
+
+  /// Returns the full width at half maximum (FWHM) of the peak, in nanometers.
+  ///
+  /// Walks outward from the [`peak_wavelength`](Self::peak_wavelength) to the first samples
+  /// on each side whose power drops below half the peak, linearly interpolating the exact
+  /// crossing wavelengths. Returns `None` if the table is empty, the peak is not positive,
+  /// or either crossing is not bracketed within the data.
+  pub fn full_width_half_max(&self) -> Option<f64> {
+    let table = self.table();
+    let peak_index =
+      (0..table.len()).max_by(|&a, &b| table[a].1.partial_cmp(&table[b].1).unwrap_or(std::cmp::Ordering::Equal))?;
+    let half = table[peak_index].1 / 2.0;
+
+    if half <= 0.0 {
+      return None;
+    }
+
+    let left = (0..peak_index).rev().find(|&i| table[i].1 < half)?;
+    let right = (peak_index + 1..table.len()).find(|&i| table[i].1 < half)?;
+
+    let crossing =
+      |(w1, p1): (u32, f64), (w2, p2): (u32, f64)| w1 as f64 + (half - p1) / (p2 - p1) * (w2 as f64 - w1 as f64);
+
+    Some(crossing(table[right - 1], table[right]) - crossing(table[left], table[left + 1]))
   }
 
   /// Returns the maximum power value across all wavelengths.
@@ -27,17 +207,152 @@ impl SpectralPowerDistribution {
       .map(|(w, _)| *w)
   }
 
+  /// Returns the root-mean-square difference between this SPD and `other` over their overlapping
+  /// range.
+  ///
+  /// Both spectra are linearly interpolated onto a common grid spanning the overlapping
+  /// wavelengths at the finer of the two steps. Unlike [`correlation`](Self::correlation), this is
+  /// sensitive to scale. Returns `NaN` when the spectra do not overlap.
+  pub fn rms_difference(&self, other: &Spd) -> f64 {
+    let samples = self.overlapping_samples(other);
+    let sum_of_squares: f64 = samples.iter().map(|(a, b)| (a - b).powi(2)).sum();
+
+    (sum_of_squares / samples.len() as f64).sqrt()
+  }
+
   /// Returns the sum of power values across all wavelengths.
   pub fn total_power(&self) -> f64 {
     self.values().sum()
   }
+
+  /// Samples both SPDs on a shared grid over their overlapping wavelength range.
+  fn overlapping_samples(&self, other: &Spd) -> Vec<(f64, f64)> {
+    let (Some(start), Some(end)) = (
+      self.min_wavelength().zip(other.min_wavelength()).map(|(a, b)| a.max(b)),
+      self.max_wavelength().zip(other.max_wavelength()).map(|(a, b)| a.min(b)),
+    ) else {
+      return Vec::new();
+    };
+
+    (start..=end)
+      .step_by(self.step().min(other.step()).max(1) as usize)
+      .filter_map(|wavelength| {
+        let wavelength = wavelength as f64;
+        self.power_at(wavelength).zip(other.power_at(wavelength))
+      })
+      .collect()
+  }
+
+  /// Returns the static samples this SPD borrows, for storing in `Copy` types such as
+  /// [`Illuminant`](crate::Illuminant).
+  ///
+  /// # Panics
+  ///
+  /// Panics if the SPD owns runtime-built samples.
+  pub(crate) const fn into_static_table(self) -> &'static [(u32, f64)] {
+    let table = match &self.0 {
+      Cow::Borrowed(table) => Some(*table),
+      Cow::Owned(_) => None,
+    };
+    std::mem::forget(self);
+
+    match table {
+      Some(table) => table,
+      None => panic!("expected an SPD backed by static samples"),
+    }
+  }
+
+  /// Returns the power at `wavelength`, linearly interpolating between neighboring samples.
+  ///
+  /// Returns `None` outside the table's wavelength range.
+  pub(crate) fn power_at(&self, wavelength: f64) -> Option<f64> {
+    let table = self.table();
+    let index = table.partition_point(|(w, _)| (*w as f64) < wavelength);
+    let &(w2, p2) = table.get(index)?;
+
+    if w2 as f64 == wavelength {
+      return Some(p2);
+    }
+
+    let &(w1, p1) = table.get(index.checked_sub(1)?)?;
+    let t = (wavelength - w1 as f64) / (w2 as f64 - w1 as f64);
+
+    Some(p1 + (p2 - p1) * t)
+  }
+}
+
+/// Returns the spectral radiant exitance of a blackbody at `kelvin` and `wavelength` (nm) by
+/// Planck's law, or 0.0 for non-positive temperatures.
+pub(crate) fn blackbody_exitance(wavelength: u32, kelvin: f64) -> f64 {
+  let lambda = wavelength as f64 * 1e-9;
+  let value = PLANCK_C1 / (lambda.powi(5) * ((PLANCK_C2 / (lambda * kelvin)).exp() - 1.0));
+
+  if kelvin > 0.0 && value.is_finite() { value } else { 0.0 }
+}
+
+impl Add for SpectralPowerDistribution {
+  type Output = Self;
+
+  /// Superposes two light sources by summing their power at every wavelength.
+  ///
+  /// Both spectra are linearly interpolated onto a common grid spanning the union of their
+  /// wavelength ranges at the finer of the two steps. Where only one spectrum has data the other
+  /// contributes no power, so a lamp's emission outside the other's range is kept. Adding an
+  /// empty SPD returns the other operand unchanged.
+  ///
+  /// ```
+  /// use farg::{Spd, SpectralTable};
+  ///
+  /// let blue = Spd::gaussian(450.0, 20.0, 380, 780, 5);
+  /// let yellow = Spd::gaussian(580.0, 30.0, 380, 780, 5);
+  /// let lamps = blue + yellow;
+  ///
+  /// assert_eq!(lamps.len(), 81);
+  /// assert!((lamps.at(450).copied().unwrap() - 1.0).abs() < 1e-3);
+  /// ```
+  fn add(self, rhs: Self) -> Self {
+    &self + &rhs
+  }
+}
+
+impl Add for &SpectralPowerDistribution {
+  type Output = SpectralPowerDistribution;
+
+  fn add(self, rhs: Self) -> SpectralPowerDistribution {
+    if rhs.is_empty() {
+      return self.clone();
+    }
+    if self.is_empty() {
+      return rhs.clone();
+    }
+
+    let (Some(start), Some(end)) = (
+      self.min_wavelength().zip(rhs.min_wavelength()).map(|(a, b)| a.min(b)),
+      self.max_wavelength().zip(rhs.max_wavelength()).map(|(a, b)| a.max(b)),
+    ) else {
+      return self.clone();
+    };
+    let power = |spd: &Spd, wavelength: u32| spd.power_at(wavelength as f64).unwrap_or(0.0);
+
+    (start..=end)
+      .step_by(self.step().min(rhs.step()).max(1) as usize)
+      .map(|wavelength| (wavelength, power(self, wavelength) + power(rhs, wavelength)))
+      .collect::<Vec<_>>()
+      .into()
+  }
+}
+
+impl From<Vec<(u32, f64)>> for SpectralPowerDistribution {
+  fn from(samples: Vec<(u32, f64)>) -> Self {
+    Self(Cow::Owned(samples))
+  }
 }
 
 impl Table for SpectralPowerDistribution {
   type Value = f64;
 
   fn table(&self) -> &[(u32, Self::Value)] {
-    self.0
+    &self.0
   }
 }
 
@@ -48,6 +363,330 @@ mod test {
   static TEST_SPD: &[(u32, f64)] = &[(380, 0.1), (400, 0.5), (420, 0.3), (440, 0.2)];
   static EMPTY_SPD: &[(u32, f64)] = &[];
 
+  mod add {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Illuminant;
+
+    #[test]
+    fn it_doubles_every_sample_when_added_to_itself() {
+      let spd = Spd::new(TEST_SPD);
+      let doubled = &spd + &spd;
+
+      assert_eq!(doubled.len(), spd.len());
+      for ((w1, original), (w2, sum)) in spd.table().iter().zip(doubled.table()) {
+        assert_eq!(w1, w2);
+        assert!((sum - original * 2.0).abs() < 1e-12);
+      }
+    }
+
+    #[test]
+    fn it_reproduces_d65_from_two_half_scaled_copies() {
+      let d65 = Illuminant::D65.spd();
+      let half = Spd::from_fn(
+        d65.min_wavelength().unwrap(),
+        d65.max_wavelength().unwrap(),
+        d65.step(),
+        |w| d65.at(w).copied().unwrap_or(0.0) * 0.5,
+      );
+      let sum = &half + &half;
+
+      assert_eq!(sum.len(), d65.len());
+      assert!(sum.rms_difference(&d65) < 1e-12);
+    }
+
+    #[test]
+    fn it_adds_borrowed_spds() {
+      let spd = Spd::new(TEST_SPD);
+      let borrowed = &spd;
+
+      assert!((borrowed + borrowed).rms_difference(&(spd.clone() + spd)) < 1e-12);
+    }
+
+    #[test]
+    fn it_covers_the_union_of_partially_overlapping_ranges() {
+      let a = Spd::from_fn(380, 780, 5, |_| 1.0);
+      let b = Spd::from_fn(400, 800, 5, |_| 2.0);
+      let sum = a + b;
+
+      assert_eq!(sum.min_wavelength(), Some(380));
+      assert_eq!(sum.max_wavelength(), Some(800));
+      assert_eq!(sum.at(380), Some(&1.0));
+      assert_eq!(sum.at(395), Some(&1.0));
+      assert_eq!(sum.at(400), Some(&3.0));
+      assert_eq!(sum.at(780), Some(&3.0));
+      assert_eq!(sum.at(785), Some(&2.0));
+      assert_eq!(sum.at(800), Some(&2.0));
+    }
+
+    #[test]
+    fn it_resamples_onto_the_finer_step() {
+      let coarse = Spd::from_fn(380, 780, 20, |_| 1.0);
+      let fine = Spd::from_fn(380, 780, 10, |_| 2.0);
+      let sum = coarse + fine;
+
+      assert_eq!(sum.step(), 10);
+      assert!(sum.values().all(|power| (power - 3.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn it_keeps_disjoint_spectra() {
+      let low = Spd::from_fn(380, 480, 10, |_| 1.0);
+      let high = Spd::from_fn(600, 700, 10, |_| 1.0);
+      let sum = low + high;
+
+      assert_eq!(sum.at(400), Some(&1.0));
+      assert_eq!(sum.at(550), Some(&0.0));
+      assert_eq!(sum.at(650), Some(&1.0));
+    }
+
+    #[test]
+    fn it_returns_the_other_operand_when_one_is_empty() {
+      let spd = Spd::new(TEST_SPD);
+
+      assert_eq!((&spd + &Spd::new(EMPTY_SPD)).table(), spd.table());
+      assert_eq!((&Spd::new(EMPTY_SPD) + &spd).table(), spd.table());
+    }
+  }
+
+  mod band {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_is_one_inside_and_zero_outside_the_band() {
+      let spd = Spd::band(500.0, 600.0, 380, 780, 5);
+
+      for &(wavelength, power) in spd.table() {
+        let expected = if (500..=600).contains(&wavelength) { 1.0 } else { 0.0 };
+        assert_eq!(power, expected, "at {wavelength} nm");
+      }
+    }
+
+    #[test]
+    fn it_includes_the_band_edges() {
+      let spd = Spd::band(500.0, 600.0, 380, 780, 5);
+
+      assert_eq!(spd.at(500), Some(&1.0));
+      assert_eq!(spd.at(600), Some(&1.0));
+      assert_eq!(spd.at(495), Some(&0.0));
+      assert_eq!(spd.at(605), Some(&0.0));
+    }
+
+    #[test]
+    fn it_is_all_zero_for_an_inverted_band() {
+      let spd = Spd::band(600.0, 500.0, 380, 780, 5);
+
+      assert!(spd.values().all(|&power| power == 0.0));
+    }
+  }
+
+  mod blackbody {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Observer;
+
+    #[test]
+    fn it_normalizes_peak_to_one() {
+      let spd = Spd::blackbody(5000.0, 380, 780, 5);
+
+      assert_eq!(spd.peak_power(), 1.0);
+      assert_eq!(spd.len(), 81);
+    }
+
+    #[test]
+    fn it_lies_near_the_planckian_locus_at_6500k() {
+      let spd = Spd::blackbody(6500.0, 360, 830, 1);
+      let xy = Observer::CIE_1931_2D
+        .cmf()
+        .calculate_reference_white(&spd)
+        .chromaticity();
+
+      assert!((xy.x() - 0.3135).abs() < 0.002);
+      assert!((xy.y() - 0.3237).abs() < 0.002);
+    }
+
+    #[test]
+    fn it_shifts_peak_to_shorter_wavelengths_at_higher_temperatures() {
+      let warm = Spd::blackbody(4000.0, 300, 1000, 1).peak_wavelength().unwrap();
+      let cool = Spd::blackbody(8000.0, 300, 1000, 1).peak_wavelength().unwrap();
+
+      assert!(cool < warm);
+      assert!((warm as f64 - 2_897_771.955 / 4000.0).abs() <= 1.0);
+    }
+
+    #[test]
+    fn it_returns_zeros_for_non_positive_temperature() {
+      let spd = Spd::blackbody(0.0, 380, 780, 10);
+
+      assert!(spd.values().all(|&power| power == 0.0));
+    }
+  }
+
+  mod correlation {
+    use super::*;
+
+    #[test]
+    fn it_is_one_against_itself() {
+      let spd = Spd::new(TEST_SPD);
+
+      assert!((spd.correlation(&spd) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_is_one_for_a_scaled_copy() {
+      let spd = Spd::blackbody(4000.0, 380, 780, 5);
+      let scaled = Spd::from_fn(380, 780, 5, |wavelength| spd.at(wavelength).unwrap() * 3.0);
+
+      assert!((spd.correlation(&scaled) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_resamples_onto_a_common_grid() {
+      let coarse = Spd::from_fn(380, 780, 20, |wavelength| wavelength as f64);
+      let fine = Spd::from_fn(400, 700, 5, |wavelength| 2.0 * wavelength as f64);
+
+      assert!((coarse.correlation(&fine) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_is_nan_without_overlap() {
+      let a = Spd::from_fn(380, 500, 10, |_| 1.0);
+      let b = Spd::from_fn(600, 700, 10, |_| 1.0);
+
+      assert!(a.correlation(&b).is_nan());
+    }
+  }
+
+  mod from_fn {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_produces_flat_spd_from_constant_closure() {
+      let spd = Spd::from_fn(380, 780, 10, |_| 100.0);
+
+      assert_eq!(spd.len(), 41);
+      assert!(spd.values().all(|&power| power == 100.0));
+    }
+
+    #[test]
+    fn it_samples_closure_at_each_wavelength() {
+      let spd = Spd::from_fn(400, 700, 20, |wavelength| wavelength as f64 / 10.0);
+
+      assert_eq!(spd.at(400), Some(&40.0));
+      assert_eq!(spd.at(560), Some(&56.0));
+      assert_eq!(spd.at(700), Some(&70.0));
+      assert_eq!(spd.at(410), None);
+    }
+
+    #[test]
+    fn it_stops_at_last_step_before_end() {
+      let spd = Spd::from_fn(400, 450, 20, |_| 1.0);
+
+      assert_eq!(spd.wavelengths().collect::<Vec<_>>(), vec![400, 420, 440]);
+    }
+
+    #[test]
+    fn it_treats_zero_step_as_one() {
+      let spd = Spd::from_fn(500, 503, 0, |_| 1.0);
+
+      assert_eq!(spd.len(), 4);
+    }
+
+    #[test]
+    fn it_returns_empty_spd_for_reversed_range() {
+      let spd = Spd::from_fn(700, 400, 10, |_| 1.0);
+
+      assert!(spd.is_empty());
+    }
+  }
+
+  mod full_width_half_max {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn gaussian(center: f64, sigma: f64) -> Spd {
+      let data: Vec<(u32, f64)> = (380..=780)
+        .map(|w| (w, (-((w as f64 - center).powi(2)) / (2.0 * sigma * sigma)).exp()))
+        .collect();
+      Spd::from(data)
+    }
+
+    #[test]
+    fn it_measures_gaussian_width() {
+      let sigma = 20.0;
+      let spd = gaussian(580.0, sigma);
+
+      assert_eq!(spd.peak_wavelength(), Some(580));
+      assert!((spd.full_width_half_max().unwrap() - 2.3548 * sigma).abs() < 0.1);
+    }
+
+    #[test]
+    fn it_interpolates_between_samples() {
+      let spd = Spd::new(TEST_SPD);
+
+      assert!((spd.full_width_half_max().unwrap() - 42.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn it_returns_none_when_crossing_is_not_bracketed() {
+      let spd = gaussian(385.0, 20.0);
+
+      assert_eq!(spd.full_width_half_max(), None);
+    }
+
+    #[test]
+    fn it_returns_none_for_empty_table() {
+      let spd = Spd::new(EMPTY_SPD);
+
+      assert_eq!(spd.full_width_half_max(), None);
+    }
+  }
+
+  mod gaussian {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_peaks_at_the_center() {
+      let spd = Spd::gaussian(550.0, 5.0, 380, 780, 1);
+
+      assert_eq!(spd.peak_wavelength(), Some(550));
+      assert_eq!(spd.peak_power(), 1.0);
+    }
+
+    #[test]
+    fn it_is_symmetric_about_the_center() {
+      let spd = Spd::gaussian(550.0, 20.0, 380, 780, 5);
+
+      assert_eq!(spd.at(530), spd.at(570));
+    }
+
+    #[test]
+    fn it_has_the_expected_full_width_half_max() {
+      let sigma = 10.0;
+      let spd = Spd::gaussian(550.0, sigma, 380, 780, 1);
+      let expected = 2.0 * (2.0 * 2.0_f64.ln()).sqrt() * sigma;
+
+      assert!((spd.full_width_half_max().unwrap() - expected).abs() < 0.05);
+    }
+
+    #[test]
+    fn it_produces_a_spike_for_zero_sigma() {
+      let spd = Spd::gaussian(550.0, 0.0, 380, 780, 5);
+
+      assert_eq!(spd.at(550), Some(&1.0));
+      assert_eq!(spd.total_power(), 1.0);
+    }
+  }
+
   mod peak_power {
     use pretty_assertions::assert_eq;
 
@@ -88,6 +727,33 @@ mod test {
     }
   }
 
+  mod rms_difference {
+    use super::*;
+
+    #[test]
+    fn it_is_zero_against_itself() {
+      let spd = Spd::new(TEST_SPD);
+
+      assert_eq!(spd.rms_difference(&spd), 0.0);
+    }
+
+    #[test]
+    fn it_is_nonzero_for_a_scaled_copy() {
+      let spd = Spd::from_fn(380, 780, 10, |_| 1.0);
+      let scaled = Spd::from_fn(380, 780, 10, |_| 3.0);
+
+      assert!((spd.rms_difference(&scaled) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_interpolates_between_samples() {
+      let coarse = Spd::from_fn(400, 500, 100, |wavelength| wavelength as f64);
+      let fine = Spd::from_fn(400, 500, 10, |wavelength| wavelength as f64);
+
+      assert!(coarse.rms_difference(&fine) < 1e-12);
+    }
+  }
+
   mod total_power {
     use pretty_assertions::assert_eq;
 
@@ -0,0 +1,84 @@
+//! Assertions for testing color spaces, including spaces defined in downstream crates.
+//!
+//! Enabled by the `test-util` feature. Intended for `dev-dependencies`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! farg = { version = "0.4", features = ["test-util"] }
+//! ```
+
+use crate::space::{ColorSpace, Xyz};
+
+/// Asserts that `color` survives a round trip through [`Xyz`] within `epsilon`.
+///
+/// Converts the color to XYZ with [`ColorSpace::to_xyz`], back with `C::from`, and compares each
+/// component and the alpha channel. Components are compared as returned by
+/// [`ColorSpace::components`], so hue channels are not wrapped; pick chromatic colors away from
+/// the 0°/360° seam when testing cylindrical spaces.
+///
+/// # Panics
+///
+/// Panics if any component or the alpha channel differs by more than `epsilon`.
+///
+/// ```
+/// use farg::space::{Rgb, Srgb};
+/// use farg::test_util::assert_roundtrip;
+///
+/// assert_roundtrip(Rgb::<Srgb>::new(255, 87, 51), 1e-10);
+/// ```
+#[track_caller]
+pub fn assert_roundtrip<C>(color: C, epsilon: f64)
+where
+  C: ColorSpace<3> + From<Xyz> + Copy,
+{
+  let roundtrip = C::from(color.to_xyz());
+  let expected = color.components();
+  let actual = roundtrip.components();
+
+  for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+    assert!(
+      (expected - actual).abs() <= epsilon,
+      "component {index} did not round-trip through Xyz: expected {expected}, got {actual} (epsilon {epsilon})"
+    );
+  }
+
+  assert!(
+    (color.alpha() - roundtrip.alpha()).abs() <= epsilon,
+    "alpha did not round-trip through Xyz: expected {}, got {} (epsilon {epsilon})",
+    color.alpha(),
+    roundtrip.alpha()
+  );
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  mod assert_roundtrip {
+    use super::*;
+
+    #[cfg(feature = "space-hsv")]
+    #[test]
+    fn it_accepts_hsv() {
+      use crate::space::{Hsv, Srgb};
+
+      assert_roundtrip(Hsv::<Srgb>::new(200.0, 60.0, 70.0).with_alpha(0.5), 1e-10);
+    }
+
+    #[cfg(feature = "space-oklch")]
+    #[test]
+    fn it_accepts_oklch() {
+      use crate::space::Oklch;
+
+      assert_roundtrip(Oklch::new(0.7, 0.1, 150.0), 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "did not round-trip")]
+    fn it_panics_when_the_tolerance_is_exceeded() {
+      use crate::space::{Rgb, Srgb};
+
+      assert_roundtrip(Rgb::<Srgb>::new(255, 87, 51), -1.0);
+    }
+  }
+}
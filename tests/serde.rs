@@ -222,3 +222,183 @@ mod cmyk {
     assert!(value.get("k").is_some());
   }
 }
+
+mod serde_array {
+  use farg::space::serde_array;
+  use serde::{Deserialize, Serialize};
+
+  use super::*;
+
+  #[derive(Deserialize, Serialize)]
+  struct RgbSwatch {
+    #[serde(with = "serde_array")]
+    color: Rgb<Srgb>,
+  }
+
+  #[test]
+  fn it_serializes_opaque_rgb_as_three_components() {
+    let swatch = RgbSwatch {
+      color: Rgb::<Srgb>::new(255, 0, 51),
+    };
+    let value = serde_json::to_value(&swatch).unwrap();
+
+    assert_eq!(value["color"], serde_json::json!([1.0, 0.0, 0.2]));
+  }
+
+  #[test]
+  fn it_roundtrips_opaque_rgb() {
+    let swatch = RgbSwatch {
+      color: Rgb::<Srgb>::new(200, 100, 50),
+    };
+    let json = serde_json::to_string(&swatch).unwrap();
+    let back: RgbSwatch = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(back.color, swatch.color);
+    assert_eq!(back.color.alpha(), 1.0);
+  }
+
+  #[test]
+  fn it_rejects_arrays_of_the_wrong_length() {
+    assert!(serde_json::from_str::<RgbSwatch>(r#"{"color":[0.5,0.5]}"#).is_err());
+    assert!(serde_json::from_str::<RgbSwatch>(r#"{"color":[0.5,0.5,0.5,0.5,0.5]}"#).is_err());
+  }
+
+  #[cfg(feature = "space-oklch")]
+  mod oklch {
+    use farg::space::Oklch;
+
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    struct OklchSwatch {
+      #[serde(with = "serde_array")]
+      color: Oklch,
+    }
+
+    #[test]
+    fn it_roundtrips_with_alpha_as_four_components() {
+      let swatch = OklchSwatch {
+        color: Oklch::new(0.6, 0.15, 270.0).with_alpha(0.5),
+      };
+      let value = serde_json::to_value(&swatch).unwrap();
+      let back: OklchSwatch = serde_json::from_value(value.clone()).unwrap();
+
+      assert_eq!(value["color"].as_array().unwrap().len(), 4);
+      assert_eq!(back.color.components(), swatch.color.components());
+      assert_eq!(back.color.alpha(), 0.5);
+    }
+  }
+
+  #[cfg(feature = "space-cmyk")]
+  mod cmyk {
+    use farg::space::Cmyk;
+
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    struct CmykSwatch {
+      #[serde(with = "serde_array")]
+      color: Cmyk<Srgb>,
+    }
+
+    #[test]
+    fn it_roundtrips_four_component_spaces() {
+      let swatch = CmykSwatch {
+        color: Cmyk::<Srgb>::new(25.0, 50.0, 75.0, 10.0),
+      };
+      let value = serde_json::to_value(&swatch).unwrap();
+      let back: CmykSwatch = serde_json::from_value(value.clone()).unwrap();
+
+      assert_eq!(value["color"].as_array().unwrap().len(), 4);
+      assert_eq!(back.color.components(), swatch.color.components());
+    }
+  }
+}
+
+mod colorimetric_context {
+  use farg::ColorimetricContext;
+  use pretty_assertions::assert_eq;
+
+  #[cfg(feature = "cat-bradford")]
+  #[test]
+  fn it_serializes_constant_names() {
+    let json = serde_json::to_string(&ColorimetricContext::DEFAULT).unwrap();
+
+    assert_eq!(
+      json,
+      r#"{"illuminant":"D65","observer":"CIE_1931_2D","cat":"BRADFORD"}"#
+    );
+  }
+
+  #[test]
+  fn it_roundtrips_the_default_context() {
+    let json = serde_json::to_string(&ColorimetricContext::DEFAULT).unwrap();
+    let back: ColorimetricContext = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(back.illuminant().name(), "D65");
+    assert_eq!(back.observer().name(), "CIE 1931 2°");
+    assert_eq!(back.cat().name(), ColorimetricContext::DEFAULT.cat().name());
+  }
+
+  #[cfg(all(feature = "illuminant-d50", feature = "cat-bradford"))]
+  #[test]
+  fn it_roundtrips_a_d50_bradford_context() {
+    let context = ColorimetricContext::new()
+      .with_illuminant(farg::Illuminant::D50)
+      .with_cat(farg::Cat::BRADFORD);
+    let json = serde_json::to_string(&context).unwrap();
+    let back: ColorimetricContext = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(back.illuminant().name(), "D50");
+    assert_eq!(back.observer().name(), "CIE 1931 2°");
+    assert_eq!(back.cat().name(), "Bradford");
+  }
+
+  #[test]
+  fn it_fails_to_serialize_a_custom_illuminant() {
+    use farg::{Illuminant, IlluminantType};
+
+    let custom = Illuminant::builder("Custom", IlluminantType::Custom)
+      .with_spd(&[(380, 50.0), (780, 100.0)])
+      .build()
+      .unwrap();
+    let error = serde_json::to_string(&ColorimetricContext::new().with_illuminant(custom)).unwrap_err();
+
+    assert!(
+      error
+        .to_string()
+        .contains("illuminant 'Custom' is not built in and cannot be serialized")
+    );
+  }
+
+  #[test]
+  fn it_fails_to_serialize_a_custom_illuminant_with_a_built_in_name() {
+    use farg::{Illuminant, IlluminantType};
+
+    let impostor = Illuminant::builder("D65", IlluminantType::Custom)
+      .with_spd(&[(380, 50.0), (780, 100.0)])
+      .build()
+      .unwrap();
+
+    assert!(serde_json::to_string(&ColorimetricContext::new().with_illuminant(impostor)).is_err());
+  }
+
+  #[test]
+  fn it_fails_to_serialize_a_custom_cat() {
+    let custom = farg::Cat::new("Custom", [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    assert!(serde_json::to_string(&ColorimetricContext::new().with_cat(custom)).is_err());
+  }
+
+  #[test]
+  fn it_rejects_unknown_names() {
+    let json = r#"{"illuminant":"D65","observer":"CIE_1931_2D","cat":"NOT_A_CAT"}"#;
+    let error = serde_json::from_str::<ColorimetricContext>(json).unwrap_err();
+
+    assert!(
+      error
+        .to_string()
+        .contains("unknown chromatic adaptation transform 'NOT_A_CAT'")
+    );
+  }
+}
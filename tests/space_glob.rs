@@ -0,0 +1,34 @@
+#![cfg(feature = "space-oklch")]
+
+use farg::space::*;
+
+mod chroma {
+  use super::*;
+
+  #[cfg(feature = "space-lab")]
+  #[test]
+  fn it_resolves_on_lab_with_the_glob_import() {
+    let lab = Lab::new(50.0, 0.0, 20.0);
+
+    assert!((lab.chroma() - lab.to_oklch().chroma()).abs() < 1e-12);
+    assert!((lab.polar_chroma() - 20.0).abs() < 1e-10);
+  }
+
+  #[cfg(feature = "space-luv")]
+  #[test]
+  fn it_resolves_on_luv_with_the_glob_import() {
+    let luv = Luv::new(50.0, 0.0, 20.0);
+
+    assert!((luv.chroma() - luv.to_oklch().chroma()).abs() < 1e-12);
+    assert!((luv.polar_chroma() - 20.0).abs() < 1e-10);
+  }
+
+  #[cfg(feature = "space-oklab")]
+  #[test]
+  fn it_resolves_on_oklab_with_the_glob_import() {
+    let oklab = Oklab::new(0.5, 0.03, 0.04);
+
+    assert!((oklab.chroma() - 0.05).abs() < 1e-10);
+    assert!((oklab.polar_chroma() - 0.05).abs() < 1e-10);
+  }
+}